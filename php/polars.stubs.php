@@ -50,13 +50,17 @@ namespace Polars {
         public function __toString(): string {}
 
         /**
-         * Get the bottom k rows by a column
+         * Get the k rows with the smallest values of the `by` column(s)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+         * @param bool|bool[]|null $reverse Take the largest values instead, one flag per key or a single flag for all
+         * @throws Polars\Exception
          *
          * @param int $k
-         * @param string $by
+         * @param mixed $by
+         * @param mixed $reverse
          * @return \Polars\DataFrame
          */
-        public function bottomK(int $k, string $by): \Polars\DataFrame {}
+        public function bottomK(int $k, mixed $by, mixed $reverse = null): \Polars\DataFrame {}
 
         /**
          * Cast columns to different data types
@@ -99,6 +103,18 @@ namespace Polars {
          */
         public function copy(): \Polars\DataFrame {}
 
+        /**
+         * Compute the pairwise correlation between all columns.
+         * The result has one row and one column per input column, in the same order
+         * @param string $method One of: 'pearson', 'spearman'
+         * @throws Polars\Exception
+         *
+         * @param string $method
+         * @param bool $propagateNans
+         * @return \Polars\DataFrame
+         */
+        public function corr(string $method = "pearson", bool $propagateNans = false): \Polars\DataFrame {}
+
         /**
          * @return \Polars\DataFrame Return the number of non-null elements for each column.
          *
@@ -241,14 +257,45 @@ namespace Polars {
         public function glimpse(): string {}
 
         /**
-         * Group by expressions
+         * Group by expressions. Aggregations on the returned GroupBy are executed eagerly
+         * and iterating over it yields every group as a DataFrame.
          * @param \Polars\Expr[] $expressions
-         * @return \Polars\LazyGroupBy
+         * @param bool $maintainOrder Keep groups in the order of their first appearance
+         * @return \Polars\GroupBy
          *
          * @param array $expressions
+         * @param bool $maintainOrder
+         * @return \Polars\GroupBy
+         */
+        public function groupBy(array $expressions, bool $maintainOrder = false): \Polars\GroupBy {}
+
+        /**
+         * Group by time windows of the index column, optionally per group of `groupBy` keys.
+         * The index column must be sorted (within each group)
+         * @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+         * @param string $every Interval between the starts of consecutive windows, e.g. '15m' or '2i'
+         * @param string|null $period Length of each window (default: $every)
+         * @param string|null $offset Offset of the window starts (default: zero)
+         * @param bool $includeBoundaries Add '_lower_boundary' and '_upper_boundary' columns
+         * @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+         * @param string $label Window label in the index column: 'left', 'right', 'datapoint'
+         * @param string $startBy Start of the first window: 'window', 'datapoint' or a day of the week such as 'monday'
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+         * @return \Polars\LazyGroupBy
+         * @throws Polars\Exception
+         *
+         * @param mixed $indexColumn
+         * @param string $every
+         * @param string|null $period
+         * @param string|null $offset
+         * @param bool $includeBoundaries
+         * @param \Polars\ClosedInterval $closed
+         * @param string $label
+         * @param string $startBy
+         * @param mixed $groupBy
          * @return \Polars\LazyGroupBy
          */
-        public function groupBy(array $expressions): \Polars\LazyGroupBy {}
+        public function groupByDynamic(mixed $indexColumn, string $every, ?string $period = null, ?string $offset = null, bool $includeBoundaries = false, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Left, string $label = "left", string $startBy = "window", mixed $groupBy = null): \Polars\LazyGroupBy {}
 
         /**
          * Get the first n rows
@@ -325,12 +372,17 @@ namespace Polars {
          * Join with another DataFrame
          * @param \Polars\DataFrame $other The right DataFrame
          * @param \Polars\Expr[] $on Join columns (used for both left and right when leftOn/rightOn not given)
-         * @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross'
+         * @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross', 'semi', 'anti'
          * @param \Polars\Expr[]|null $leftOn Left join columns (overrides $on for the left side)
          * @param \Polars\Expr[]|null $rightOn Right join columns (overrides $on for the right side)
          * @param string|null $suffix Suffix for duplicate column names (default: '_right')
          * @param string|null $validate Join validation: 'm:m', 'm:1', '1:m', '1:1'
          * @param bool|null $coalesce Coalesce join columns
+         * @param bool $nullsEqual Match null keys with each other
+         * @param string $maintainOrder Row order to preserve: 'none', 'left', 'right', 'left_right', 'right_left'
+         * @param bool $allowParallel Allow evaluating both sides in parallel
+         * @param bool $forceParallel Force evaluating both sides in parallel
+         * @throws Polars\Exception
          *
          * @param \Polars\DataFrame $other
          * @param array $on
@@ -340,28 +392,45 @@ namespace Polars {
          * @param string|null $suffix
          * @param string|null $validate
          * @param bool|null $coalesce
+         * @param bool $nullsEqual
+         * @param string $maintainOrder
+         * @param bool $allowParallel
+         * @param bool $forceParallel
          * @return \Polars\DataFrame
          */
-        public function join(\Polars\DataFrame $other, array $on, string $how = "inner", ?array $leftOn = null, ?array $rightOn = null, ?string $suffix = null, ?string $validate = null, ?bool $coalesce = null): \Polars\DataFrame {}
+        public function join(\Polars\DataFrame $other, array $on, string $how = "inner", ?array $leftOn = null, ?array $rightOn = null, ?string $suffix = null, ?string $validate = null, ?bool $coalesce = null, bool $nullsEqual = false, string $maintainOrder = "none", bool $allowParallel = true, bool $forceParallel = false): \Polars\DataFrame {}
 
         /**
          * Perform an asof join with another DataFrame
          * @param \Polars\DataFrame $other The right DataFrame
-         * @param string $on Column to join on (must be sorted)
-         * @param string|null $leftBy Group by column for left DataFrame
-         * @param string|null $rightBy Group by column for right DataFrame
-         * @param string|null $tolerance Tolerance for the asof join (time duration string e.g. "5m")
-         * @param string $strategy Join strategy: 'backward', 'forward', 'nearest'
+         * @param string|null $on Key column of both sides (must be sorted)
+         * @param string|null $strategy Join strategy: 'backward', 'forward', 'nearest'
+         * @param string|string[]|null $leftBy Group by columns of the left side
+         * @param string|string[]|null $rightBy Group by columns of the right side
+         * @param int|float|string|null $tolerance Maximum key distance, as a number or a duration string (e.g. "5m")
+         * @param string|string[]|null $by Group by columns of both sides
+         * @param string|null $leftOn Key column of the left side
+         * @param string|null $rightOn Key column of the right side
+         * @param string|null $suffix Suffix for duplicate column names (default: '_right')
+         * @param bool $allowExactMatches Allow matching equal keys
+         * @param bool $checkSortedness Check that the keys are sorted
+         * @throws Polars\Exception
          *
          * @param \Polars\DataFrame $other
-         * @param string $on
+         * @param string|null $on
          * @param string|null $strategy
-         * @param string|null $leftBy
-         * @param string|null $rightBy
-         * @param string|null $tolerance
+         * @param mixed $leftBy
+         * @param mixed $rightBy
+         * @param mixed $tolerance
+         * @param mixed $by
+         * @param string|null $leftOn
+         * @param string|null $rightOn
+         * @param string|null $suffix
+         * @param bool $allowExactMatches
+         * @param bool $checkSortedness
          * @return \Polars\DataFrame
          */
-        public function joinAsof(\Polars\DataFrame $other, string $on, ?string $strategy = null, ?string $leftBy = null, ?string $rightBy = null, ?string $tolerance = null): \Polars\DataFrame {}
+        public function joinAsof(\Polars\DataFrame $other, ?string $on = null, ?string $strategy = null, mixed $leftBy = null, mixed $rightBy = null, mixed $tolerance = null, mixed $by = null, ?string $leftOn = null, ?string $rightOn = null, ?string $suffix = null, bool $allowExactMatches = true, bool $checkSortedness = true): \Polars\DataFrame {}
 
         /**
          * Join with another DataFrame using arbitrary predicates
@@ -396,6 +465,14 @@ namespace Polars {
          */
         public function max(): \Polars\DataFrame {}
 
+        /**
+         * Get the maximum value horizontally across columns.
+         * @return \Polars\Series
+         *
+         * @return \Polars\Series
+         */
+        public function maxHorizontal(): \Polars\Series {}
+
         /**
          * Aggregate the columns of this DataFrame to their mean value.
          *
@@ -403,6 +480,15 @@ namespace Polars {
          */
         public function mean(): \Polars\DataFrame {}
 
+        /**
+         * Take the mean of all values horizontally across columns.
+         * @return \Polars\Series
+         *
+         * @param bool $ignoreNulls
+         * @return \Polars\Series
+         */
+        public function meanHorizontal(bool $ignoreNulls = true): \Polars\Series {}
+
         /**
          * Aggregate the columns to their median value.
          *
@@ -439,6 +525,14 @@ namespace Polars {
          */
         public function min(): \Polars\DataFrame {}
 
+        /**
+         * Get the minimum value horizontally across columns.
+         * @return \Polars\Series
+         *
+         * @return \Polars\Series
+         */
+        public function minHorizontal(): \Polars\Series {}
+
         /**
          * Get the number of unique values per column
          *
@@ -617,6 +711,27 @@ namespace Polars {
          */
         public function reverse(): \Polars\DataFrame {}
 
+        /**
+         * Group by lookback windows anchored on every row of the index column, optionally per group of `groupBy` keys.
+         * The window of a row with index value `t` is `(t + offset, t + offset + period]` by default.
+         * The index column must be sorted (within each group)
+         * @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+         * @param string $period Length of each window, e.g. '30d' or '3i'
+         * @param string|null $offset Offset of the window starts (default: -$period)
+         * @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+         * @return \Polars\LazyGroupBy
+         * @throws Polars\Exception
+         *
+         * @param mixed $indexColumn
+         * @param string $period
+         * @param string|null $offset
+         * @param \Polars\ClosedInterval $closed
+         * @param mixed $groupBy
+         * @return \Polars\LazyGroupBy
+         */
+        public function rolling(mixed $indexColumn, string $period, ?string $offset = null, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right, mixed $groupBy = null): \Polars\LazyGroupBy {}
+
         /**
          * Get a single row as an associative array (supports negative indexing)
          *
@@ -667,13 +782,16 @@ namespace Polars {
         public function selectSeq(array $expressions): \Polars\DataFrame {}
 
         /**
-         * Set the sorted flag on a column
+         * Set the sorted flag on one or more columns
+         * @param string|string[] $column Column name(s)
+         * @param bool $descending Whether the columns are sorted in descending order
+         * @throws Polars\Exception
          *
-         * @param string $column
+         * @param mixed $column
          * @param bool $descending
          * @return void
          */
-        public function setSorted(string $column, bool $descending = false): void {}
+        public function setSorted(mixed $column, bool $descending = false): void {}
 
         /**
          * @return int[] Get the shape of the DataFrame as [rows, columns]
@@ -708,21 +826,22 @@ namespace Polars {
         public function slice(int $offset, int $length): \Polars\DataFrame {}
 
         /**
-         * Sort DataFrame by one or more columns
-         * @param string|string[] $by Column name or array of column names to sort by
-         * @param bool $descending Sort order (applies to all columns)
-         * @param bool $nullsLast Put null values last
+         * Sort DataFrame by one or more columns or expressions
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to sort by
+         * @param bool|bool[]|null $descending Sort in descending order, one flag per key or a single flag for all
+         * @param bool|bool[]|null $nullsLast Put null values last, one flag per key or a single flag for all (default: true)
          * @param bool $maintainOrder Maintain order of equal elements (stable sort)
          * @param bool $multithreaded Use multithreaded sorting
+         * @throws Polars\Exception
          *
          * @param mixed $by
-         * @param bool $descending
-         * @param bool $nullsLast
+         * @param mixed $descending
+         * @param mixed $nullsLast
          * @param bool $maintainOrder
          * @param bool $multithreaded
          * @return \Polars\DataFrame
          */
-        public function sort(mixed $by, bool $descending = false, bool $nullsLast = true, bool $maintainOrder = false, bool $multithreaded = true): \Polars\DataFrame {}
+        public function sort(mixed $by, mixed $descending = null, mixed $nullsLast = null, bool $maintainOrder = false, bool $multithreaded = true): \Polars\DataFrame {}
 
         /**
          * Execute a SQL query against this DataFrame
@@ -749,6 +868,15 @@ namespace Polars {
          */
         public function sum(): \Polars\DataFrame {}
 
+        /**
+         * Sum all values horizontally across columns.
+         * @return \Polars\Series
+         *
+         * @param bool $ignoreNulls
+         * @return \Polars\Series
+         */
+        public function sumHorizontal(bool $ignoreNulls = true): \Polars\Series {}
+
         /**
          * Get the last n rows
          *
@@ -785,13 +913,17 @@ namespace Polars {
         public function toSeries(): \Polars\Series {}
 
         /**
-         * Get the top k rows by a column
+         * Get the k rows with the largest values of the `by` column(s)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+         * @param bool|bool[]|null $reverse Take the smallest values instead, one flag per key or a single flag for all
+         * @throws Polars\Exception
          *
          * @param int $k
-         * @param string $by
+         * @param mixed $by
+         * @param mixed $reverse
          * @return \Polars\DataFrame
          */
-        public function topK(int $k, string $by): \Polars\DataFrame {}
+        public function topK(int $k, mixed $by, mixed $reverse = null): \Polars\DataFrame {}
 
         /**
          * Transpose the DataFrame
@@ -956,6 +1088,13 @@ namespace Polars {
          */
         public function __construct(mixed $value) {}
 
+        /**
+         * Compute absolute values
+         *
+         * @return \Polars\Expr
+         */
+        public function abs(): \Polars\Expr {}
+
         /**
          * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
          *
@@ -977,6 +1116,16 @@ namespace Polars {
          */
         public static function all(): \Polars\Expr {}
 
+        /**
+         * Compute the bitwise AND horizontally across boolean columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Boolean columns
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
+         * @return \Polars\Expr
+         */
+        public static function allHorizontal(mixed $exprs): \Polars\Expr {}
+
         /**
          * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
          *
@@ -992,375 +1141,2865 @@ namespace Polars {
         public function any(bool $ignoreNulls = true): \Polars\Expr {}
 
         /**
+         * Compute the bitwise OR horizontally across boolean columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Boolean columns
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
          * @return \Polars\Expr
          */
-        public function approxNUnique(): \Polars\Expr {}
-
-        /**
-         * @return \Polars\Expr
-         */
-        public function argMax(): \Polars\Expr {}
+        public static function anyHorizontal(mixed $exprs): \Polars\Expr {}
 
         /**
+         * Append the values of another expression
+         * @param mixed $other Expression or value to append
+         * @param bool $upcast Cast both sides to a common supertype if the types differ
+         * @throws Polars\Exception
+         *
+         * @param mixed $other
+         * @param bool $upcast
          * @return \Polars\Expr
          */
-        public function argMin(): \Polars\Expr {}
+        public function appendExpr(mixed $other, bool $upcast = true): \Polars\Expr {}
 
         /**
          * @return \Polars\Expr
          */
-        public function bitwiseAnd(): \Polars\Expr {}
+        public function approxNUnique(): \Polars\Expr {}
 
         /**
+         * Alias of `quantile($quantile, QuantileMethod::Nearest)`. Polars has no sketch-based
+         * quantile, so the result is the exact nearest-rank value, not an approximation
+         * @throws Polars\Exception
+         *
+         * @param float $quantile
          * @return \Polars\Expr
          */
-        public function bitwiseOr(): \Polars\Expr {}
+        public function approxQuantile(float $quantile): \Polars\Expr {}
 
         /**
+         * Compute the element-wise value for the inverse cosine
+         *
          * @return \Polars\Expr
          */
-        public function bitwiseXor(): \Polars\Expr {}
+        public function arccos(): \Polars\Expr {}
 
         /**
-         * Cast to a data type
+         * Compute the element-wise value for the inverse hyperbolic cosine
          *
-         * @param string $dtype
          * @return \Polars\Expr
          */
-        public function cast(string $dtype): \Polars\Expr {}
+        public function arccosh(): \Polars\Expr {}
 
         /**
-         * @param string $name
+         * Compute the element-wise value for the inverse sine
+         *
          * @return \Polars\Expr
          */
-        public static function col(string $name): \Polars\Expr {}
+        public function arcsin(): \Polars\Expr {}
 
         /**
-         * @param array $names
+         * Compute the element-wise value for the inverse hyperbolic sine
+         *
          * @return \Polars\Expr
          */
-        public static function cols(array $names): \Polars\Expr {}
+        public function arcsinh(): \Polars\Expr {}
 
         /**
+         * Compute the element-wise value for the inverse tangent
+         *
          * @return \Polars\Expr
          */
-        public function count(): \Polars\Expr {}
+        public function arctan(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Compute the element-wise value for the inverse hyperbolic tangent
          *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function div(mixed $other): \Polars\Expr {}
+        public function arctanh(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
-         *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function eq(mixed $other): \Polars\Expr {}
+        public function argMax(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
-         *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function eqMissing(mixed $other): \Polars\Expr {}
+        public function argMin(): \Polars\Expr {}
 
         /**
-         * Exclude specific columns from the expression
+         * Get the index values that would sort this column
          *
-         * @param mixed $columns
+         * @param bool $descending
+         * @param bool $nullsLast
          * @return \Polars\Expr
          */
-        public function exclude(mixed $columns): \Polars\Expr {}
+        public function argSort(bool $descending = false, bool $nullsLast = true): \Polars\Expr {}
 
         /**
+         * Get the indices of the true values
+         *
          * @return \Polars\Expr
          */
-        public function first(): \Polars\Expr {}
+        public function argTrue(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Get the index of the first occurrence of every unique value
          *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function floorDiv(mixed $other): \Polars\Expr {}
+        public function argUnique(): \Polars\Expr {}
 
         /**
-         * Take every nth value
+         * Get the indices where the condition is true
+         * @param string|\Polars\Expr $condition Boolean expression or name of a boolean column
+         * @throws Polars\Exception
          *
-         * @param int $n
-         * @param int $offset
+         * @param mixed $condition
          * @return \Polars\Expr
          */
-        public function gatherEvery(int $n, int $offset = 0): \Polars\Expr {}
+        public static function argWhere(mixed $condition): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Fill null values with the next non-null value
+         * @throws Polars\Exception
          *
-         * @param mixed $other
+         * @param int|null $limit
          * @return \Polars\Expr
          */
-        public function ge(mixed $other): \Polars\Expr {}
+        public function backwardFill(?int $limit = null): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Bitwise AND of all values (aggregation), or element-wise with `other` when it is given
+         * @param int|\Polars\Expr|null $other Expression or integer to combine with element-wise
+         * @throws Polars\Exception
          *
          * @param mixed $other
          * @return \Polars\Expr
          */
-        public function gt(mixed $other): \Polars\Expr {}
+        public function bitwiseAnd(mixed $other = null): \Polars\Expr {}
 
         /**
+         * Count the number of set bits of every element
+         *
          * @return \Polars\Expr
          */
-        public function hasNulls(): \Polars\Expr {}
+        public function bitwiseCountOnes(): \Polars\Expr {}
 
         /**
+         * Count the number of unset bits of every element
+         *
          * @return \Polars\Expr
          */
-        public function implode(): \Polars\Expr {}
+        public function bitwiseCountZeros(): \Polars\Expr {}
 
         /**
-         * @param mixed $lowerBound
-         * @param mixed $upperBound
-         * @param \Polars\ClosedInterval $closed
+         * Count the number of leading set bits of every element
+         *
          * @return \Polars\Expr
          */
-        public function isBetween(mixed $lowerBound, mixed $upperBound, \Polars\ClosedInterval $closed): \Polars\Expr {}
+        public function bitwiseLeadingOnes(): \Polars\Expr {}
 
         /**
-         * @param bool $fisher
-         * @param bool $bias
+         * Count the number of leading unset bits of every element
+         *
          * @return \Polars\Expr
          */
-        public function kurtosis(bool $fisher = true, bool $bias = true): \Polars\Expr {}
+        public function bitwiseLeadingZeros(): \Polars\Expr {}
 
         /**
+         * Bitwise OR of all values (aggregation), or element-wise with `other` when it is given
+         * @param int|\Polars\Expr|null $other Expression or integer to combine with element-wise
+         * @throws Polars\Exception
+         *
+         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function last(): \Polars\Expr {}
+        public function bitwiseOr(mixed $other = null): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Count the number of trailing set bits of every element
          *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function le(mixed $other): \Polars\Expr {}
+        public function bitwiseTrailingOnes(): \Polars\Expr {}
 
         /**
+         * Count the number of trailing unset bits of every element
+         *
          * @return \Polars\Expr
          */
-        public function len(): \Polars\Expr {}
+        public function bitwiseTrailingZeros(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Bitwise XOR of all values (aggregation), or element-wise with `other` when it is given
+         * @param int|\Polars\Expr|null $other Expression or integer to combine with element-wise
+         * @throws Polars\Exception
          *
          * @param mixed $other
          * @return \Polars\Expr
          */
-        public function lt(mixed $other): \Polars\Expr {}
+        public function bitwiseXor(mixed $other = null): \Polars\Expr {}
 
         /**
+         * Return the `k` smallest elements
+         *
+         * @param int $k
          * @return \Polars\Expr
          */
-        public function max(): \Polars\Expr {}
+        public function bottomK(int $k = 5): \Polars\Expr {}
 
         /**
+         * Return the elements corresponding to the `k` smallest elements of the `by` column(s)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) used to determine the smallest elements
+         * @param bool|bool[]|null $reverse Consider the `k` largest elements of the `by` column(s) instead, per column
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param int $k
+         * @param mixed $reverse
          * @return \Polars\Expr
          */
-        public function mean(): \Polars\Expr {}
+        public function bottomKBy(mixed $by, int $k = 5, mixed $reverse = null): \Polars\Expr {}
 
         /**
+         * Cast to a data type
+         *
+         * @param string $dtype
          * @return \Polars\Expr
          */
-        public function median(): \Polars\Expr {}
+        public function cast(string $dtype): \Polars\Expr {}
 
         /**
+         * Compute the cube root of the elements
+         *
          * @return \Polars\Expr
          */
-        public function min(): \Polars\Expr {}
+        public function cbrt(): \Polars\Expr {}
 
         /**
+         * Rounds up to the nearest integer value
+         *
          * @return \Polars\Expr
          */
-        public function mode(): \Polars\Expr {}
+        public function ceil(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Set values outside the given boundaries to the boundary value. Null bound means no bound on that side
+         * @param int|float|null|\Polars\Expr $lowerBound
+         * @param int|float|null|\Polars\Expr $upperBound
+         * @throws Polars\Exception
          *
-         * @param mixed $other
+         * @param mixed $lowerBound
+         * @param mixed $upperBound
          * @return \Polars\Expr
          */
-        public function modulo(mixed $other): \Polars\Expr {}
+        public function clip(mixed $lowerBound = null, mixed $upperBound = null): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Take the first non-null value of the given columns, row by row
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns in order of preference
+         * @throws Polars\Exception
          *
-         * @param mixed $other
+         * @param mixed $exprs
          * @return \Polars\Expr
          */
-        public function mul(mixed $other): \Polars\Expr {}
+        public static function coalesce(mixed $exprs): \Polars\Expr {}
 
         /**
+         * @param string $name
          * @return \Polars\Expr
          */
-        public function nUnique(): \Polars\Expr {}
+        public static function col(string $name): \Polars\Expr {}
 
         /**
+         * @param array $names
          * @return \Polars\Expr
          */
-        public function nanMax(): \Polars\Expr {}
+        public static function cols(array $names): \Polars\Expr {}
 
         /**
+         * Horizontally concatenate columns into a single list column
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to concatenate
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
          * @return \Polars\Expr
          */
-        public function nanMin(): \Polars\Expr {}
+        public static function concatList(mixed $exprs): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Horizontally concatenate columns into a single string column
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to concatenate
+         * @throws Polars\Exception
          *
-         * @param mixed $other
+         * @param mixed $exprs
+         * @param string $separator
+         * @param bool $ignoreNulls
          * @return \Polars\Expr
          */
-        public function ne(mixed $other): \Polars\Expr {}
+        public static function concatStr(mixed $exprs, string $separator = "", bool $ignoreNulls = false): \Polars\Expr {}
 
         /**
+         * Compute the correlation between two columns
+         * @param string|\Polars\Expr $a First column
+         * @param string|\Polars\Expr $b Second column
+         * @param string $method One of: 'pearson', 'spearman'
+         * @param bool $propagateNans Make the spearman correlation NaN when any value is NaN
+         * @throws Polars\Exception
+         *
+         * @param mixed $a
+         * @param mixed $b
+         * @param string $method
+         * @param bool $propagateNans
          * @return \Polars\Expr
          */
-        public function neg(): \Polars\Expr {}
+        public static function corr(mixed $a, mixed $b, string $method = "pearson", bool $propagateNans = false): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Compute the element-wise value for the cosine
          *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function neqMissing(mixed $other): \Polars\Expr {}
+        public function cos(): \Polars\Expr {}
 
         /**
+         * Compute the element-wise value for the hyperbolic cosine
+         *
          * @return \Polars\Expr
          */
-        public function nullCount(): \Polars\Expr {}
+        public function cosh(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Compute the element-wise value for the cotangent
          *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function or_(mixed $other): \Polars\Expr {}
+        public function cot(): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
-         *
-         * @param mixed $other
          * @return \Polars\Expr
          */
-        public function pow(mixed $other): \Polars\Expr {}
+        public function count(): \Polars\Expr {}
 
         /**
+         * Compute the covariance between two columns
+         * @param string|\Polars\Expr $a First column
+         * @param string|\Polars\Expr $b Second column
+         * @throws Polars\Exception
+         *
+         * @param mixed $a
+         * @param mixed $b
+         * @param int $ddof
          * @return \Polars\Expr
          */
-        public function product(): \Polars\Expr {}
+        public static function cov(mixed $a, mixed $b, int $ddof = 1): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $quantile Quantile value (0.0 to 1.0)
-         * @throws Polars\Exception
+         * Get an array with the cumulative count of non-null values computed at every element
          *
-         * @param mixed $quantile
-         * @param \Polars\QuantileMethod $interpolation
+         * @param bool $reverse
          * @return \Polars\Expr
          */
-        public function quantile(mixed $quantile, \Polars\QuantileMethod $interpolation): \Polars\Expr {}
+        public function cumCount(bool $reverse = false): \Polars\Expr {}
 
         /**
-         * Shift values by n positions
+         * Get an array with the cumulative max computed at every element
          *
-         * @param mixed $n
+         * @param bool $reverse
          * @return \Polars\Expr
          */
-        public function shift(mixed $n): \Polars\Expr {}
+        public function cumMax(bool $reverse = false): \Polars\Expr {}
 
         /**
-         * @param bool $bias
+         * Get an array with the cumulative min computed at every element
+         *
+         * @param bool $reverse
          * @return \Polars\Expr
          */
-        public function skew(bool $bias = true): \Polars\Expr {}
+        public function cumMin(bool $reverse = false): \Polars\Expr {}
 
         /**
-         * @param int $ddof
+         * Get an array with the cumulative product computed at every element
+         *
+         * @param bool $reverse
          * @return \Polars\Expr
          */
-        public function std(int $ddof = 1): \Polars\Expr {}
+        public function cumProd(bool $reverse = false): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Get an array with the cumulative sum computed at every element
          *
-         * @param mixed $other
+         * @param bool $reverse
          * @return \Polars\Expr
          */
-        public function sub(mixed $other): \Polars\Expr {}
+        public function cumSum(bool $reverse = false): \Polars\Expr {}
 
         /**
+         * Run an expression over a sliding window that increases by one slot every iteration.
+         * Use `Expr::element()` to refer to the values in the window
+         * @throws Polars\Exception
+         *
+         * @param \Polars\Expr $expr
+         * @param int $minSamples
          * @return \Polars\Expr
          */
-        public function sum(): \Polars\Expr {}
+        public function cumulativeEval(\Polars\Expr $expr, int $minSamples = 1): \Polars\Expr {}
 
         /**
+         * Bin continuous values into discrete categories
+         * @param array $breaks Bin boundaries, in ascending order
+         * @param array|null $labels Names of the categories; must have one more element than `breaks`
+         * @param bool $leftClosed Make the intervals closed on the left instead of on the right
+         * @param bool $includeBreaks Return a struct with the right endpoint of the bin and the category
+         * @throws Polars\Exception
+         *
+         * @param array $breaks
+         * @param array|null $labels
+         * @param bool $leftClosed
+         * @param bool $includeBreaks
          * @return \Polars\Expr
          */
-        public function uniqueCounts(): \Polars\Expr {}
+        public function cut(array $breaks, ?array $labels = null, bool $leftClosed = false, bool $includeBreaks = false): \Polars\Expr {}
 
         /**
-         * @param int $ddof
+         * Generate a range of dates
+         * @param string|\Polars\Expr $start First date, e.g. '2024-01-01'
+         * @param string|\Polars\Expr $end Last date, e.g. '2024-12-31'
+         * @param string $interval Interval between dates, e.g. '1d', '1w' or '1mo'
+         * @throws Polars\Exception
+         *
+         * @param mixed $start
+         * @param mixed $end
+         * @param string $interval
+         * @param \Polars\ClosedInterval $closed
          * @return \Polars\Expr
          */
-        public function variance(int $ddof = 1): \Polars\Expr {}
+        public static function dateRange(mixed $start, mixed $end, string $interval = "1d", \Polars\ClosedInterval $closed = PolarsClosedInterval :: Both): \Polars\Expr {}
 
         /**
-         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         * Generate a range of datetimes
+         * @param string|\Polars\Expr $start First datetime, e.g. '2024-01-01 00:00:00'
+         * @param string|\Polars\Expr $end Last datetime, e.g. '2024-01-02 00:00:00'
+         * @param string $interval Interval between datetimes, e.g. '1h' or '15m'
+         * @param string|null $timeUnit One of: 'ns', 'us', 'ms'
+         * @param string|null $timeZone Time zone of the result, e.g. 'Europe/Warsaw'
+         * @throws Polars\Exception
          *
-         * @param mixed $other
+         * @param mixed $start
+         * @param mixed $end
+         * @param string $interval
+         * @param \Polars\ClosedInterval $closed
+         * @param string|null $timeUnit
+         * @param string|null $timeZone
+         * @return \Polars\Expr
+         */
+        public static function datetimeRange(mixed $start, mixed $end, string $interval = "1d", \Polars\ClosedInterval $closed = PolarsClosedInterval :: Both, ?string $timeUnit = null, ?string $timeZone = null): \Polars\Expr {}
+
+        /**
+         * Convert from radians to degrees
+         *
+         * @return \Polars\Expr
+         */
+        public function degrees(): \Polars\Expr {}
+
+        /**
+         * Calculate the first discrete difference between shifted items
+         * @param string $nullBehavior One of: 'ignore', 'drop'
+         * @throws Polars\Exception
+         *
+         * @param int $n
+         * @param string $nullBehavior
+         * @return \Polars\Expr
+         */
+        public function diff(int $n = 1, string $nullBehavior = "ignore"): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function div(mixed $other): \Polars\Expr {}
+
+        /**
+         * Compute the dot/inner product between two expressions
+         * @param int|float|\Polars\Expr $other
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function dot(mixed $other): \Polars\Expr {}
+
+        /**
+         * Drop floating point NaN values
+         *
+         * @return \Polars\Expr
+         */
+        public function dropNans(): \Polars\Expr {}
+
+        /**
+         * Drop null values
+         *
+         * @return \Polars\Expr
+         */
+        public function dropNulls(): \Polars\Expr {}
+
+        /**
+         * Alias for an element being evaluated in an eval expression (e.g. `cumulativeEval`)
+         *
+         * @return \Polars\Expr
+         */
+        public static function element(): \Polars\Expr {}
+
+        /**
+         * Compute the entropy as `-sum(pk * log(pk))`, where `pk` are discrete probabilities.
+         * Natural logarithm is used when base is null
+         * @param bool $normalize Normalize `pk` so that it sums to 1
+         *
+         * @param float|null $base
+         * @param bool $normalize
+         * @return \Polars\Expr
+         */
+        public function entropy(?float $base = null, bool $normalize = true): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function eq(mixed $other): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function eqMissing(mixed $other): \Polars\Expr {}
+
+        /**
+         * Compute exponentially-weighted moving mean.
+         * Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+         * @throws Polars\Exception
+         *
+         * @param float|null $com
+         * @param float|null $span
+         * @param float|null $halfLife
+         * @param float|null $alpha
+         * @param bool $adjust
+         * @param int $minPeriods
+         * @param bool $ignoreNulls
+         * @return \Polars\Expr
+         */
+        public function ewmMean(?float $com = null, ?float $span = null, ?float $halfLife = null, ?float $alpha = null, bool $adjust = true, int $minPeriods = 1, bool $ignoreNulls = false): \Polars\Expr {}
+
+        /**
+         * Compute time-based exponentially weighted moving average
+         * @param string|\Polars\Expr $by Temporal or integer column with the times of the values
+         * @param string $halfLife Duration string such as '1d' over which an observation decays to half its value
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $halfLife
+         * @return \Polars\Expr
+         */
+        public function ewmMeanBy(mixed $by, string $halfLife): \Polars\Expr {}
+
+        /**
+         * Compute exponentially-weighted moving standard deviation.
+         * Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+         * @throws Polars\Exception
+         *
+         * @param float|null $com
+         * @param float|null $span
+         * @param float|null $halfLife
+         * @param float|null $alpha
+         * @param bool $adjust
+         * @param bool $bias
+         * @param int $minPeriods
+         * @param bool $ignoreNulls
+         * @return \Polars\Expr
+         */
+        public function ewmStd(?float $com = null, ?float $span = null, ?float $halfLife = null, ?float $alpha = null, bool $adjust = true, bool $bias = false, int $minPeriods = 1, bool $ignoreNulls = false): \Polars\Expr {}
+
+        /**
+         * Compute exponentially-weighted moving variance.
+         * Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+         * @throws Polars\Exception
+         *
+         * @param float|null $com
+         * @param float|null $span
+         * @param float|null $halfLife
+         * @param float|null $alpha
+         * @param bool $adjust
+         * @param bool $bias
+         * @param int $minPeriods
+         * @param bool $ignoreNulls
+         * @return \Polars\Expr
+         */
+        public function ewmVar(?float $com = null, ?float $span = null, ?float $halfLife = null, ?float $alpha = null, bool $adjust = true, bool $bias = false, int $minPeriods = 1, bool $ignoreNulls = false): \Polars\Expr {}
+
+        /**
+         * Exclude specific columns from the expression
+         *
+         * @param mixed $columns
+         * @return \Polars\Expr
+         */
+        public function exclude(mixed $columns): \Polars\Expr {}
+
+        /**
+         * Compute the exponential, element-wise
+         *
+         * @return \Polars\Expr
+         */
+        public function exp(): \Polars\Expr {}
+
+        /**
+         * Explode a list column into one row per element
+         *
+         * @return \Polars\Expr
+         */
+        public function explode(): \Polars\Expr {}
+
+        /**
+         * Fill floating point NaN values with a value
+         * @param mixed $value Value or Expr to fill NaN values with
+         * @throws Polars\Exception
+         *
+         * @param mixed $value
+         * @return \Polars\Expr
+         */
+        public function fillNan(mixed $value): \Polars\Expr {}
+
+        /**
+         * Fill null values with a value or using a strategy. Exactly one of `value` or `strategy` must be given
+         * @param mixed $value Value or Expr to fill nulls with
+         * @param string|null $strategy One of: 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'
+         * @param int|null $limit Maximum number of consecutive nulls to fill (forward and backward strategies only)
+         * @throws Polars\Exception
+         *
+         * @param mixed $value
+         * @param string|null $strategy
+         * @param int|null $limit
+         * @return \Polars\Expr
+         */
+        public function fillNull(mixed $value = null, ?string $strategy = null, ?int $limit = null): \Polars\Expr {}
+
+        /**
+         * Filter the values by a boolean predicate. Mostly useful in group by aggregations,
+         * e.g. the sum of amounts where status is paid
+         * @param string|\Polars\Expr $predicate Boolean expression or name of a boolean column
+         * @throws Polars\Exception
+         *
+         * @param mixed $predicate
+         * @return \Polars\Expr
+         */
+        public function filter(mixed $predicate): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function first(): \Polars\Expr {}
+
+        /**
+         * Flatten a list column into one row per element. Alias for `explode`
+         *
+         * @return \Polars\Expr
+         */
+        public function flatten(): \Polars\Expr {}
+
+        /**
+         * Rounds down to the nearest integer value
+         *
+         * @return \Polars\Expr
+         */
+        public function floor(): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function floorDiv(mixed $other): \Polars\Expr {}
+
+        /**
+         * Accumulate over multiple columns horizontally, starting from `acc`.
+         * The function is called with `(Expr $acc, Expr $column)` while the expression is built
+         * and must return the new accumulator Expr
+         * @param mixed $acc Initial value or Expr of the accumulator
+         * @param callable $function Combines the accumulator with the next column
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to fold over
+         * @throws Polars\Exception
+         *
+         * @param mixed $acc
+         * @param callable $function
+         * @param mixed $exprs
+         * @return \Polars\Expr
+         */
+        public static function fold(mixed $acc, callable $function, mixed $exprs): \Polars\Expr {}
+
+        /**
+         * Fill null values with the last non-null value
+         * @throws Polars\Exception
+         *
+         * @param int|null $limit
+         * @return \Polars\Expr
+         */
+        public function forwardFill(?int $limit = null): \Polars\Expr {}
+
+        /**
+         * Take values by index. Negative indices count from the end
+         * @param int|int[]|\Polars\Series|\Polars\Expr $indices Index or indices to take
+         * @throws Polars\Exception
+         *
+         * @param mixed $indices
+         * @return \Polars\Expr
+         */
+        public function gather(mixed $indices): \Polars\Expr {}
+
+        /**
+         * Take every nth value
+         *
+         * @param int $n
+         * @param int $offset
+         * @return \Polars\Expr
+         */
+        public function gatherEvery(int $n, int $offset = 0): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function ge(mixed $other): \Polars\Expr {}
+
+        /**
+         * Take a single value by index, returning a scalar. Negative indices count from the end
+         * @param int|\Polars\Expr $index Index of the value
+         * @throws Polars\Exception
+         *
+         * @param mixed $index
+         * @return \Polars\Expr
+         */
+        public function get(mixed $index): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function gt(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function hasNulls(): \Polars\Expr {}
+
+        /**
+         * Hash every element to a UInt64. The hash is deterministic for given seeds within
+         * one Polars version, but may change between versions
+         * @param int $seed Seed of the hash; other seeds default to it
+         * @throws Polars\Exception
+         *
+         * @param int $seed
+         * @param int|null $seed1
+         * @param int|null $seed2
+         * @param int|null $seed3
+         * @return \Polars\Expr
+         */
+        public function hash(int $seed = 0, ?int $seed1 = null, ?int $seed2 = null, ?int $seed3 = null): \Polars\Expr {}
+
+        /**
+         * Get the first n values
+         *
+         * @param int $n
+         * @return \Polars\Expr
+         */
+        public function head(int $n = 10): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function implode(): \Polars\Expr {}
+
+        /**
+         * Generate a range of integers. When `end` is not given, the range ends at the number of rows
+         * @param int|\Polars\Expr $start Start of the range (inclusive)
+         * @param int|\Polars\Expr|null $end End of the range (exclusive)
+         * @param string|\Polars\DataType|null $dtype Integer data type of the result, int64 by default
+         * @throws Polars\Exception
+         *
+         * @param mixed $start
+         * @param mixed $end
+         * @param int $step
+         * @param mixed $dtype
+         * @return \Polars\Expr
+         */
+        public static function intRange(mixed $start = null, mixed $end = null, int $step = 1, mixed $dtype = null): \Polars\Expr {}
+
+        /**
+         * Generate a list of integers for every row, from `start` to `end` (exclusive)
+         * @param int|string|\Polars\Expr $start Start value or column
+         * @param int|string|\Polars\Expr $end End value or column
+         * @param int|string|\Polars\Expr $step Step value or column
+         * @param string|\Polars\DataType|null $dtype Integer data type of the list elements, int64 by default
+         * @throws Polars\Exception
+         *
+         * @param mixed $start
+         * @param mixed $end
+         * @param mixed $step
+         * @param mixed $dtype
+         * @return \Polars\Expr
+         */
+        public static function intRanges(mixed $start, mixed $end, mixed $step = null, mixed $dtype = null): \Polars\Expr {}
+
+        /**
+         * Fill null values by interpolating between the surrounding non-null values.
+         * Nulls at the beginning and end remain null
+         * @param string $method One of: 'linear', 'nearest'
+         * @throws Polars\Exception
+         *
+         * @param string $method
+         * @return \Polars\Expr
+         */
+        public function interpolate(string $method = "linear"): \Polars\Expr {}
+
+        /**
+         * @param mixed $lowerBound
+         * @param mixed $upperBound
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function isBetween(mixed $lowerBound, mixed $upperBound, \Polars\ClosedInterval $closed): \Polars\Expr {}
+
+        /**
+         * Get a mask of duplicated values
+         *
+         * @return \Polars\Expr
+         */
+        public function isDuplicated(): \Polars\Expr {}
+
+        /**
+         * Get a mask of finite values
+         *
+         * @return \Polars\Expr
+         */
+        public function isFinite(): \Polars\Expr {}
+
+        /**
+         * Get a mask of the first occurrence of every distinct value
+         *
+         * @return \Polars\Expr
+         */
+        public function isFirstDistinct(): \Polars\Expr {}
+
+        /**
+         * Check if elements of this expression are present in the other collection
+         * @param array|\Polars\Series|\Polars\Expr $other Values to look for. An Expr must evaluate to a list
+         * @throws Polars\Exception
+         *
+         * @param mixed $other
+         * @param bool $nullsEqual
+         * @return \Polars\Expr
+         */
+        public function isIn(mixed $other, bool $nullsEqual = false): \Polars\Expr {}
+
+        /**
+         * Get a mask of infinite values
+         *
+         * @return \Polars\Expr
+         */
+        public function isInfinite(): \Polars\Expr {}
+
+        /**
+         * Get a mask of the last occurrence of every distinct value
+         *
+         * @return \Polars\Expr
+         */
+        public function isLastDistinct(): \Polars\Expr {}
+
+        /**
+         * Get a mask of NaN values (float columns only)
+         *
+         * @return \Polars\Expr
+         */
+        public function isNan(): \Polars\Expr {}
+
+        /**
+         * Get a mask of non-NaN values (float columns only)
+         *
+         * @return \Polars\Expr
+         */
+        public function isNotNan(): \Polars\Expr {}
+
+        /**
+         * Get a mask of non-null values
+         *
+         * @return \Polars\Expr
+         */
+        public function isNotNull(): \Polars\Expr {}
+
+        /**
+         * Get a mask of null values
+         *
+         * @return \Polars\Expr
+         */
+        public function isNull(): \Polars\Expr {}
+
+        /**
+         * Get a mask of unique values
+         *
+         * @return \Polars\Expr
+         */
+        public function isUnique(): \Polars\Expr {}
+
+        /**
+         * @param bool $fisher
+         * @param bool $bias
+         * @return \Polars\Expr
+         */
+        public function kurtosis(bool $fisher = true, bool $bias = true): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function last(): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function le(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function len(): \Polars\Expr {}
+
+        /**
+         * Get the first n values. Alias for `head`
+         *
+         * @param int $n
+         * @return \Polars\Expr
+         */
+        public function limit(int $n = 10): \Polars\Expr {}
+
+        /**
+         * Create a literal expression, optionally cast to a data type
+         * @param mixed $value int, float, string, bool, null or Expr
+         * @param string|\Polars\DataType|null $dtype Data type of the literal
+         * @throws Polars\Exception
+         *
+         * @param mixed $value
+         * @param mixed $dtype
+         * @return \Polars\Expr
+         */
+        public static function lit(mixed $value, mixed $dtype = null): \Polars\Expr {}
+
+        /**
+         * Compute the logarithm to a given base. Natural logarithm is used when base is null
+         *
+         * @param float|null $base
+         * @return \Polars\Expr
+         */
+        public function log(?float $base = null): \Polars\Expr {}
+
+        /**
+         * Compute the base 10 logarithm of the elements
+         *
+         * @return \Polars\Expr
+         */
+        public function log10(): \Polars\Expr {}
+
+        /**
+         * Compute the natural logarithm of the elements plus one
+         *
+         * @return \Polars\Expr
+         */
+        public function log1p(): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function lt(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function max(): \Polars\Expr {}
+
+        /**
+         * Get the maximum value horizontally across columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to compare
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
+         * @return \Polars\Expr
+         */
+        public static function maxHorizontal(mixed $exprs): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function mean(): \Polars\Expr {}
+
+        /**
+         * Compute the mean of all values horizontally across columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to average
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
+         * @param bool $ignoreNulls
+         * @return \Polars\Expr
+         */
+        public static function meanHorizontal(mixed $exprs, bool $ignoreNulls = true): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function median(): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function min(): \Polars\Expr {}
+
+        /**
+         * Get the minimum value horizontally across columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to compare
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
+         * @return \Polars\Expr
+         */
+        public static function minHorizontal(mixed $exprs): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function mode(): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function modulo(mixed $other): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function mul(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function nUnique(): \Polars\Expr {}
+
+        /**
+         * Access methods modifying the output name of the expression
+         *
+         * @return \Polars\ExprNameNameSpace
+         */
+        public function name(): \Polars\ExprNameNameSpace {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function nanMax(): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function nanMin(): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function ne(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function neg(): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function neqMissing(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function nullCount(): \Polars\Expr {}
+
+        /**
+         * Create a column of ones. When `n` is not given, one is created for every row
+         * @param int|\Polars\Expr|null $n Number of elements
+         * @param string|\Polars\DataType|null $dtype Data type of the result, float64 by default
+         * @throws Polars\Exception
+         *
+         * @param mixed $n
+         * @param mixed $dtype
+         * @return \Polars\Expr
+         */
+        public static function ones(mixed $n = null, mixed $dtype = null): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function or_(mixed $other): \Polars\Expr {}
+
+        /**
+         * Compute expressions over the given groups (window function)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $partitionBy Column(s) to group by
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $orderBy Order the window functions by these column(s)
+         * @param string $mappingStrategy One of: 'group_to_rows', 'join', 'explode'
+         * @throws Polars\Exception
+         *
+         * @param mixed $partitionBy
+         * @param mixed $orderBy
+         * @param bool $descending
+         * @param bool $nullsLast
+         * @param string $mappingStrategy
+         * @return \Polars\Expr
+         */
+        public function over(mixed $partitionBy = null, mixed $orderBy = null, bool $descending = false, bool $nullsLast = false, string $mappingStrategy = "group_to_rows"): \Polars\Expr {}
+
+        /**
+         * Computes percentage change between values
+         *
+         * @param int $n
+         * @return \Polars\Expr
+         */
+        public function pctChange(int $n = 1): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function pow(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function product(): \Polars\Expr {}
+
+        /**
+         * Bin continuous values into discrete categories based on their quantiles
+         * @param array|int $quantiles Quantile probabilities between 0 and 1, or the number of bins of equal probability
+         * @param array|null $labels Names of the categories; must have one more element than the number of quantiles
+         * @param bool $leftClosed Make the intervals closed on the left instead of on the right
+         * @param bool $allowDuplicates Drop duplicate breaks instead of raising an error
+         * @param bool $includeBreaks Return a struct with the right endpoint of the bin and the category
+         * @throws Polars\Exception
+         *
+         * @param mixed $quantiles
+         * @param array|null $labels
+         * @param bool $leftClosed
+         * @param bool $allowDuplicates
+         * @param bool $includeBreaks
+         * @return \Polars\Expr
+         */
+        public function qcut(mixed $quantiles, ?array $labels = null, bool $leftClosed = false, bool $allowDuplicates = false, bool $includeBreaks = false): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $quantile Quantile value (0.0 to 1.0)
+         * @throws Polars\Exception
+         *
+         * @param mixed $quantile
+         * @param \Polars\QuantileMethod $interpolation
+         * @return \Polars\Expr
+         */
+        public function quantile(mixed $quantile, \Polars\QuantileMethod $interpolation): \Polars\Expr {}
+
+        /**
+         * Convert from degrees to radians
+         *
+         * @return \Polars\Expr
+         */
+        public function radians(): \Polars\Expr {}
+
+        /**
+         * Assign ranks to data, dealing with ties appropriately
+         * @param string $method One of: 'average', 'min', 'max', 'dense', 'ordinal', 'random'
+         * @throws Polars\Exception
+         *
+         * @param string $method
+         * @param bool $descending
+         * @param int|null $seed
+         * @return \Polars\Expr
+         */
+        public function rank(string $method = "average", bool $descending = false, ?int $seed = null): \Polars\Expr {}
+
+        /**
+         * Accumulate over multiple columns horizontally, using the first column as initial accumulator.
+         * The function is called with `(Expr $acc, Expr $column)` while the expression is built
+         * and must return the new accumulator Expr
+         * @param callable $function Combines the accumulator with the next column
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to reduce
+         * @throws Polars\Exception
+         *
+         * @param callable $function
+         * @param mixed $exprs
+         * @return \Polars\Expr
+         */
+        public static function reduce(callable $function, mixed $exprs): \Polars\Expr {}
+
+        /**
+         * Reinterpret the bits of 64-bit integers as signed (Int64) or unsigned (UInt64)
+         *
+         * @param bool $signed
+         * @return \Polars\Expr
+         */
+        public function reinterpret(bool $signed = true): \Polars\Expr {}
+
+        /**
+         * Repeat a value `n` times. When `n` is not given, the value is repeated for every row
+         * @param mixed $value Value or Expr to repeat
+         * @param int|\Polars\Expr|null $n Number of repetitions
+         * @throws Polars\Exception
+         *
+         * @param mixed $value
+         * @param mixed $n
+         * @return \Polars\Expr
+         */
+        public static function repeat(mixed $value, mixed $n = null): \Polars\Expr {}
+
+        /**
+         * Replace values according to a mapping. Values missing from the mapping are kept as they are,
+         * unless a default is given
+         * @param array $mapping Associative array of old value => new value
+         * @param mixed $default Value (or Expr) used for values missing from the mapping
+         * @throws Polars\Exception
+         *
+         * @param array $mapping
+         * @param mixed $default
+         * @return \Polars\Expr
+         */
+        public function replace(array $mapping, mixed $default = null): \Polars\Expr {}
+
+        /**
+         * Replace all values according to a mapping. Values missing from the mapping raise an error
+         * on evaluation, unless a default is given
+         * @param array $mapping Associative array of old value => new value
+         * @param mixed $default Value (or Expr) used for values missing from the mapping
+         * @param string|null $returnDtype Data type of the result, inferred from the new values if not given
+         * @throws Polars\Exception
+         *
+         * @param array $mapping
+         * @param mixed $default
+         * @param string|null $returnDtype
+         * @return \Polars\Expr
+         */
+        public function replaceStrict(array $mapping, mixed $default = null, ?string $returnDtype = null): \Polars\Expr {}
+
+        /**
+         * Reverse the selection
+         *
+         * @return \Polars\Expr
+         */
+        public function reverse(): \Polars\Expr {}
+
+        /**
+         * Apply a rolling max over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Expr
+         */
+        public function rollingMax(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Expr {}
+
+        /**
+         * Apply a rolling max over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function rollingMaxBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Expr {}
+
+        /**
+         * Apply a rolling mean over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Expr
+         */
+        public function rollingMean(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Expr {}
+
+        /**
+         * Apply a rolling mean over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function rollingMeanBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Expr {}
+
+        /**
+         * Apply a rolling median over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Expr
+         */
+        public function rollingMedian(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Expr {}
+
+        /**
+         * Apply a rolling median over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function rollingMedianBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Expr {}
+
+        /**
+         * Apply a rolling min over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Expr
+         */
+        public function rollingMin(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Expr {}
+
+        /**
+         * Apply a rolling min over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function rollingMinBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Expr {}
+
+        /**
+         * Apply a rolling quantile over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param float $quantile
+         * @param int $windowSize
+         * @param \Polars\QuantileMethod $interpolation
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Expr
+         */
+        public function rollingQuantile(float $quantile, int $windowSize, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Expr {}
+
+        /**
+         * Apply a rolling quantile over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param float $quantile
+         * @param \Polars\QuantileMethod $interpolation
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function rollingQuantileBy(mixed $by, string $windowSize, float $quantile, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Expr {}
+
+        /**
+         * Apply a rolling standard deviation over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @param int $ddof
+         * @return \Polars\Expr
+         */
+        public function rollingStd(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null, int $ddof = 1): \Polars\Expr {}
+
+        /**
+         * Apply a rolling standard deviation over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @param int $ddof
+         * @return \Polars\Expr
+         */
+        public function rollingStdBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right, int $ddof = 1): \Polars\Expr {}
+
+        /**
+         * Apply a rolling sum over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Expr
+         */
+        public function rollingSum(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Expr {}
+
+        /**
+         * Apply a rolling sum over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public function rollingSumBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Expr {}
+
+        /**
+         * Apply a rolling variance over a fixed window of `windowSize` values
+         * @throws Polars\Exception
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @param int $ddof
+         * @return \Polars\Expr
+         */
+        public function rollingVar(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null, int $ddof = 1): \Polars\Expr {}
+
+        /**
+         * Apply a rolling variance over a temporal window (e.g. '7d') based on the `by` column
+         * @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @param int $ddof
+         * @return \Polars\Expr
+         */
+        public function rollingVarBy(mixed $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right, int $ddof = 1): \Polars\Expr {}
+
+        /**
+         * Round underlying floating point data by `decimals` digits
+         * @param string $mode One of: 'half_to_even', 'half_away_from_zero'
+         * @throws Polars\Exception
+         *
+         * @param int $decimals
+         * @param string $mode
+         * @return \Polars\Expr
+         */
+        public function round(int $decimals = 0, string $mode = "half_to_even"): \Polars\Expr {}
+
+        /**
+         * Round to a number of significant figures
+         *
+         * @param int $digits
+         * @return \Polars\Expr
+         */
+        public function roundSigFigs(int $digits): \Polars\Expr {}
+
+        /**
+         * Sample from this expression. Exactly one of `n` or `fraction` must be given
+         * @throws Polars\Exception
+         *
+         * @param int|null $n
+         * @param float|null $fraction
+         * @param bool $withReplacement
+         * @param bool $shuffle
+         * @param int|null $seed
+         * @return \Polars\Expr
+         */
+        public function sample(?int $n = null, ?float $fraction = null, bool $withReplacement = false, bool $shuffle = false, ?int $seed = null): \Polars\Expr {}
+
+        /**
+         * Shift values by n positions
+         *
+         * @param mixed $n
+         * @return \Polars\Expr
+         */
+        public function shift(mixed $n): \Polars\Expr {}
+
+        /**
+         * Shift the bits of every integer to the left, keeping the data type.
+         * Bits shifted past the width of the type are lost
+         * @throws Polars\Exception
+         *
+         * @param int $n
+         * @return \Polars\Expr
+         */
+        public function shiftLeft(int $n): \Polars\Expr {}
+
+        /**
+         * Shift the bits of every integer to the right, keeping the data type.
+         * The sign of signed integers is kept
+         * @throws Polars\Exception
+         *
+         * @param int $n
+         * @return \Polars\Expr
+         */
+        public function shiftRight(int $n): \Polars\Expr {}
+
+        /**
+         * Shuffle the contents of this expression
+         * @throws Polars\Exception
+         *
+         * @param int|null $seed
+         * @return \Polars\Expr
+         */
+        public function shuffle(?int $seed = null): \Polars\Expr {}
+
+        /**
+         * Compute the element-wise sign function on numeric types (-1, 0 or 1)
+         *
+         * @return \Polars\Expr
+         */
+        public function sign(): \Polars\Expr {}
+
+        /**
+         * Compute the element-wise value for the sine
+         *
+         * @return \Polars\Expr
+         */
+        public function sin(): \Polars\Expr {}
+
+        /**
+         * Compute the element-wise value for the hyperbolic sine
+         *
+         * @return \Polars\Expr
+         */
+        public function sinh(): \Polars\Expr {}
+
+        /**
+         * @param bool $bias
+         * @return \Polars\Expr
+         */
+        public function skew(bool $bias = true): \Polars\Expr {}
+
+        /**
+         * Get a slice of the values. A negative offset counts from the end;
+         * without length the slice runs to the end
+         *
+         * @param int $offset
+         * @param int|null $length
+         * @return \Polars\Expr
+         */
+        public function slice(int $offset, ?int $length = null): \Polars\Expr {}
+
+        /**
+         * Sort this column. In a group-by context, the groups are sorted
+         *
+         * @param bool $descending
+         * @param bool $nullsLast
+         * @return \Polars\Expr
+         */
+        public function sort(bool $descending = false, bool $nullsLast = true): \Polars\Expr {}
+
+        /**
+         * Sort this column by the ordering of other columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to sort by
+         * @param bool|bool[]|null $descending Sort in descending order, one flag per column or a single flag for all
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param mixed $descending
+         * @param bool $nullsLast
+         * @param bool $maintainOrder
+         * @param bool $multithreaded
+         * @return \Polars\Expr
+         */
+        public function sortBy(mixed $by, mixed $descending = null, bool $nullsLast = true, bool $maintainOrder = false, bool $multithreaded = true): \Polars\Expr {}
+
+        /**
+         * Compute the square root of the elements
+         *
+         * @return \Polars\Expr
+         */
+        public function sqrt(): \Polars\Expr {}
+
+        /**
+         * @param int $ddof
+         * @return \Polars\Expr
+         */
+        public function std(int $ddof = 1): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
+         * @return \Polars\Expr
+         */
+        public function sub(mixed $other): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function sum(): \Polars\Expr {}
+
+        /**
+         * Sum all values horizontally across columns
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to sum
+         * @throws Polars\Exception
+         *
+         * @param mixed $exprs
+         * @param bool $ignoreNulls
+         * @return \Polars\Expr
+         */
+        public static function sumHorizontal(mixed $exprs, bool $ignoreNulls = true): \Polars\Expr {}
+
+        /**
+         * Get the last n values
+         *
+         * @param int $n
+         * @return \Polars\Expr
+         */
+        public function tail(int $n = 10): \Polars\Expr {}
+
+        /**
+         * Compute the element-wise value for the tangent
+         *
+         * @return \Polars\Expr
+         */
+        public function tan(): \Polars\Expr {}
+
+        /**
+         * Compute the element-wise value for the hyperbolic tangent
+         *
+         * @return \Polars\Expr
+         */
+        public function tanh(): \Polars\Expr {}
+
+        /**
+         * Generate a range of times within a day
+         * @param string|\Polars\Expr|null $start First time, e.g. '08:00', midnight by default
+         * @param string|\Polars\Expr|null $end Last time, e.g. '17:30:00', end of the day by default
+         * @param string $interval Interval between times, e.g. '1h' or '30m'
+         * @throws Polars\Exception
+         *
+         * @param mixed $start
+         * @param mixed $end
+         * @param string $interval
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Expr
+         */
+        public static function timeRange(mixed $start = null, mixed $end = null, string $interval = "1h", \Polars\ClosedInterval $closed = PolarsClosedInterval :: Both): \Polars\Expr {}
+
+        /**
+         * Return the `k` largest elements
+         *
+         * @param int $k
+         * @return \Polars\Expr
+         */
+        public function topK(int $k = 5): \Polars\Expr {}
+
+        /**
+         * Return the elements corresponding to the `k` largest elements of the `by` column(s)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) used to determine the largest elements
+         * @param bool|bool[]|null $reverse Consider the `k` smallest elements of the `by` column(s) instead, per column
+         * @throws Polars\Exception
+         *
+         * @param mixed $by
+         * @param int $k
+         * @param mixed $reverse
+         * @return \Polars\Expr
+         */
+        public function topKBy(mixed $by, int $k = 5, mixed $reverse = null): \Polars\Expr {}
+
+        /**
+         * Get unique values of this expression
+         *
+         * @param bool $maintainOrder
+         * @return \Polars\Expr
+         */
+        public function unique(bool $maintainOrder = false): \Polars\Expr {}
+
+        /**
+         * @return \Polars\Expr
+         */
+        public function uniqueCounts(): \Polars\Expr {}
+
+        /**
+         * Count the occurrences of unique values. Returns a struct with the value and a
+         * 'count' field ('proportion' when normalized)
+         *
+         * @param bool $sort
+         * @param bool $normalize
+         * @return \Polars\Expr
+         */
+        public function valueCounts(bool $sort = false, bool $normalize = false): \Polars\Expr {}
+
+        /**
+         * @param int $ddof
+         * @return \Polars\Expr
+         */
+        public function variance(int $ddof = 1): \Polars\Expr {}
+
+        /**
+         * @param int|float|string|bool|null|\Polars\Expr $other Accepts numeric, string, bool, null or PolarsExpr object
+         *
+         * @param mixed $other
          * @return \Polars\Expr
          */
         public function xxor(mixed $other): \Polars\Expr {}
+
+        /**
+         * Create a column of zeros. When `n` is not given, one zero is created for every row
+         * @param int|\Polars\Expr|null $n Number of elements
+         * @param string|\Polars\DataType|null $dtype Data type of the result, float64 by default
+         * @throws Polars\Exception
+         *
+         * @param mixed $n
+         * @param mixed $dtype
+         * @return \Polars\Expr
+         */
+        public static function zeros(mixed $n = null, mixed $dtype = null): \Polars\Expr {}
+    }
+
+    class ExprNameNameSpace {
+        public function __construct() {}
+
+        /**
+         * Keep the original root column name, ignoring any alias
+         *
+         * @return \Polars\Expr
+         */
+        public function keep(): \Polars\Expr {}
+
+        /**
+         * Rename the output by calling a PHP callable with the root column name.
+         * The callable is invoked when the query is resolved and must return a string.
+         * @param callable $function Callable with signature `fn(string $name): string`
+         * @throws Polars\Exception
+         *
+         * @param mixed $function
+         * @return \Polars\Expr
+         */
+        public function map(mixed $function): \Polars\Expr {}
+
+        /**
+         * Add a prefix to the root column name
+         *
+         * @param string $prefix
+         * @return \Polars\Expr
+         */
+        public function prefix(string $prefix): \Polars\Expr {}
+
+        /**
+         * Add a prefix to every field name of a struct column
+         *
+         * @param string $prefix
+         * @return \Polars\Expr
+         */
+        public function prefixFields(string $prefix): \Polars\Expr {}
+
+        /**
+         * Add a suffix to the root column name
+         *
+         * @param string $suffix
+         * @return \Polars\Expr
+         */
+        public function suffix(string $suffix): \Polars\Expr {}
+
+        /**
+         * Add a suffix to every field name of a struct column
+         *
+         * @param string $suffix
+         * @return \Polars\Expr
+         */
+        public function suffixFields(string $suffix): \Polars\Expr {}
+
+        /**
+         * Convert the root column name to lowercase
+         *
+         * @return \Polars\Expr
+         */
+        public function toLowercase(): \Polars\Expr {}
+
+        /**
+         * Convert the root column name to uppercase
+         *
+         * @return \Polars\Expr
+         */
+        public function toUppercase(): \Polars\Expr {}
+    }
+
+    class GroupBy implements \IteratorAggregate {
+        public function __construct() {}
+
+        /**
+         * Aggregate using expressions
+         * @param \Polars\Expr[] $expressions
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param array $expressions
+         * @return \Polars\DataFrame
+         */
+        public function agg(array $expressions): \Polars\DataFrame {}
+
+        /**
+         * Aggregate the values of every group into lists
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function all(): \Polars\DataFrame {}
+
+        /**
+         * Number of non-null values per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function count(): \Polars\DataFrame {}
+
+        /**
+         * First row per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function first(): \Polars\DataFrame {}
+
+        /**
+         * Iterate over the groups as `array $key => DataFrame $group` pairs
+         * @return \Polars\GroupByIterator
+         * @throws Polars\Exception
+         *
+         * @return \Polars\GroupByIterator
+         */
+        public function getIterator(): \Polars\GroupByIterator {}
+
+        /**
+         * Keep only the groups matching the predicate. The predicate is evaluated per group and
+         * must aggregate to a single boolean, e.g. `Expr::col('a')->len()->gt(1)`.
+         * @param \Polars\Expr $predicate Boolean aggregation
+         * @return \Polars\GroupBy
+         * @throws Polars\Exception
+         *
+         * @param \Polars\Expr $predicate
+         * @return \Polars\GroupBy
+         */
+        public function having(\Polars\Expr $predicate): \Polars\GroupBy {}
+
+        /**
+         * First n rows per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param int $n
+         * @return \Polars\DataFrame
+         */
+        public function head(int $n = 5): \Polars\DataFrame {}
+
+        /**
+         * Last row per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function last(): \Polars\DataFrame {}
+
+        /**
+         * Number of rows per group
+         * @param string $name Name of the length column
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param string $name
+         * @return \Polars\DataFrame
+         */
+        public function len(string $name = "len"): \Polars\DataFrame {}
+
+        /**
+         * Apply a PHP callable to every group and concatenate the results vertically.
+         * The callable receives the group as a DataFrame and must return a DataFrame.
+         * @param callable $function Callable with signature `fn(DataFrame $group): DataFrame`
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param callable $function
+         * @return \Polars\DataFrame
+         */
+        public function mapGroups(callable $function): \Polars\DataFrame {}
+
+        /**
+         * Max per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function max(): \Polars\DataFrame {}
+
+        /**
+         * Mean per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function mean(): \Polars\DataFrame {}
+
+        /**
+         * Median per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function median(): \Polars\DataFrame {}
+
+        /**
+         * Min per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function min(): \Polars\DataFrame {}
+
+        /**
+         * Number of unique values per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function nUnique(): \Polars\DataFrame {}
+
+        /**
+         * Quantile per group
+         * @param float $quantile Quantile between 0.0 and 1.0
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param float $quantile
+         * @param \Polars\QuantileMethod $interpolation
+         * @return \Polars\DataFrame
+         */
+        public function quantile(float $quantile, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest): \Polars\DataFrame {}
+
+        /**
+         * Sum per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function sum(): \Polars\DataFrame {}
+
+        /**
+         * Last n rows per group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param int $n
+         * @return \Polars\DataFrame
+         */
+        public function tail(int $n = 5): \Polars\DataFrame {}
+    }
+
+    class GroupByIterator implements \Iterator {
+        public function __construct() {}
+
+        /**
+         * Rows of the current group
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @return \Polars\DataFrame
+         */
+        public function current(): \Polars\DataFrame {}
+
+        /**
+         * Key values of the current group, one per group by expression
+         * @return array
+         * @throws Polars\Exception
+         *
+         * @return array
+         */
+        public function key(): array {}
+
+        /**
+         * Move to the next group
+         *
+         * @return void
+         */
+        public function next(): void {}
+
+        /**
+         * Move back to the first group
+         *
+         * @return void
+         */
+        public function rewind(): void {}
+
+        /**
+         * Whether the current position points at a group
+         *
+         * @return bool
+         */
+        public function valid(): bool {}
     }
 
-    class LazyFrame {
-        public function __construct() {}
+    class LazyFrame {
+        public function __construct() {}
+
+        /**
+         * String representation showing the query plan
+         *
+         * @return string
+         */
+        public function __toString(): string {}
+
+        /**
+         * Get the k rows with the smallest values of the `by` column(s)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+         * @param bool|bool[]|null $reverse Take the largest values instead, one flag per key or a single flag for all
+         * @return \Polars\LazyFrame
+         *
+         * @param int $k
+         * @param mixed $by
+         * @param mixed $reverse
+         * @return \Polars\LazyFrame
+         */
+        public function bottomK(int $k, mixed $by, mixed $reverse = null): \Polars\LazyFrame {}
+
+        /**
+         * Cache the LazyFrame computation
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function cache(): \Polars\LazyFrame {}
+
+        /**
+         * Cast columns to different data types
+         * @param array $dtypes Associative array of column name => data type string
+         * @return \Polars\LazyFrame
+         *
+         * @param array $dtypes
+         * @param bool $strict
+         * @return \Polars\LazyFrame
+         */
+        public function cast(array $dtypes, bool $strict = false): \Polars\LazyFrame {}
+
+        /**
+         * Execute the lazy query and return a DataFrame.
+         * Optimization toggles left as null keep the setting of the LazyFrame.
+         * @param string $engine Engine to run the query on: 'auto', 'in-memory' or 'streaming'
+         * @param bool|null $typeCoercion Coerce types so that operations succeed
+         * @param bool|null $predicatePushdown Apply filters as early as possible
+         * @param bool|null $projectionPushdown Only read columns that are used later in the query
+         * @param bool|null $simplifyExpression Run expression simplification rules
+         * @param bool|null $slicePushdown Push slices and limits down to the scans
+         * @param bool|null $commSubplanElim Cache duplicated subplans
+         * @param bool|null $commSubexprElim Cache duplicated expressions
+         * @param bool|null $clusterWithColumns Combine sequential independent `withColumns()` calls
+         * @param bool $noOptimization Turn off all optimizations except type coercion and type checking before applying the toggles
+         * @return \Polars\DataFrame
+         * @throws Polars\Exception
+         *
+         * @param string $engine
+         * @param bool|null $typeCoercion
+         * @param bool|null $predicatePushdown
+         * @param bool|null $projectionPushdown
+         * @param bool|null $simplifyExpression
+         * @param bool|null $slicePushdown
+         * @param bool|null $commSubplanElim
+         * @param bool|null $commSubexprElim
+         * @param bool|null $clusterWithColumns
+         * @param bool $noOptimization
+         * @return \Polars\DataFrame
+         */
+        public function collect(string $engine = "auto", ?bool $typeCoercion = null, ?bool $predicatePushdown = null, ?bool $projectionPushdown = null, ?bool $simplifyExpression = null, ?bool $slicePushdown = null, ?bool $commSubplanElim = null, ?bool $commSubexprElim = null, ?bool $clusterWithColumns = null, bool $noOptimization = false): \Polars\DataFrame {}
+
+        /**
+         * Return the number of non-null elements for each column
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function count(): \Polars\LazyFrame {}
+
+        /**
+         * Create the Cartesian product with another LazyFrame
+         * @param \Polars\LazyFrame $other The right LazyFrame
+         * @param string|null $suffix Suffix for duplicate column names (default: '_right')
+         * @return \Polars\LazyFrame
+         *
+         * @param \Polars\LazyFrame $other
+         * @param string|null $suffix
+         * @return \Polars\LazyFrame
+         */
+        public function crossJoin(\Polars\LazyFrame $other, ?string $suffix = null): \Polars\LazyFrame {}
+
+        /**
+         * Drop columns
+         * @param string[] $columns
+         * @return \Polars\LazyFrame
+         *
+         * @param array $columns
+         * @return \Polars\LazyFrame
+         */
+        public function drop(array $columns): \Polars\LazyFrame {}
+
+        /**
+         * Drop rows with NaN values
+         * @param string[]|null $subset Column names to check
+         * @return \Polars\LazyFrame
+         *
+         * @param array|null $subset
+         * @return \Polars\LazyFrame
+         */
+        public function dropNans(?array $subset = null): \Polars\LazyFrame {}
+
+        /**
+         * Drop rows with null values
+         * @param string[]|null $subset Column names to check
+         * @return \Polars\LazyFrame
+         *
+         * @param array|null $subset
+         * @return \Polars\LazyFrame
+         */
+        public function dropNulls(?array $subset = null): \Polars\LazyFrame {}
+
+        /**
+         * Get data types
+         * @return \Polars\DataType[]
+         *
+         * @return array
+         */
+        public function dtypes(): array {}
+
+        /**
+         * Return the query plan as a string
+         * @param bool $optimized Describe the plan after optimization
+         * @param string $format Output format: 'plain', 'tree' or 'dot' (Graphviz)
+         * @return string
+         * @throws Polars\Exception
+         *
+         * @param bool $optimized
+         * @param string $format
+         * @return string
+         */
+        public function explain(bool $optimized = true, string $format = "plain"): string {}
+
+        /**
+         * Explode list columns into rows
+         * @param string[] $columns Column names to explode
+         * @return \Polars\LazyFrame
+         *
+         * @param array $columns
+         * @return \Polars\LazyFrame
+         */
+        public function explode(array $columns): \Polars\LazyFrame {}
+
+        /**
+         * Fill NaN values with a value or expression
+         * @param int|float|string|bool|null|\Polars\Expr $value
+         * @return \Polars\LazyFrame
+         *
+         * @param mixed $value
+         * @return \Polars\LazyFrame
+         */
+        public function fillNan(mixed $value): \Polars\LazyFrame {}
+
+        /**
+         * Fill null values with a value or expression
+         * @param int|float|string|bool|null|\Polars\Expr $value
+         * @return \Polars\LazyFrame
+         *
+         * @param mixed $value
+         * @return \Polars\LazyFrame
+         */
+        public function fillNull(mixed $value): \Polars\LazyFrame {}
+
+        /**
+         * Filter rows by expression
+         * @return \Polars\LazyFrame
+         *
+         * @param \Polars\Expr $expression
+         * @return \Polars\LazyFrame
+         */
+        public function filter(\Polars\Expr $expression): \Polars\LazyFrame {}
+
+        /**
+         * Get the first row
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function first(): \Polars\LazyFrame {}
+
+        /**
+         * Take every nth row
+         * @return \Polars\LazyFrame
+         * @throws Polars\Exception
+         *
+         * @param int $n
+         * @param int $offset
+         * @return \Polars\LazyFrame
+         */
+        public function gatherEvery(int $n, int $offset = 0): \Polars\LazyFrame {}
+
+        /**
+         * Get column names
+         * @return string[]
+         *
+         * @return array
+         */
+        public function getColumns(): array {}
+
+        /**
+         * Group by expressions
+         * @param \Polars\Expr[] $expressions
+         * @param bool $maintainOrder Keep groups in the order of their first appearance
+         * @return \Polars\LazyGroupBy
+         *
+         * @param array $expressions
+         * @param bool $maintainOrder
+         * @return \Polars\LazyGroupBy
+         */
+        public function groupBy(array $expressions, bool $maintainOrder = false): \Polars\LazyGroupBy {}
+
+        /**
+         * Group by time windows of the index column, optionally per group of `groupBy` keys.
+         * The index column must be sorted (within each group)
+         * @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+         * @param string $every Interval between the starts of consecutive windows, e.g. '15m' or '2i'
+         * @param string|null $period Length of each window (default: $every)
+         * @param string|null $offset Offset of the window starts (default: zero)
+         * @param bool $includeBoundaries Add '_lower_boundary' and '_upper_boundary' columns
+         * @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+         * @param string $label Window label in the index column: 'left', 'right', 'datapoint'
+         * @param string $startBy Start of the first window: 'window', 'datapoint' or a day of the week such as 'monday'
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+         * @return \Polars\LazyGroupBy
+         * @throws Polars\Exception
+         *
+         * @param mixed $indexColumn
+         * @param string $every
+         * @param string|null $period
+         * @param string|null $offset
+         * @param bool $includeBoundaries
+         * @param \Polars\ClosedInterval $closed
+         * @param string $label
+         * @param string $startBy
+         * @param mixed $groupBy
+         * @return \Polars\LazyGroupBy
+         */
+        public function groupByDynamic(mixed $indexColumn, string $every, ?string $period = null, ?string $offset = null, bool $includeBoundaries = false, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Left, string $label = "left", string $startBy = "window", mixed $groupBy = null): \Polars\LazyGroupBy {}
+
+        /**
+         * Get the first n rows
+         * @return \Polars\LazyFrame
+         *
+         * @param int $n
+         * @return \Polars\LazyFrame
+         */
+        public function head(int $n = 10): \Polars\LazyFrame {}
+
+        /**
+         * Interpolate null values using linear interpolation
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function interpolate(): \Polars\LazyFrame {}
+
+        /**
+         * Join with another LazyFrame
+         * @param \Polars\LazyFrame $other The right LazyFrame
+         * @param \Polars\Expr[] $on Join columns (used for both left and right when leftOn/rightOn not given)
+         * @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross', 'semi', 'anti'
+         * @param \Polars\Expr[]|null $leftOn Left join columns (overrides $on for the left side)
+         * @param \Polars\Expr[]|null $rightOn Right join columns (overrides $on for the right side)
+         * @param string|null $suffix Suffix for duplicate column names (default: '_right')
+         * @param string|null $validate Join validation: 'm:m', 'm:1', '1:m', '1:1'
+         * @param bool|null $coalesce Coalesce join columns
+         * @param bool $nullsEqual Match null keys with each other
+         * @param string $maintainOrder Row order to preserve: 'none', 'left', 'right', 'left_right', 'right_left'
+         * @param bool $allowParallel Allow evaluating both sides in parallel
+         * @param bool $forceParallel Force evaluating both sides in parallel
+         * @return \Polars\LazyFrame
+         *
+         * @param \Polars\LazyFrame $other
+         * @param array $on
+         * @param string $how
+         * @param array|null $leftOn
+         * @param array|null $rightOn
+         * @param string|null $suffix
+         * @param string|null $validate
+         * @param bool|null $coalesce
+         * @param bool $nullsEqual
+         * @param string $maintainOrder
+         * @param bool $allowParallel
+         * @param bool $forceParallel
+         * @return \Polars\LazyFrame
+         */
+        public function join(\Polars\LazyFrame $other, array $on, string $how = "inner", ?array $leftOn = null, ?array $rightOn = null, ?string $suffix = null, ?string $validate = null, ?bool $coalesce = null, bool $nullsEqual = false, string $maintainOrder = "none", bool $allowParallel = true, bool $forceParallel = false): \Polars\LazyFrame {}
+
+        /**
+         * Perform an asof join with another LazyFrame
+         * @param \Polars\LazyFrame $other The right LazyFrame
+         * @param string|null $on Key column of both sides (must be sorted)
+         * @param string|null $strategy Join strategy: 'backward', 'forward', 'nearest'
+         * @param string|string[]|null $leftBy Group by columns of the left side
+         * @param string|string[]|null $rightBy Group by columns of the right side
+         * @param int|float|string|null $tolerance Maximum key distance, as a number or a duration string (e.g. "5m")
+         * @param string|string[]|null $by Group by columns of both sides
+         * @param string|null $leftOn Key column of the left side
+         * @param string|null $rightOn Key column of the right side
+         * @param string|null $suffix Suffix for duplicate column names (default: '_right')
+         * @param bool $allowExactMatches Allow matching equal keys
+         * @param bool $checkSortedness Check that the keys are sorted
+         * @return \Polars\LazyFrame
+         *
+         * @param \Polars\LazyFrame $other
+         * @param string|null $on
+         * @param string|null $strategy
+         * @param mixed $leftBy
+         * @param mixed $rightBy
+         * @param mixed $tolerance
+         * @param mixed $by
+         * @param string|null $leftOn
+         * @param string|null $rightOn
+         * @param string|null $suffix
+         * @param bool $allowExactMatches
+         * @param bool $checkSortedness
+         * @return \Polars\LazyFrame
+         */
+        public function joinAsof(\Polars\LazyFrame $other, ?string $on = null, ?string $strategy = null, mixed $leftBy = null, mixed $rightBy = null, mixed $tolerance = null, mixed $by = null, ?string $leftOn = null, ?string $rightOn = null, ?string $suffix = null, bool $allowExactMatches = true, bool $checkSortedness = true): \Polars\LazyFrame {}
+
+        /**
+         * Join with another LazyFrame using arbitrary predicates, e.g. inequalities
+         * @param \Polars\LazyFrame $other The right LazyFrame
+         * @param \Polars\Expr[] $predicates Join predicates, all of which must hold
+         * @param string|null $suffix Suffix for duplicate column names (default: '_right')
+         * @return \Polars\LazyFrame
+         *
+         * @param \Polars\LazyFrame $other
+         * @param array $predicates
+         * @param string|null $suffix
+         * @return \Polars\LazyFrame
+         */
+        public function joinWhere(\Polars\LazyFrame $other, array $predicates, ?string $suffix = null): \Polars\LazyFrame {}
+
+        /**
+         * Get the last row
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function last(): \Polars\LazyFrame {}
+
+        /**
+         * Limit to n rows (alias for head)
+         * @return \Polars\LazyFrame
+         *
+         * @param int $n
+         * @return \Polars\LazyFrame
+         */
+        public function limit(int $n = 10): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their maximum value
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function max(): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their mean value
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function mean(): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their median value
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function median(): \Polars\LazyFrame {}
+
+        /**
+         * Unpivot (alias for unpivot, deprecated name)
+         * @return \Polars\LazyFrame
+         *
+         * @param array $on
+         * @param array $index
+         * @param string|null $variableName
+         * @param string|null $valueName
+         * @return \Polars\LazyFrame
+         */
+        public function melt(array $on, array $index, ?string $variableName = null, ?string $valueName = null): \Polars\LazyFrame {}
+
+        /**
+         * Merge two sorted LazyFrames by a key column
+         * @param \Polars\LazyFrame $other The other sorted LazyFrame
+         * @param string $key The column to merge on (must be sorted in both LazyFrames)
+         * @return \Polars\LazyFrame
+         * @throws Polars\Exception
+         *
+         * @param \Polars\LazyFrame $other
+         * @param string $key
+         * @return \Polars\LazyFrame
+         */
+        public function mergeSorted(\Polars\LazyFrame $other, string $key): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their minimum value
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function min(): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their null count
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function nullCount(): \Polars\LazyFrame {}
+
+        /**
+         * Pivot a LazyFrame from long to wide format. The values of the `on` column that become
+         * output columns must be given upfront, as the schema of a lazy query is known before it runs.
+         * @param string $on Column whose values become the output columns
+         * @param array $onColumns Values of the `on` column to turn into columns, in output order
+         * @param string[]|null $index Column(s) to use as row index (default: all columns not in `on` and `values`)
+         * @param string[]|null $values Column(s) to aggregate (default: all columns not in `on` and `index`)
+         * @param string $aggregateFunction Aggregation function: 'first', 'last', 'sum', 'mean', 'median', 'min', 'max', 'count', 'len'
+         * @param string $separator Separator between the value column name and the `on` value when aggregating multiple values
+         * @return \Polars\LazyFrame
+         * @throws Polars\Exception
+         *
+         * @param string $on
+         * @param array $onColumns
+         * @param array|null $index
+         * @param array|null $values
+         * @param string $aggregateFunction
+         * @param string $separator
+         * @return \Polars\LazyFrame
+         */
+        public function pivot(string $on, array $onColumns, ?array $index = null, ?array $values = null, string $aggregateFunction = "first", string $separator = "_"): \Polars\LazyFrame {}
+
+        /**
+         * Run the query and collect it together with the time spent in every node of the plan.
+         * Returns `[DataFrame $result, DataFrame $timings]`; the timings DataFrame has the
+         * columns `node`, `start` and `end`, in microseconds since the start of the query.
+         * @return \Polars\DataFrame[]
+         * @throws Polars\Exception
+         *
+         * @return array
+         */
+        public function profile(): array {}
+
+        /**
+         * Aggregate the columns to their quantile value
+         * @return \Polars\LazyFrame
+         *
+         * @param float $quantile
+         * @param \Polars\QuantileMethod $interpolation
+         * @return \Polars\LazyFrame
+         */
+        public function quantile(float $quantile, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest): \Polars\LazyFrame {}
+
+        /**
+         * Rename columns
+         * @param string[] $existing Old column names
+         * @param string[] $newNames New column names
+         * @return \Polars\LazyFrame
+         *
+         * @param array $existing
+         * @param array $newNames
+         * @return \Polars\LazyFrame
+         */
+        public function rename(array $existing, array $newNames): \Polars\LazyFrame {}
+
+        /**
+         * Reverse row order
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function reverse(): \Polars\LazyFrame {}
+
+        /**
+         * Group by lookback windows anchored on every row of the index column, optionally per group of `groupBy` keys.
+         * The window of a row with index value `t` is `(t + offset, t + offset + period]` by default.
+         * The index column must be sorted (within each group)
+         * @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+         * @param string $period Length of each window, e.g. '30d' or '3i'
+         * @param string|null $offset Offset of the window starts (default: -$period)
+         * @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+         * @return \Polars\LazyGroupBy
+         * @throws Polars\Exception
+         *
+         * @param mixed $indexColumn
+         * @param string $period
+         * @param string|null $offset
+         * @param \Polars\ClosedInterval $closed
+         * @param mixed $groupBy
+         * @return \Polars\LazyGroupBy
+         */
+        public function rolling(mixed $indexColumn, string $period, ?string $offset = null, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right, mixed $groupBy = null): \Polars\LazyGroupBy {}
+
+        /**
+         * Scan a CSV file into a LazyFrame
+         *
+         * @param string $path
+         * @param bool $hasHeader
+         * @param string $separator
+         * @return \Polars\LazyFrame
+         */
+        public static function scanCsv(string $path, bool $hasHeader = true, string $separator = ","): \Polars\LazyFrame {}
+
+        /**
+         * Scan a NDJSON file into a LazyFrame
+         *
+         * @param string $path
+         * @return \Polars\LazyFrame
+         */
+        public static function scanNdjson(string $path): \Polars\LazyFrame {}
+
+        /**
+         * Scan a Parquet file into a LazyFrame
+         *
+         * @param string $path
+         * @return \Polars\LazyFrame
+         */
+        public static function scanParquet(string $path): \Polars\LazyFrame {}
+
+        /**
+         * Get schema description as string
+         * @return string
+         *
+         * @return string
+         */
+        public function schema(): string {}
+
+        /**
+         * Select columns by expression
+         * @param \Polars\Expr[] $expressions
+         * @return \Polars\LazyFrame
+         *
+         * @param array $expressions
+         * @return \Polars\LazyFrame
+         */
+        public function select(array $expressions): \Polars\LazyFrame {}
+
+        /**
+         * Flag columns as sorted, so that later operations can use fast paths.
+         * Flagging an unsorted column leads to incorrect results
+         * @param string|string[] $column Column name(s)
+         * @param bool $descending Whether the columns are sorted in descending order
+         * @return \Polars\LazyFrame
+         *
+         * @param mixed $column
+         * @param bool $descending
+         * @return \Polars\LazyFrame
+         */
+        public function setSorted(mixed $column, bool $descending = false): \Polars\LazyFrame {}
+
+        /**
+         * Shift column values by n positions
+         * @return \Polars\LazyFrame
+         *
+         * @param int $n
+         * @return \Polars\LazyFrame
+         */
+        public function shift(int $n): \Polars\LazyFrame {}
+
+        /**
+         * Sink the LazyFrame to a CSV file and return the result as a DataFrame
+         * @return \Polars\DataFrame
+         *
+         * @param string $path
+         * @param bool $includeHeader
+         * @param string $separator
+         * @return \Polars\DataFrame
+         */
+        public function sinkCsv(string $path, bool $includeHeader = true, string $separator = ","): \Polars\DataFrame {}
+
+        /**
+         * Sink the LazyFrame to a NDJSON file and return the result as a DataFrame
+         * @return \Polars\DataFrame
+         *
+         * @param string $path
+         * @return \Polars\DataFrame
+         */
+        public function sinkNdjson(string $path): \Polars\DataFrame {}
+
+        /**
+         * Sink the LazyFrame to a Parquet file and return the result as a DataFrame
+         * @return \Polars\DataFrame
+         *
+         * @param string $path
+         * @return \Polars\DataFrame
+         */
+        public function sinkParquet(string $path): \Polars\DataFrame {}
+
+        /**
+         * Get a slice of rows
+         * @return \Polars\LazyFrame
+         *
+         * @param int $offset
+         * @param int $length
+         * @return \Polars\LazyFrame
+         */
+        public function slice(int $offset, int $length): \Polars\LazyFrame {}
+
+        /**
+         * Sort by one or more columns or expressions
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to sort by
+         * @param bool|bool[]|null $descending Sort in descending order, one flag per key or a single flag for all
+         * @param bool|bool[]|null $nullsLast Put null values last, one flag per key or a single flag for all (default: true)
+         * @param bool $maintainOrder Maintain order of equal elements (stable sort)
+         * @param bool $multithreaded Use multithreaded sorting
+         * @return \Polars\LazyFrame
+         *
+         * @param mixed $by
+         * @param mixed $descending
+         * @param mixed $nullsLast
+         * @param bool $maintainOrder
+         * @param bool $multithreaded
+         * @return \Polars\LazyFrame
+         */
+        public function sort(mixed $by, mixed $descending = null, mixed $nullsLast = null, bool $maintainOrder = false, bool $multithreaded = true): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their standard deviation
+         * @return \Polars\LazyFrame
+         *
+         * @param int $ddof
+         * @return \Polars\LazyFrame
+         */
+        public function std(int $ddof = 1): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the columns to their sum value
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function sum(): \Polars\LazyFrame {}
+
+        /**
+         * Get the last n rows
+         * @return \Polars\LazyFrame
+         *
+         * @param int $n
+         * @return \Polars\LazyFrame
+         */
+        public function tail(int $n = 10): \Polars\LazyFrame {}
+
+        /**
+         * Get the k rows with the largest values of the `by` column(s)
+         * @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+         * @param bool|bool[]|null $reverse Take the smallest values instead, one flag per key or a single flag for all
+         * @return \Polars\LazyFrame
+         *
+         * @param int $k
+         * @param mixed $by
+         * @param mixed $reverse
+         * @return \Polars\LazyFrame
+         */
+        public function topK(int $k, mixed $by, mixed $reverse = null): \Polars\LazyFrame {}
+
+        /**
+         * Get unique rows
+         * @param string[]|null $subset Column names to consider for uniqueness
+         * @return \Polars\LazyFrame
+         *
+         * @param array|null $subset
+         * @param string $keep
+         * @return \Polars\LazyFrame
+         */
+        public function unique(?array $subset = null, string $keep = "first"): \Polars\LazyFrame {}
+
+        /**
+         * Unnest struct columns into separate columns
+         * @param string[] $columns Names of struct columns to unnest
+         * @return \Polars\LazyFrame
+         *
+         * @param array $columns
+         * @return \Polars\LazyFrame
+         */
+        public function unnest(array $columns): \Polars\LazyFrame {}
 
         /**
-         * String representation showing the query plan
+         * Unpivot a LazyFrame from wide to long format
+         * @param string[] $on Columns to use as values
+         * @param string[] $index Columns to use as identifier
+         * @param string|null $variableName Custom name for the variable column (default: 'variable')
+         * @param string|null $valueName Custom name for the value column (default: 'value')
+         * @return \Polars\LazyFrame
          *
-         * @return string
+         * @param array $on
+         * @param array $index
+         * @param string|null $variableName
+         * @param string|null $valueName
+         * @return \Polars\LazyFrame
          */
-        public function __toString(): string {}
+        public function unpivot(array $on, array $index, ?string $variableName = null, ?string $valueName = null): \Polars\LazyFrame {}
 
         /**
-         * Cache the LazyFrame computation
+         * Aggregate the columns to their variance
          * @return \Polars\LazyFrame
          *
+         * @param int $ddof
          * @return \Polars\LazyFrame
          */
-        public function cache(): \Polars\LazyFrame {}
+        public function variance(int $ddof = 1): \Polars\LazyFrame {}
 
         /**
-         * Execute the lazy query and return a DataFrame
-         * @return \Polars\DataFrame
+         * Get number of columns
+         * @return int
          *
-         * @return \Polars\DataFrame
+         * @return int
          */
-        public function collect(): \Polars\DataFrame {}
+        public function width(): int {}
 
         /**
-         * Return the number of non-null elements for each column
+         * Add or modify columns
+         * @param \Polars\Expr[] $expressions
+         * @return \Polars\LazyFrame
+         *
+         * @param array $expressions
+         * @return \Polars\LazyFrame
+         */
+        public function withColumns(array $expressions): \Polars\LazyFrame {}
+
+        /**
+         * Set the optimizations used when the query is collected, explained or profiled.
+         * Toggles left as null keep the current setting.
+         * @param bool|null $typeCoercion Coerce types so that operations succeed
+         * @param bool|null $predicatePushdown Apply filters as early as possible
+         * @param bool|null $projectionPushdown Only read columns that are used later in the query
+         * @param bool|null $simplifyExpression Run expression simplification rules
+         * @param bool|null $slicePushdown Push slices and limits down to the scans
+         * @param bool|null $commSubplanElim Cache duplicated subplans
+         * @param bool|null $commSubexprElim Cache duplicated expressions
+         * @param bool|null $clusterWithColumns Combine sequential independent `withColumns()` calls
+         * @param bool $noOptimization Turn off all optimizations except type coercion and type checking before applying the toggles
+         * @return \Polars\LazyFrame
+         *
+         * @param bool|null $typeCoercion
+         * @param bool|null $predicatePushdown
+         * @param bool|null $projectionPushdown
+         * @param bool|null $simplifyExpression
+         * @param bool|null $slicePushdown
+         * @param bool|null $commSubplanElim
+         * @param bool|null $commSubexprElim
+         * @param bool|null $clusterWithColumns
+         * @param bool $noOptimization
+         * @return \Polars\LazyFrame
+         */
+        public function withOptimizations(?bool $typeCoercion = null, ?bool $predicatePushdown = null, ?bool $projectionPushdown = null, ?bool $simplifyExpression = null, ?bool $slicePushdown = null, ?bool $commSubplanElim = null, ?bool $commSubexprElim = null, ?bool $clusterWithColumns = null, bool $noOptimization = false): \Polars\LazyFrame {}
+
+        /**
+         * Add a row index column
+         * @return \Polars\LazyFrame
+         *
+         * @param string $name
+         * @param int $offset
+         * @return \Polars\LazyFrame
+         */
+        public function withRowIndex(string $name = "index", int $offset = 0): \Polars\LazyFrame {}
+    }
+
+    class LazyGroupBy {
+        public function __construct() {}
+
+        /**
+         * Aggregate using expressions
+         * @param \Polars\Expr[] $expressions
+         * @return \Polars\LazyFrame
+         *
+         * @param array $expressions
+         * @return \Polars\LazyFrame
+         */
+        public function agg(array $expressions): \Polars\LazyFrame {}
+
+        /**
+         * Aggregate the values of every group into lists
+         * @return \Polars\LazyFrame
+         *
+         * @return \Polars\LazyFrame
+         */
+        public function all(): \Polars\LazyFrame {}
+
+        /**
+         * Count rows per group
          * @return \Polars\LazyFrame
          *
          * @return \Polars\LazyFrame
@@ -1368,599 +4007,723 @@ namespace Polars {
         public function count(): \Polars\LazyFrame {}
 
         /**
-         * Drop columns
-         * @param string[] $columns
+         * First row per group
          * @return \Polars\LazyFrame
          *
-         * @param array $columns
          * @return \Polars\LazyFrame
          */
-        public function drop(array $columns): \Polars\LazyFrame {}
+        public function first(): \Polars\LazyFrame {}
 
         /**
-         * Drop rows with null values
-         * @param string[]|null $subset Column names to check
+         * Keep only the groups matching the predicate. The predicate is evaluated per group,
+         * so aggregations like `Expr::col('a')->sum()` refer to the group.
+         * Calling it again adds another predicate.
+         * @param \Polars\Expr $predicate Boolean expression
+         * @return \Polars\LazyGroupBy
+         *
+         * @param \Polars\Expr $predicate
+         * @return \Polars\LazyGroupBy
+         */
+        public function having(\Polars\Expr $predicate): \Polars\LazyGroupBy {}
+
+        /**
+         * First n rows per group
          * @return \Polars\LazyFrame
+         * @throws Polars\Exception
          *
-         * @param array|null $subset
+         * @param int $n
          * @return \Polars\LazyFrame
          */
-        public function dropNulls(?array $subset = null): \Polars\LazyFrame {}
+        public function head(int $n = 5): \Polars\LazyFrame {}
 
         /**
-         * Get data types
-         * @return \Polars\DataType[]
+         * Last row per group
+         * @return \Polars\LazyFrame
          *
-         * @return array
+         * @return \Polars\LazyFrame
          */
-        public function dtypes(): array {}
+        public function last(): \Polars\LazyFrame {}
 
         /**
-         * Return the query plan as a string
-         * @return string
+         * Number of rows per group
+         * @param string $name Name of the length column
+         * @return \Polars\LazyFrame
          *
-         * @param bool $optimized
-         * @return string
+         * @param string $name
+         * @return \Polars\LazyFrame
          */
-        public function explain(bool $optimized = true): string {}
+        public function len(string $name = "len"): \Polars\LazyFrame {}
 
         /**
-         * Fill NaN values with a value or expression
-         * @param int|float|string|bool|null|\Polars\Expr $value
+         * Apply a PHP callable to every group and concatenate the results vertically.
+         * The callable receives the group as a DataFrame and must return a DataFrame
+         * matching `schema`. It is called when the query is collected with the in-memory engine.
+         * @param callable $function Callable with signature `fn(DataFrame $group): DataFrame`
+         * @param array|null $schema Output schema as column name => data type, defaults to the input schema
          * @return \Polars\LazyFrame
+         * @throws Polars\Exception
          *
-         * @param mixed $value
+         * @param mixed $function
+         * @param array|null $schema
          * @return \Polars\LazyFrame
          */
-        public function fillNan(mixed $value): \Polars\LazyFrame {}
+        public function mapGroups(mixed $function, ?array $schema = null): \Polars\LazyFrame {}
 
         /**
-         * Fill null values with a value or expression
-         * @param int|float|string|bool|null|\Polars\Expr $value
+         * Max per group
          * @return \Polars\LazyFrame
          *
-         * @param mixed $value
          * @return \Polars\LazyFrame
          */
-        public function fillNull(mixed $value): \Polars\LazyFrame {}
+        public function max(): \Polars\LazyFrame {}
 
         /**
-         * Filter rows by expression
+         * Mean per group
          * @return \Polars\LazyFrame
          *
-         * @param \Polars\Expr $expression
          * @return \Polars\LazyFrame
          */
-        public function filter(\Polars\Expr $expression): \Polars\LazyFrame {}
+        public function mean(): \Polars\LazyFrame {}
 
         /**
-         * Get the first row
+         * Median per group
          * @return \Polars\LazyFrame
          *
          * @return \Polars\LazyFrame
          */
-        public function first(): \Polars\LazyFrame {}
+        public function median(): \Polars\LazyFrame {}
 
         /**
-         * Get column names
-         * @return string[]
+         * Min per group
+         * @return \Polars\LazyFrame
          *
-         * @return array
+         * @return \Polars\LazyFrame
          */
-        public function getColumns(): array {}
+        public function min(): \Polars\LazyFrame {}
 
         /**
-         * Group by expressions
-         * @param \Polars\Expr[] $expressions
-         * @return \Polars\LazyGroupBy
+         * Number of unique values per group
+         * @return \Polars\LazyFrame
          *
-         * @param array $expressions
-         * @return \Polars\LazyGroupBy
+         * @return \Polars\LazyFrame
          */
-        public function groupBy(array $expressions): \Polars\LazyGroupBy {}
+        public function nUnique(): \Polars\LazyFrame {}
 
         /**
-         * Get the first n rows
+         * Quantile per group
+         * @param float $quantile Quantile between 0.0 and 1.0
          * @return \Polars\LazyFrame
+         * @throws Polars\Exception
          *
-         * @param int $n
+         * @param float $quantile
+         * @param \Polars\QuantileMethod $interpolation
          * @return \Polars\LazyFrame
          */
-        public function head(int $n = 10): \Polars\LazyFrame {}
+        public function quantile(float $quantile, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest): \Polars\LazyFrame {}
 
         /**
-         * Join with another LazyFrame
-         * @param \Polars\LazyFrame $other The right LazyFrame
-         * @param \Polars\Expr[] $on Join columns (used for both left and right)
-         * @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross'
+         * Standard deviation per group
+         * @param int $ddof Delta degrees of freedom
          * @return \Polars\LazyFrame
          *
-         * @param \Polars\LazyFrame $other
-         * @param array $on
-         * @param string $how
+         * @param int $ddof
          * @return \Polars\LazyFrame
          */
-        public function join(\Polars\LazyFrame $other, array $on, string $how = "inner"): \Polars\LazyFrame {}
+        public function std(int $ddof = 1): \Polars\LazyFrame {}
 
         /**
-         * Get the last row
+         * Sum per group
          * @return \Polars\LazyFrame
          *
          * @return \Polars\LazyFrame
          */
-        public function last(): \Polars\LazyFrame {}
+        public function sum(): \Polars\LazyFrame {}
 
         /**
-         * Limit to n rows (alias for head)
+         * Last n rows per group
          * @return \Polars\LazyFrame
+         * @throws Polars\Exception
          *
          * @param int $n
          * @return \Polars\LazyFrame
          */
-        public function limit(int $n = 10): \Polars\LazyFrame {}
+        public function tail(int $n = 5): \Polars\LazyFrame {}
 
         /**
-         * Aggregate the columns to their maximum value
+         * Variance per group
+         * @param int $ddof Delta degrees of freedom
          * @return \Polars\LazyFrame
          *
+         * @param int $ddof
          * @return \Polars\LazyFrame
          */
-        public function max(): \Polars\LazyFrame {}
+        public function variance(int $ddof = 1): \Polars\LazyFrame {}
+    }
+
+    enum QuantileMethod {
+      case Nearest;
+      case Lower;
+      case Higher;
+      case Midpoint;
+      case Linear;
+      case Equiprobable;
+    }
+
+    class Selector {
+        public function __construct() {}
+
+        /**
+         * @return string
+         */
+        public function __toString(): string {}
+
+        /**
+         * Convert selector to an expression
+         *
+         * @return \Polars\Expr
+         */
+        public function asExpr(): \Polars\Expr {}
+
+        /**
+         * Select all columns not matched by this selector
+         *
+         * @return \Polars\Selector
+         */
+        public function complement(): \Polars\Selector {}
+
+        /**
+         * Select columns matched by this selector but not by the other one
+         *
+         * @param \Polars\Selector $other
+         * @return \Polars\Selector
+         */
+        public function difference(\Polars\Selector $other): \Polars\Selector {}
+
+        /**
+         * Select columns matched by exactly one of the selectors
+         *
+         * @param \Polars\Selector $other
+         * @return \Polars\Selector
+         */
+        public function exclusiveOr(\Polars\Selector $other): \Polars\Selector {}
+
+        /**
+         * Select columns matched by both selectors
+         *
+         * @param \Polars\Selector $other
+         * @return \Polars\Selector
+         */
+        public function intersection(\Polars\Selector $other): \Polars\Selector {}
+
+        /**
+         * Select columns matched by either selector
+         *
+         * @param \Polars\Selector $other
+         * @return \Polars\Selector
+         */
+        public function union(\Polars\Selector $other): \Polars\Selector {}
+    }
+
+    class Selectors {
+        public function __construct() {}
+
+        /**
+         * Select all columns
+         *
+         * @return \Polars\Selector
+         */
+        public static function all(): \Polars\Selector {}
+
+        /**
+         * Select all boolean columns
+         *
+         * @return \Polars\Selector
+         */
+        public static function boolean(): \Polars\Selector {}
+
+        /**
+         * Select columns of given data types
+         * @param string|\Polars\DataType|array $dtypes Data type name, DataType object or an array of them
+         * @throws Polars\Exception
+         *
+         * @param mixed $dtypes
+         * @return \Polars\Selector
+         */
+        public static function byDtype(mixed $dtypes): \Polars\Selector {}
+
+        /**
+         * Select columns by position. Negative indices count from the end.
+         *
+         * @param array $indices
+         * @return \Polars\Selector
+         */
+        public static function byIndex(array $indices): \Polars\Selector {}
+
+        /**
+         * Select columns by name
+         *
+         * @param array $names
+         * @return \Polars\Selector
+         */
+        public static function byName(array $names): \Polars\Selector {}
+
+        /**
+         * Select columns whose name contains any of given substrings
+         *
+         * @param array $substrings
+         * @return \Polars\Selector
+         */
+        public static function contains(array $substrings): \Polars\Selector {}
+
+        /**
+         * Select columns whose name ends with any of given suffixes
+         *
+         * @param array $suffixes
+         * @return \Polars\Selector
+         */
+        public static function endsWith(array $suffixes): \Polars\Selector {}
 
         /**
-         * Aggregate the columns to their mean value
-         * @return \Polars\LazyFrame
+         * Select the first column
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public function mean(): \Polars\LazyFrame {}
+        public static function first(): \Polars\Selector {}
 
         /**
-         * Aggregate the columns to their median value
-         * @return \Polars\LazyFrame
+         * Select all float columns
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public function median(): \Polars\LazyFrame {}
+        public static function float(): \Polars\Selector {}
 
         /**
-         * Aggregate the columns to their minimum value
-         * @return \Polars\LazyFrame
+         * Select all integer columns, signed and unsigned
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public function min(): \Polars\LazyFrame {}
+        public static function integer(): \Polars\Selector {}
 
         /**
-         * Aggregate the columns to their null count
-         * @return \Polars\LazyFrame
+         * Select the last column
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public function nullCount(): \Polars\LazyFrame {}
+        public static function last(): \Polars\Selector {}
 
         /**
-         * Aggregate the columns to their quantile value
-         * @return \Polars\LazyFrame
+         * Select columns whose name matches the regular expression
          *
-         * @param float $quantile
-         * @param \Polars\QuantileMethod $interpolation
-         * @return \Polars\LazyFrame
+         * @param string $pattern
+         * @return \Polars\Selector
          */
-        public function quantile(float $quantile, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest): \Polars\LazyFrame {}
+        public static function matches(string $pattern): \Polars\Selector {}
 
         /**
-         * Rename columns
-         * @param string[] $existing Old column names
-         * @param string[] $newNames New column names
-         * @return \Polars\LazyFrame
+         * Select all numeric (integer and float) columns
          *
-         * @param array $existing
-         * @param array $newNames
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public function rename(array $existing, array $newNames): \Polars\LazyFrame {}
+        public static function numeric(): \Polars\Selector {}
 
         /**
-         * Reverse row order
-         * @return \Polars\LazyFrame
+         * Select columns whose name starts with any of given prefixes
          *
-         * @return \Polars\LazyFrame
+         * @param array $prefixes
+         * @return \Polars\Selector
          */
-        public function reverse(): \Polars\LazyFrame {}
+        public static function startsWith(array $prefixes): \Polars\Selector {}
 
         /**
-         * Scan a CSV file into a LazyFrame
+         * Select all string columns
          *
-         * @param string $path
-         * @param bool $hasHeader
-         * @param string $separator
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public static function scanCsv(string $path, bool $hasHeader = true, string $separator = ","): \Polars\LazyFrame {}
+        public static function string(): \Polars\Selector {}
 
         /**
-         * Scan a NDJSON file into a LazyFrame
+         * Select all temporal (date, datetime, duration and time) columns
          *
-         * @param string $path
-         * @return \Polars\LazyFrame
+         * @return \Polars\Selector
          */
-        public static function scanNdjson(string $path): \Polars\LazyFrame {}
+        public static function temporal(): \Polars\Selector {}
+    }
 
+    class Series implements \ArrayAccess, \Countable {
         /**
-         * Scan a Parquet file into a LazyFrame
+         * Get the data type of the Series
          *
-         * @param string $path
-         * @return \Polars\LazyFrame
+         * @var \Polars\DataType
          */
-        public static function scanParquet(string $path): \Polars\LazyFrame {}
+        public readonly \Polars\DataType $dtype;
 
         /**
-         * Get schema description as string
-         * @return string
+         * Get the name of the Series
          *
-         * @return string
+         * @var string
          */
-        public function schema(): string {}
+        public readonly string $name;
 
         /**
-         * Select columns by expression
-         * @param \Polars\Expr[] $expressions
-         * @return \Polars\LazyFrame
+         * Get the shape of the Series as [length]
          *
-         * @param array $expressions
-         * @return \Polars\LazyFrame
+         * @var array
          */
-        public function select(array $expressions): \Polars\LazyFrame {}
+        public readonly array $shape;
 
         /**
-         * Sink the LazyFrame to a CSV file and return the result as a DataFrame
-         * @return \Polars\DataFrame
+         * Create a new Series from a PHP array
          *
-         * @param string $path
-         * @param bool $includeHeader
-         * @param string $separator
-         * @return \Polars\DataFrame
+         * @param string $name
+         * @param array $values
          */
-        public function sinkCsv(string $path, bool $includeHeader = true, string $separator = ","): \Polars\DataFrame {}
+        public function __construct(string $name = "", array $values) {}
 
         /**
-         * Sink the LazyFrame to a NDJSON file and return the result as a DataFrame
-         * @return \Polars\DataFrame
+         * Display the Series
          *
-         * @param string $path
-         * @return \Polars\DataFrame
+         * @return string
          */
-        public function sinkNdjson(string $path): \Polars\DataFrame {}
+        public function __toString(): string {}
 
         /**
-         * Sink the LazyFrame to a Parquet file and return the result as a DataFrame
-         * @return \Polars\DataFrame
+         * Compute absolute values
          *
-         * @param string $path
-         * @return \Polars\DataFrame
+         * @return \Polars\Series
          */
-        public function sinkParquet(string $path): \Polars\DataFrame {}
+        public function abs(): \Polars\Series {}
 
         /**
-         * Get a slice of rows
-         * @return \Polars\LazyFrame
+         * Create an alias for the Series (same as rename)
          *
-         * @param int $offset
-         * @param int $length
-         * @return \Polars\LazyFrame
+         * @param string $name
+         * @return \Polars\Series
          */
-        public function slice(int $offset, int $length): \Polars\LazyFrame {}
+        public function alias(string $name): \Polars\Series {}
 
         /**
-         * Sort by a single column
-         * @return \Polars\LazyFrame
+         * Check if all values are true (for boolean Series)
          *
-         * @param string $column
-         * @param bool $descending
-         * @param bool $nullsLast
-         * @return \Polars\LazyFrame
+         * @return bool
          */
-        public function sort(string $column, bool $descending = false, bool $nullsLast = true): \Polars\LazyFrame {}
+        public function all(): bool {}
 
         /**
-         * Aggregate the columns to their standard deviation
-         * @return \Polars\LazyFrame
+         * Check if any value is true (for boolean Series)
          *
-         * @param int $ddof
-         * @return \Polars\LazyFrame
+         * @return bool
          */
-        public function std(int $ddof = 1): \Polars\LazyFrame {}
+        public function any(): bool {}
 
         /**
-         * Aggregate the columns to their sum value
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the inverse cosine
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function sum(): \Polars\LazyFrame {}
+        public function arccos(): \Polars\Series {}
 
         /**
-         * Get the last n rows
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the inverse hyperbolic cosine
          *
-         * @param int $n
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function tail(int $n = 10): \Polars\LazyFrame {}
+        public function arccosh(): \Polars\Series {}
 
         /**
-         * Get unique rows
-         * @param string[]|null $subset Column names to consider for uniqueness
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the inverse sine
          *
-         * @param array|null $subset
-         * @param string $keep
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function unique(?array $subset = null, string $keep = "first"): \Polars\LazyFrame {}
+        public function arcsin(): \Polars\Series {}
 
         /**
-         * Aggregate the columns to their variance
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the inverse hyperbolic sine
          *
-         * @param int $ddof
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function variance(int $ddof = 1): \Polars\LazyFrame {}
+        public function arcsinh(): \Polars\Series {}
 
         /**
-         * Get number of columns
-         * @return int
+         * Compute the element-wise value for the inverse tangent
          *
-         * @return int
+         * @return \Polars\Series
          */
-        public function width(): int {}
+        public function arctan(): \Polars\Series {}
 
         /**
-         * Add or modify columns
-         * @param \Polars\Expr[] $expressions
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the inverse hyperbolic tangent
          *
-         * @param array $expressions
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function withColumns(array $expressions): \Polars\LazyFrame {}
+        public function arctanh(): \Polars\Series {}
 
         /**
-         * Add a row index column
-         * @return \Polars\LazyFrame
+         * Get the index of the maximum value
          *
-         * @param string $name
-         * @param int $offset
-         * @return \Polars\LazyFrame
+         * @return mixed
          */
-        public function withRowIndex(string $name = "index", int $offset = 0): \Polars\LazyFrame {}
-    }
-
-    class LazyGroupBy {
-        public function __construct() {}
+        public function argMax(): mixed {}
 
         /**
-         * Aggregate using expressions
-         * @param \Polars\Expr[] $expressions
-         * @return \Polars\LazyFrame
+         * Get the index of the minimum value
          *
-         * @param array $expressions
-         * @return \Polars\LazyFrame
+         * @return mixed
          */
-        public function agg(array $expressions): \Polars\LazyFrame {}
+        public function argMin(): mixed {}
 
         /**
-         * Count rows per group
-         * @return \Polars\LazyFrame
+         * Cast Series to a different data type
+         * @param string $dtype One of: 'int8', 'int16', 'int32', 'int64', 'uint8', 'uint16', 'uint32', 'uint64', 'float32', 'float64', 'bool', 'string'
          *
-         * @return \Polars\LazyFrame
+         * @param string $dtype
+         * @return \Polars\Series
          */
-        public function count(): \Polars\LazyFrame {}
+        public function cast(string $dtype): \Polars\Series {}
 
         /**
-         * First row per group
-         * @return \Polars\LazyFrame
+         * Compute the cube root of the elements
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function first(): \Polars\LazyFrame {}
+        public function cbrt(): \Polars\Series {}
 
         /**
-         * First n rows per group
-         * @return \Polars\LazyFrame
+         * Rounds up to the nearest integer value
          *
-         * @param int $n
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function head(int $n = 5): \Polars\LazyFrame {}
+        public function ceil(): \Polars\Series {}
 
         /**
-         * Last row per group
-         * @return \Polars\LazyFrame
+         * Set values outside the given boundaries to the boundary value. Null bound means no bound on that side
+         * @param int|float|null $lowerBound
+         * @param int|float|null $upperBound
          *
-         * @return \Polars\LazyFrame
+         * @param mixed $lowerBound
+         * @param mixed $upperBound
+         * @return \Polars\Series
          */
-        public function last(): \Polars\LazyFrame {}
+        public function clip(mixed $lowerBound = null, mixed $upperBound = null): \Polars\Series {}
 
         /**
-         * Max per group
-         * @return \Polars\LazyFrame
+         * Create a copy of the Series
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function max(): \Polars\LazyFrame {}
+        public function copy(): \Polars\Series {}
 
         /**
-         * Mean per group
-         * @return \Polars\LazyFrame
+         * Compute the correlation with another Series
+         * @param string $method One of: 'pearson', 'spearman'
+         * @throws Polars\Exception
          *
-         * @return \Polars\LazyFrame
+         * @param \Polars\Series $other
+         * @param string $method
+         * @param bool $propagateNans
+         * @return mixed
          */
-        public function mean(): \Polars\LazyFrame {}
+        public function corr(\Polars\Series $other, string $method = "pearson", bool $propagateNans = false): mixed {}
 
         /**
-         * Median per group
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the cosine
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function median(): \Polars\LazyFrame {}
+        public function cos(): \Polars\Series {}
 
         /**
-         * Min per group
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the hyperbolic cosine
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function min(): \Polars\LazyFrame {}
+        public function cosh(): \Polars\Series {}
 
         /**
-         * Sum per group
-         * @return \Polars\LazyFrame
+         * Compute the element-wise value for the cotangent
          *
-         * @return \Polars\LazyFrame
+         * @return \Polars\Series
          */
-        public function sum(): \Polars\LazyFrame {}
+        public function cot(): \Polars\Series {}
 
         /**
-         * Last n rows per group
-         * @return \Polars\LazyFrame
+         * Get the number of elements (Countable interface)
          *
-         * @param int $n
-         * @return \Polars\LazyFrame
+         * @return int
          */
-        public function tail(int $n = 5): \Polars\LazyFrame {}
-    }
+        public function count(): int {}
 
-    enum QuantileMethod {
-      case Nearest;
-      case Lower;
-      case Higher;
-      case Midpoint;
-      case Linear;
-      case Equiprobable;
-    }
+        /**
+         * Count non-null values
+         *
+         * @return int
+         */
+        public function countNonNull(): int {}
 
-    class Series implements \ArrayAccess, \Countable {
         /**
-         * Get the data type of the Series
+         * Compute the covariance with another Series
+         * @throws Polars\Exception
          *
-         * @var \Polars\DataType
+         * @param \Polars\Series $other
+         * @param int $ddof
+         * @return mixed
          */
-        public readonly \Polars\DataType $dtype;
+        public function cov(\Polars\Series $other, int $ddof = 1): mixed {}
 
         /**
-         * Get the name of the Series
+         * Get the cumulative count of non-null values computed at every element
          *
-         * @var string
+         * @param bool $reverse
+         * @return \Polars\Series
          */
-        public readonly string $name;
+        public function cumCount(bool $reverse = false): \Polars\Series {}
 
         /**
-         * Get the shape of the Series as [length]
+         * Get the cumulative max computed at every element
          *
-         * @var array
+         * @param bool $reverse
+         * @return \Polars\Series
          */
-        public readonly array $shape;
+        public function cumMax(bool $reverse = false): \Polars\Series {}
 
         /**
-         * Create a new Series from a PHP array
+         * Get the cumulative min computed at every element
          *
-         * @param string $name
-         * @param array $values
+         * @param bool $reverse
+         * @return \Polars\Series
          */
-        public function __construct(string $name = "", array $values) {}
+        public function cumMin(bool $reverse = false): \Polars\Series {}
 
         /**
-         * Display the Series
+         * Get the cumulative product computed at every element
          *
-         * @return string
+         * @param bool $reverse
+         * @return \Polars\Series
          */
-        public function __toString(): string {}
+        public function cumProd(bool $reverse = false): \Polars\Series {}
 
         /**
-         * Create an alias for the Series (same as rename)
+         * Get the cumulative sum computed at every element
          *
-         * @param string $name
+         * @param bool $reverse
          * @return \Polars\Series
          */
-        public function alias(string $name): \Polars\Series {}
+        public function cumSum(bool $reverse = false): \Polars\Series {}
 
         /**
-         * Check if all values are true (for boolean Series)
+         * Run an expression over a sliding window that increases by one slot every iteration.
+         * Use `Expr::element()` to refer to the values in the window
+         * @throws Polars\Exception
          *
-         * @return bool
+         * @param \Polars\Expr $expr
+         * @param int $minSamples
+         * @return \Polars\Series
          */
-        public function all(): bool {}
+        public function cumulativeEval(\Polars\Expr $expr, int $minSamples = 1): \Polars\Series {}
 
         /**
-         * Check if any value is true (for boolean Series)
+         * Convert from radians to degrees
          *
-         * @return bool
+         * @return \Polars\Series
          */
-        public function any(): bool {}
+        public function degrees(): \Polars\Series {}
 
         /**
-         * Get the index of the maximum value
+         * Calculate the first discrete difference between shifted items
+         * @param string $nullBehavior One of: 'ignore', 'drop'
          *
-         * @return mixed
+         * @param int $n
+         * @param string $nullBehavior
+         * @return \Polars\Series
          */
-        public function argMax(): mixed {}
+        public function diff(int $n = 1, string $nullBehavior = "ignore"): \Polars\Series {}
 
         /**
-         * Get the index of the minimum value
+         * Compute the dot/inner product with another Series
          *
+         * @param \Polars\Series $other
          * @return mixed
          */
-        public function argMin(): mixed {}
+        public function dot(\Polars\Series $other): mixed {}
 
         /**
-         * Cast Series to a different data type
-         * @param string $dtype One of: 'int8', 'int16', 'int32', 'int64', 'uint8', 'uint16', 'uint32', 'uint64', 'float32', 'float64', 'bool', 'string'
+         * Remove null values
          *
-         * @param string $dtype
          * @return \Polars\Series
          */
-        public function cast(string $dtype): \Polars\Series {}
+        public function dropNulls(): \Polars\Series {}
 
         /**
-         * Create a copy of the Series
+         * Element-wise equality comparison
+         * @param int|float|string|bool|null $other
          *
+         * @param mixed $other
          * @return \Polars\Series
          */
-        public function copy(): \Polars\Series {}
+        public function eq(mixed $other): \Polars\Series {}
 
         /**
-         * Get the number of elements (Countable interface)
+         * Compute exponentially-weighted moving mean.
+         * Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
          *
-         * @return int
+         * @param float|null $com
+         * @param float|null $span
+         * @param float|null $halfLife
+         * @param float|null $alpha
+         * @param bool $adjust
+         * @param int $minPeriods
+         * @param bool $ignoreNulls
+         * @return \Polars\Series
          */
-        public function count(): int {}
+        public function ewmMean(?float $com = null, ?float $span = null, ?float $halfLife = null, ?float $alpha = null, bool $adjust = true, int $minPeriods = 1, bool $ignoreNulls = false): \Polars\Series {}
 
         /**
-         * Count non-null values
+         * Compute time-based exponentially weighted moving average, using `by` as the times of the values
+         * @param string $halfLife Duration string such as '1d' over which an observation decays to half its value
          *
-         * @return int
+         * @param \Polars\Series $by
+         * @param string $halfLife
+         * @return \Polars\Series
          */
-        public function countNonNull(): int {}
+        public function ewmMeanBy(\Polars\Series $by, string $halfLife): \Polars\Series {}
 
         /**
-         * Remove null values
+         * Compute exponentially-weighted moving standard deviation.
+         * Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
          *
+         * @param float|null $com
+         * @param float|null $span
+         * @param float|null $halfLife
+         * @param float|null $alpha
+         * @param bool $adjust
+         * @param bool $bias
+         * @param int $minPeriods
+         * @param bool $ignoreNulls
          * @return \Polars\Series
          */
-        public function dropNulls(): \Polars\Series {}
+        public function ewmStd(?float $com = null, ?float $span = null, ?float $halfLife = null, ?float $alpha = null, bool $adjust = true, bool $bias = false, int $minPeriods = 1, bool $ignoreNulls = false): \Polars\Series {}
 
         /**
-         * Element-wise equality comparison
-         * @param int|float|string|bool|null $other
+         * Compute exponentially-weighted moving variance.
+         * Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
          *
-         * @param mixed $other
+         * @param float|null $com
+         * @param float|null $span
+         * @param float|null $halfLife
+         * @param float|null $alpha
+         * @param bool $adjust
+         * @param bool $bias
+         * @param int $minPeriods
+         * @param bool $ignoreNulls
          * @return \Polars\Series
          */
-        public function eq(mixed $other): \Polars\Series {}
+        public function ewmVar(?float $com = null, ?float $span = null, ?float $halfLife = null, ?float $alpha = null, bool $adjust = true, bool $bias = false, int $minPeriods = 1, bool $ignoreNulls = false): \Polars\Series {}
+
+        /**
+         * Compute the exponential, element-wise
+         *
+         * @return \Polars\Series
+         */
+        public function exp(): \Polars\Series {}
 
         /**
          * Fill null values using backward strategy
@@ -1997,6 +4760,13 @@ namespace Polars {
          */
         public function first(): mixed {}
 
+        /**
+         * Rounds down to the nearest integer value
+         *
+         * @return \Polars\Series
+         */
+        public function floor(): \Polars\Series {}
+
         /**
          * Element-wise greater than or equal comparison
          * @param int|float|string|bool|null $other
@@ -2030,6 +4800,22 @@ namespace Polars {
          */
         public function head(int $n = 10): \Polars\Series {}
 
+        /**
+         * Bin values into buckets and count their occurrences
+         * @param array|null $bins Bin edges; the first bucket is closed on both sides
+         * @param int|null $binCount Number of equal-width bins spanning the data range; defaults to 10 without `bins`
+         * @param bool $includeCategory Include a `category` column with the bin interval
+         * @param bool $includeBreakpoint Include a `breakpoint` column with the right edge of the bin
+         * @throws Polars\Exception
+         *
+         * @param array|null $bins
+         * @param int|null $binCount
+         * @param bool $includeCategory
+         * @param bool $includeBreakpoint
+         * @return \Polars\DataFrame
+         */
+        public function hist(?array $bins = null, ?int $binCount = null, bool $includeCategory = true, bool $includeBreakpoint = true): \Polars\DataFrame {}
+
         /**
          * Aggregate all values into a single list
          *
@@ -2102,6 +4888,28 @@ namespace Polars {
          */
         public function len(): int {}
 
+        /**
+         * Compute the logarithm to a given base. Natural logarithm is used when base is null
+         *
+         * @param float|null $base
+         * @return \Polars\Series
+         */
+        public function log(?float $base = null): \Polars\Series {}
+
+        /**
+         * Compute the base 10 logarithm of the elements
+         *
+         * @return \Polars\Series
+         */
+        public function log10(): \Polars\Series {}
+
+        /**
+         * Compute the natural logarithm of the elements plus one
+         *
+         * @return \Polars\Series
+         */
+        public function log1p(): \Polars\Series {}
+
         /**
          * Element-wise less than comparison
          * @param int|float|string|bool|null $other
@@ -2234,6 +5042,14 @@ namespace Polars {
          */
         public function offsetUnset(mixed $_offset): void {}
 
+        /**
+         * Compute percentage change between values
+         *
+         * @param int $n
+         * @return \Polars\Series
+         */
+        public function pctChange(int $n = 1): \Polars\Series {}
+
         /**
          * Get the product of all values
          *
@@ -2251,6 +5067,13 @@ namespace Polars {
          */
         public function quantile(float $quantile, string $method = "linear"): mixed {}
 
+        /**
+         * Convert from degrees to radians
+         *
+         * @return \Polars\Series
+         */
+        public function radians(): \Polars\Series {}
+
         /**
          * Rename the Series
          *
@@ -2266,6 +5089,229 @@ namespace Polars {
          */
         public function reverse(): \Polars\Series {}
 
+        /**
+         * Apply a rolling max over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Series
+         */
+        public function rollingMax(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Series {}
+
+        /**
+         * Apply a rolling max over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Series
+         */
+        public function rollingMaxBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Series {}
+
+        /**
+         * Apply a rolling mean over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Series
+         */
+        public function rollingMean(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Series {}
+
+        /**
+         * Apply a rolling mean over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Series
+         */
+        public function rollingMeanBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Series {}
+
+        /**
+         * Apply a rolling median over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Series
+         */
+        public function rollingMedian(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Series {}
+
+        /**
+         * Apply a rolling median over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Series
+         */
+        public function rollingMedianBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Series {}
+
+        /**
+         * Apply a rolling min over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Series
+         */
+        public function rollingMin(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Series {}
+
+        /**
+         * Apply a rolling min over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Series
+         */
+        public function rollingMinBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Series {}
+
+        /**
+         * Apply a rolling quantile over a fixed window of `windowSize` values
+         *
+         * @param float $quantile
+         * @param int $windowSize
+         * @param \Polars\QuantileMethod $interpolation
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Series
+         */
+        public function rollingQuantile(float $quantile, int $windowSize, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Series {}
+
+        /**
+         * Apply a rolling quantile over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param float $quantile
+         * @param \Polars\QuantileMethod $interpolation
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Series
+         */
+        public function rollingQuantileBy(\Polars\Series $by, string $windowSize, float $quantile, \Polars\QuantileMethod $interpolation = PolarsQuantileMethod :: Nearest, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Series {}
+
+        /**
+         * Apply a rolling standard deviation over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @param int $ddof
+         * @return \Polars\Series
+         */
+        public function rollingStd(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null, int $ddof = 1): \Polars\Series {}
+
+        /**
+         * Apply a rolling standard deviation over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @param int $ddof
+         * @return \Polars\Series
+         */
+        public function rollingStdBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right, int $ddof = 1): \Polars\Series {}
+
+        /**
+         * Apply a rolling sum over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @return \Polars\Series
+         */
+        public function rollingSum(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null): \Polars\Series {}
+
+        /**
+         * Apply a rolling sum over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @return \Polars\Series
+         */
+        public function rollingSumBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right): \Polars\Series {}
+
+        /**
+         * Apply a rolling variance over a fixed window of `windowSize` values
+         *
+         * @param int $windowSize
+         * @param int|null $minPeriods
+         * @param bool $center
+         * @param array|null $weights
+         * @param int $ddof
+         * @return \Polars\Series
+         */
+        public function rollingVar(int $windowSize, ?int $minPeriods = null, bool $center = false, ?array $weights = null, int $ddof = 1): \Polars\Series {}
+
+        /**
+         * Apply a rolling variance over a temporal window (e.g. '7d') based on the values of `by`
+         *
+         * @param \Polars\Series $by
+         * @param string $windowSize
+         * @param int $minPeriods
+         * @param \Polars\ClosedInterval $closed
+         * @param int $ddof
+         * @return \Polars\Series
+         */
+        public function rollingVarBy(\Polars\Series $by, string $windowSize, int $minPeriods = 1, \Polars\ClosedInterval $closed = PolarsClosedInterval :: Right, int $ddof = 1): \Polars\Series {}
+
+        /**
+         * Round underlying floating point data by `decimals` digits
+         * @param string $mode One of: 'half_to_even', 'half_away_from_zero'
+         *
+         * @param int $decimals
+         * @param string $mode
+         * @return \Polars\Series
+         */
+        public function round(int $decimals = 0, string $mode = "half_to_even"): \Polars\Series {}
+
+        /**
+         * Round to a number of significant figures
+         *
+         * @param int $digits
+         * @return \Polars\Series
+         */
+        public function roundSigFigs(int $digits): \Polars\Series {}
+
+        /**
+         * Compute the element-wise sign function on numeric types (-1, 0 or 1)
+         *
+         * @return \Polars\Series
+         */
+        public function sign(): \Polars\Series {}
+
+        /**
+         * Compute the element-wise value for the sine
+         *
+         * @return \Polars\Series
+         */
+        public function sin(): \Polars\Series {}
+
+        /**
+         * Compute the element-wise value for the hyperbolic sine
+         *
+         * @return \Polars\Series
+         */
+        public function sinh(): \Polars\Series {}
+
         /**
          * Extract a slice of the Series
          *
@@ -2284,6 +5330,13 @@ namespace Polars {
          */
         public function sort(bool $descending = false, bool $nullsLast = true): \Polars\Series {}
 
+        /**
+         * Compute the square root of the elements
+         *
+         * @return \Polars\Series
+         */
+        public function sqrt(): \Polars\Series {}
+
         /**
          * Get the standard deviation
          *
@@ -2307,6 +5360,20 @@ namespace Polars {
          */
         public function tail(int $n = 10): \Polars\Series {}
 
+        /**
+         * Compute the element-wise value for the tangent
+         *
+         * @return \Polars\Series
+         */
+        public function tan(): \Polars\Series {}
+
+        /**
+         * Compute the element-wise value for the hyperbolic tangent
+         *
+         * @return \Polars\Series
+         */
+        public function tanh(): \Polars\Series {}
+
         /**
          * Convert Series to PHP array
          *
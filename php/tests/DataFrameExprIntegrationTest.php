@@ -7,6 +7,7 @@ use Polars\ClosedInterval;
 use Polars\DataFrame;
 use Polars\Expr;
 use Polars\QuantileMethod;
+use Polars\Series;
 
 class DataFrameExprIntegrationTest extends TestCase
 {
@@ -612,6 +613,117 @@ class DataFrameExprIntegrationTest extends TestCase
         $this->assertEquals(0b0110, $result->item()); // 15 ^ 10 ^ 3 = 6
     }
 
+    public function testSelectWithElementWiseBitwise(): void
+    {
+        $df = new DataFrame(['x' => [0b1100, 0b1010], 'y' => [0b1010, 0b0110]]);
+
+        $result = $df->select([
+            Expr::col('x')->bitwiseAnd(Expr::col('y'))->alias('and'),
+            Expr::col('x')->bitwiseOr(Expr::col('y'))->alias('or'),
+            Expr::col('x')->bitwiseXor(Expr::col('y'))->alias('xor'),
+        ]);
+
+        $this->assertEquals([0b1000, 0b0010], $result->column('and')->toArray());
+        $this->assertEquals([0b1110, 0b1110], $result->column('or')->toArray());
+        $this->assertEquals([0b0110, 0b1100], $result->column('xor')->toArray());
+    }
+
+    public function testSelectWithBitwiseCounts(): void
+    {
+        $df = new DataFrame(['x' => [0b1011, 0b1000]]);
+
+        $result = $df->select([
+            Expr::col('x')->bitwiseCountOnes()->alias('ones'),
+            Expr::col('x')->bitwiseCountZeros()->alias('zeros'),
+            Expr::col('x')->bitwiseTrailingZeros()->alias('trailing'),
+            Expr::col('x')->bitwiseLeadingZeros()->alias('leading'),
+        ]);
+
+        $this->assertEquals([3, 1], $result->column('ones')->toArray());
+        $this->assertEquals([61, 63], $result->column('zeros')->toArray());
+        $this->assertEquals([0, 3], $result->column('trailing')->toArray());
+        $this->assertEquals([60, 60], $result->column('leading')->toArray());
+    }
+
+    public function testSelectWithShifts(): void
+    {
+        $df = new DataFrame(['x' => [1, 5, -8]]);
+
+        $result = $df->select([
+            Expr::col('x')->shiftLeft(3)->alias('left'),
+            Expr::col('x')->shiftRight(1)->alias('right'),
+        ]);
+
+        $this->assertEquals([8, 40, -64], $result->column('left')->toArray());
+        $this->assertEquals([0, 2, -4], $result->column('right')->toArray());
+    }
+
+    public function testShiftsKeepInt8Type(): void
+    {
+        $df = new DataFrame(['x' => [1, 64, -1]]);
+
+        $result = $df->select([
+            Expr::col('x')->cast('int8')->shiftLeft(2)->alias('left'),
+            Expr::col('x')->cast('int8')->shiftLeft(10)->alias('out'),
+            Expr::col('x')->cast('int8')->shiftRight(10)->alias('sign'),
+        ]);
+
+        $this->assertEquals([4, 0, -4], $result->column('left')->toArray());
+        $this->assertEquals([0, 0, 0], $result->column('out')->toArray());
+        $this->assertEquals([0, 0, -1], $result->column('sign')->toArray());
+        $this->assertEquals('i8', (string) $result->dtypes[0]);
+        $this->assertEquals('i8', (string) $result->dtypes[1]);
+    }
+
+    public function testShiftLeftWrapsInt32(): void
+    {
+        $df = new DataFrame(['x' => [1 << 30, 3]]);
+
+        $result = $df->select([Expr::col('x')->cast('int32')->shiftLeft(1)]);
+
+        $this->assertEquals([-2147483648, 6], $result->column('x')->toArray());
+        $this->assertEquals('i32', (string) $result->dtypes[0]);
+    }
+
+    public function testShiftBySixtyThree(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, -8]]);
+
+        $result = $df->select([
+            Expr::col('x')->shiftLeft(63)->alias('left'),
+            Expr::col('x')->shiftRight(63)->alias('right'),
+        ]);
+
+        $this->assertEquals([PHP_INT_MIN, 0, 0], $result->column('left')->toArray());
+        $this->assertEquals([0, 0, -1], $result->column('right')->toArray());
+    }
+
+    public function testSelectWithHash(): void
+    {
+        $df = new DataFrame(['x' => ['a', 'b', 'a']]);
+
+        $result = $df->select([
+            Expr::col('x')->hash(1)->alias('h1'),
+            Expr::col('x')->hash(2)->alias('h2'),
+        ]);
+
+        $h1 = $result->column('h1')->toArray();
+        $this->assertEquals($h1[0], $h1[2]);
+        $this->assertNotEquals($h1[0], $h1[1]);
+        $this->assertNotEquals($h1, $result->column('h2')->toArray());
+        $this->assertEquals('u64', (string)$result->dtypes[0]);
+    }
+
+    public function testSelectWithReinterpret(): void
+    {
+        $df = new DataFrame(['x' => [1, 2]]);
+
+        $result = $df->select([Expr::col('x')->reinterpret(false)]);
+
+        $this->assertEquals('u64', (string)$result->dtypes[0]);
+        $this->assertEquals([1, 2], $result->column('x')->toArray());
+    }
+
     public function testSelectWithImplode(): void
     {
         $df = new DataFrame(['x' => [1, 2, 3]]);
@@ -677,4 +789,855 @@ class DataFrameExprIntegrationTest extends TestCase
         // Returns counts per unique value: 3 unique values
         $this->assertEquals(3, $result->height());
     }
+
+    public function testSelectWithAbsAndSign(): void
+    {
+        $df = new DataFrame(['x' => [-2, 0, 3]]);
+
+        $result = $df->select([
+            Expr::col('x')->abs()->alias('abs'),
+            Expr::col('x')->sign()->alias('sign'),
+        ]);
+
+        $this->assertEquals([2, 0, 3], $result->column('abs')->toArray());
+        $this->assertEquals([-1, 0, 1], $result->column('sign')->toArray());
+    }
+
+    public function testSelectWithSqrtAndLog(): void
+    {
+        $df = new DataFrame(['x' => [1.0, 4.0, 100.0]]);
+
+        $result = $df->select([
+            Expr::col('x')->sqrt()->alias('sqrt'),
+            Expr::col('x')->log10()->alias('log10'),
+            Expr::col('x')->log(2.0)->alias('log2'),
+        ]);
+
+        $this->assertEqualsWithDelta([1.0, 2.0, 10.0], $result->column('sqrt')->toArray(), 0.0001);
+        $this->assertEqualsWithDelta([0.0, 0.602, 2.0], $result->column('log10')->toArray(), 0.001);
+        $this->assertEqualsWithDelta(2.0, $result->column('log2')[1], 0.0001);
+    }
+
+    public function testSelectWithTrigonometry(): void
+    {
+        $df = new DataFrame(['x' => [0.0, 90.0, 180.0]]);
+
+        $result = $df->select([
+            Expr::col('x')->radians()->sin()->alias('sin'),
+            Expr::col('x')->radians()->degrees()->alias('roundtrip'),
+        ]);
+
+        $this->assertEqualsWithDelta([0.0, 1.0, 0.0], $result->column('sin')->toArray(), 0.0001);
+        $this->assertEqualsWithDelta([0.0, 90.0, 180.0], $result->column('roundtrip')->toArray(), 0.0001);
+    }
+
+    public function testSelectWithRounding(): void
+    {
+        $df = new DataFrame(['x' => [1.25, -1.75, 2.5]]);
+
+        $result = $df->select([
+            Expr::col('x')->floor()->alias('floor'),
+            Expr::col('x')->ceil()->alias('ceil'),
+            Expr::col('x')->round()->alias('even'),
+            Expr::col('x')->round(0, 'half_away_from_zero')->alias('away'),
+        ]);
+
+        $this->assertEquals([1.0, -2.0, 2.0], $result->column('floor')->toArray());
+        $this->assertEquals([2.0, -1.0, 3.0], $result->column('ceil')->toArray());
+        $this->assertEquals([1.0, -2.0, 2.0], $result->column('even')->toArray());
+        $this->assertEquals([1.0, -2.0, 3.0], $result->column('away')->toArray());
+    }
+
+    public function testSelectWithRoundSigFigs(): void
+    {
+        $df = new DataFrame(['x' => [1234.5, 0.012345]]);
+
+        $result = $df->select([Expr::col('x')->roundSigFigs(2)]);
+
+        $this->assertEqualsWithDelta([1200.0, 0.012], $result->column('x')->toArray(), 0.0001);
+    }
+
+    public function testSelectWithClip(): void
+    {
+        $df = new DataFrame(['x' => [-5, 0, 5, 10]]);
+
+        $result = $df->select([
+            Expr::col('x')->clip(0, 6)->alias('both'),
+            Expr::col('x')->clip(lowerBound: 0)->alias('lower'),
+            Expr::col('x')->clip(upperBound: 6)->alias('upper'),
+        ]);
+
+        $this->assertEquals([0, 0, 5, 6], $result->column('both')->toArray());
+        $this->assertEquals([0, 0, 5, 10], $result->column('lower')->toArray());
+        $this->assertEquals([-5, 0, 5, 6], $result->column('upper')->toArray());
+    }
+
+    public function testSelectWithDot(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, 3], 'b' => [4, 5, 6]]);
+
+        $result = $df->select([Expr::col('a')->dot(Expr::col('b'))]);
+
+        $this->assertEquals(32, $result->item());
+    }
+
+    public function testWithColumnsCumulative(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 4, 7]]);
+
+        $result = $df->withColumns([
+            Expr::col('x')->cumSum()->alias('sum'),
+            Expr::col('x')->cumSum(reverse: true)->alias('sum_rev'),
+            Expr::col('x')->cumProd()->alias('prod'),
+            Expr::col('x')->cumMax()->alias('max'),
+            Expr::col('x')->cumCount()->alias('count'),
+        ]);
+
+        $this->assertEquals([1, 3, 7, 14], $result->column('sum')->toArray());
+        $this->assertEquals([14, 13, 11, 7], $result->column('sum_rev')->toArray());
+        $this->assertEquals([1, 2, 8, 56], $result->column('prod')->toArray());
+        $this->assertEquals([1, 2, 4, 7], $result->column('max')->toArray());
+        $this->assertEquals([1, 2, 3, 4], $result->column('count')->toArray());
+    }
+
+    public function testWithColumnsDiffAndPctChange(): void
+    {
+        $df = new DataFrame(['x' => [10, 15, 12, 24]]);
+
+        $result = $df->withColumns([
+            Expr::col('x')->diff()->alias('diff'),
+            Expr::col('x')->diff(2)->alias('diff2'),
+            Expr::col('x')->pctChange()->alias('pct'),
+        ]);
+
+        $this->assertEquals([null, 5, -3, 12], $result->column('diff')->toArray());
+        $this->assertEquals([null, null, 2, 9], $result->column('diff2')->toArray());
+        $this->assertEqualsWithDelta(0.5, $result->column('pct')[1], 0.0001);
+        $this->assertEqualsWithDelta(1.0, $result->column('pct')[3], 0.0001);
+    }
+
+    public function testCumulativeEval(): void
+    {
+        $df = new DataFrame(['x' => [1.0, 2.0, 3.0, 4.0]]);
+
+        $result = $df->select([Expr::col('x')->cumulativeEval(Expr::element()->mean())]);
+
+        $this->assertEqualsWithDelta([1.0, 1.5, 2.0, 2.5], $result->column('x')->toArray(), 0.0001);
+    }
+
+    public function testCumSumOverPartition(): void
+    {
+        $df = new DataFrame([
+            'account' => ['a', 'b', 'a', 'b', 'a'],
+            'amount' => [10, 1, 20, 2, 30],
+        ]);
+
+        $result = $df->withColumns([
+            Expr::col('amount')->cumSum()->over('account')->alias('balance'),
+            Expr::col('amount')->sum()->over('account')->alias('total'),
+        ]);
+
+        $this->assertEquals([10, 1, 30, 3, 60], $result->column('balance')->toArray());
+        $this->assertEquals([60, 3, 60, 3, 60], $result->column('total')->toArray());
+    }
+
+    public function testOverWithOrderBy(): void
+    {
+        $df = new DataFrame([
+            'group' => ['a', 'a', 'a'],
+            'order' => [3, 1, 2],
+            'value' => [100, 1, 10],
+        ]);
+
+        $result = $df->withColumns([
+            Expr::col('value')->cumSum()->over('group', orderBy: 'order')->alias('running'),
+        ]);
+
+        $this->assertEquals([111, 1, 11], $result->column('running')->toArray());
+    }
+
+    public function testRollingMean(): void
+    {
+        $df = new DataFrame(['x' => [1.0, 2.0, 3.0, 4.0, 5.0]]);
+
+        $result = $df->withColumns([
+            Expr::col('x')->rollingMean(2)->alias('mean'),
+            Expr::col('x')->rollingSum(3, minPeriods: 1)->alias('sum'),
+            Expr::col('x')->rollingMax(3, center: true)->alias('max'),
+        ]);
+
+        $this->assertEquals([null, 1.5, 2.5, 3.5, 4.5], $result->column('mean')->toArray());
+        $this->assertEquals([1.0, 3.0, 6.0, 9.0, 12.0], $result->column('sum')->toArray());
+        $this->assertEquals([null, 3.0, 4.0, 5.0, null], $result->column('max')->toArray());
+    }
+
+    public function testRollingStdAndQuantile(): void
+    {
+        $df = new DataFrame(['x' => [1.0, 3.0, 5.0, 7.0]]);
+
+        $result = $df->select([
+            Expr::col('x')->rollingStd(2)->alias('std'),
+            Expr::col('x')->rollingQuantile(0.5, 3, QuantileMethod::Linear)->alias('q'),
+        ]);
+
+        $this->assertEqualsWithDelta(1.4142, $result->column('std')[1], 0.0001);
+        $this->assertEquals([null, null, 3.0, 5.0], $result->column('q')->toArray());
+    }
+
+    public function testRollingSumByIntegerIndex(): void
+    {
+        $df = new DataFrame([
+            'idx' => [0, 1, 2, 5, 6],
+            'x' => [1.0, 2.0, 3.0, 4.0, 5.0],
+        ]);
+
+        $result = $df->withColumns([
+            Expr::col('x')->rollingSumBy('idx', '2i')->alias('right'),
+            Expr::col('x')->rollingSumBy('idx', '2i', closed: ClosedInterval::Both)->alias('both'),
+        ]);
+
+        $this->assertEquals([1.0, 3.0, 5.0, 4.0, 9.0], $result->column('right')->toArray());
+        $this->assertEquals([1.0, 3.0, 6.0, 4.0, 9.0], $result->column('both')->toArray());
+    }
+
+    public function testEwmMean(): void
+    {
+        $df = new DataFrame(['x' => [1.0, 2.0, 3.0]]);
+
+        $result = $df->withColumns([
+            Expr::col('x')->ewmMean(alpha: 0.5, adjust: false)->alias('unadjusted'),
+            Expr::col('x')->ewmMean(com: 1.0)->alias('adjusted'),
+        ]);
+
+        $this->assertEqualsWithDelta([1.0, 1.5, 2.25], $result->column('unadjusted')->toArray(), 0.0001);
+        $this->assertEqualsWithDelta([1.0, 1.6667, 2.4286], $result->column('adjusted')->toArray(), 0.0001);
+    }
+
+    public function testEwmMeanByIntegerIndex(): void
+    {
+        $df = new DataFrame([
+            't' => [0, 1, 2],
+            'x' => [1.0, 2.0, 3.0],
+        ]);
+
+        $result = $df->select([Expr::col('x')->ewmMeanBy('t', '1i')]);
+
+        $this->assertEqualsWithDelta([1.0, 1.5, 2.25], $result->column('x')->toArray(), 0.0001);
+    }
+
+    public function testRankMethods(): void
+    {
+        $df = new DataFrame(['x' => [3, 1, 2, 2]]);
+
+        $result = $df->select([
+            Expr::col('x')->rank()->alias('average'),
+            Expr::col('x')->rank('min')->alias('min'),
+            Expr::col('x')->rank('dense', descending: true)->alias('dense_desc'),
+            Expr::col('x')->rank('ordinal')->alias('ordinal'),
+        ]);
+
+        $this->assertEquals([4.0, 1.0, 2.5, 2.5], $result->column('average')->toArray());
+        $this->assertEquals([4, 1, 2, 2], $result->column('min')->toArray());
+        $this->assertEquals([1, 3, 2, 2], $result->column('dense_desc')->toArray());
+        $this->assertEquals([4, 1, 2, 3], $result->column('ordinal')->toArray());
+    }
+
+    public function testSortArgSortAndReverse(): void
+    {
+        $df = new DataFrame(['x' => [3, 1, 2]]);
+
+        $result = $df->select([
+            Expr::col('x')->sort()->alias('asc'),
+            Expr::col('x')->sort(descending: true)->alias('desc'),
+            Expr::col('x')->argSort()->alias('arg'),
+            Expr::col('x')->reverse()->alias('reversed'),
+        ]);
+
+        $this->assertEquals([1, 2, 3], $result->column('asc')->toArray());
+        $this->assertEquals([3, 2, 1], $result->column('desc')->toArray());
+        $this->assertEquals([1, 2, 0], $result->column('arg')->toArray());
+        $this->assertEquals([2, 1, 3], $result->column('reversed')->toArray());
+    }
+
+    public function testSortByMultipleColumns(): void
+    {
+        $df = new DataFrame([
+            'name' => ['a', 'b', 'c', 'd'],
+            'group' => [1, 2, 1, 2],
+            'score' => [10, 20, 30, 40],
+        ]);
+
+        $result = $df->select([
+            Expr::col('name')->sortBy(['group', 'score'], [false, true]),
+        ]);
+
+        $this->assertEquals(['c', 'a', 'd', 'b'], $result->column('name')->toArray());
+    }
+
+    public function testTopKAndBottomKBy(): void
+    {
+        $df = new DataFrame([
+            'name' => ['a', 'b', 'c', 'd'],
+            'score' => [10, 40, 30, 20],
+        ]);
+
+        $result = $df->select([
+            Expr::col('score')->topK(2)->sort(descending: true)->alias('top'),
+            Expr::col('score')->bottomK(2)->sort()->alias('bottom'),
+            Expr::col('name')->topKBy('score', 2)->sort()->alias('top_names'),
+            Expr::col('name')->bottomKBy('score', 2)->sort()->alias('bottom_names'),
+        ]);
+
+        $this->assertEquals([40, 30], $result->column('top')->toArray());
+        $this->assertEquals([10, 20], $result->column('bottom')->toArray());
+        $this->assertEquals(['b', 'c'], $result->column('top_names')->toArray());
+        $this->assertEquals(['a', 'd'], $result->column('bottom_names')->toArray());
+    }
+
+    public function testOrderingExpressionsInGroupByAndOver(): void
+    {
+        $df = new DataFrame([
+            'team' => ['x', 'x', 'y', 'y'],
+            'player' => ['a', 'b', 'c', 'd'],
+            'score' => [5, 9, 7, 3],
+        ]);
+
+        $aggregated = $df->lazy()
+            ->groupBy(['team'])
+            ->agg([Expr::col('player')->sortBy('score', descending: true)->first()->alias('best')])
+            ->sort('team')
+            ->collect();
+
+        $this->assertEquals(['b', 'c'], $aggregated->column('best')->toArray());
+
+        $ranked = $df->withColumns([
+            Expr::col('score')->rank('ordinal', descending: true)->over('team')->alias('position'),
+        ]);
+
+        $this->assertEquals([2, 1, 1, 2], $ranked->column('position')->toArray());
+    }
+
+    public function testShuffleAndSampleKeepValues(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4]]);
+
+        $shuffled = $df->select([Expr::col('x')->shuffle(seed: 7)])->column('x')->toArray();
+        sort($shuffled);
+        $this->assertEquals([1, 2, 3, 4], $shuffled);
+
+        $sampled = $df->select([Expr::col('x')->sample(n: 2, seed: 7)]);
+        $this->assertEquals(2, $sampled->height());
+
+        $fraction = $df->select([Expr::col('x')->sample(fraction: 0.5, seed: 7)]);
+        $this->assertEquals(2, $fraction->height());
+    }
+
+    public function testIsIn(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4], 's' => ['a', 'b', 'c', 'd']]);
+
+        $result = $df->select([
+            Expr::col('x')->isIn([2, 4]),
+            Expr::col('s')->isIn(new Series('allowed', ['a', 'c'])),
+        ]);
+
+        $this->assertEquals([false, true, false, true], $result->column('x')->toArray());
+        $this->assertEquals([true, false, true, false], $result->column('s')->toArray());
+    }
+
+    public function testReplaceAndReplaceStrict(): void
+    {
+        $df = new DataFrame(['code' => [1, 2, 3], 'country' => ['PL', 'DE', 'US']]);
+
+        $result = $df->select([
+            Expr::col('code')->replace([1 => 10, 2 => 20])->alias('replaced'),
+            Expr::col('country')->replace(['PL' => 'Poland'], default: 'other')->alias('with_default'),
+            Expr::col('code')->replaceStrict([1 => 0.5, 2 => 1.5, 3 => 2.5])->alias('strict'),
+            Expr::col('code')->replaceStrict([1 => 1], default: 0, returnDtype: 'float64')->alias('typed'),
+        ]);
+
+        $this->assertEquals([10, 20, 3], $result->column('replaced')->toArray());
+        $this->assertEquals(['Poland', 'other', 'other'], $result->column('with_default')->toArray());
+        $this->assertEquals([0.5, 1.5, 2.5], $result->column('strict')->toArray());
+        $this->assertEquals([1.0, 0.0, 0.0], $result->column('typed')->toArray());
+    }
+
+    public function testReplaceStrictMissingValueFails(): void
+    {
+        $df = new DataFrame(['code' => [1, 2]]);
+
+        $this->expectException(\Polars\Exception::class);
+        $df->select([Expr::col('code')->replaceStrict([1 => 10])]);
+    }
+
+    public function testDistinctMasks(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 1, 3]]);
+
+        $result = $df->select([
+            Expr::col('x')->isDuplicated()->alias('duplicated'),
+            Expr::col('x')->isUnique()->alias('unique'),
+            Expr::col('x')->isFirstDistinct()->alias('first'),
+            Expr::col('x')->isLastDistinct()->alias('last'),
+        ]);
+
+        $this->assertEquals([true, false, true, false], $result->column('duplicated')->toArray());
+        $this->assertEquals([false, true, false, true], $result->column('unique')->toArray());
+        $this->assertEquals([true, true, false, true], $result->column('first')->toArray());
+        $this->assertEquals([false, true, true, true], $result->column('last')->toArray());
+    }
+
+    public function testUniqueArgUniqueAndValueCounts(): void
+    {
+        $df = new DataFrame(['x' => [3, 1, 3, 2, 1, 3]]);
+
+        $unique = $df->select([Expr::col('x')->unique(maintainOrder: true)]);
+        $this->assertEquals([3, 1, 2], $unique->column('x')->toArray());
+
+        $argUnique = $df->select([Expr::col('x')->argUnique()]);
+        $this->assertEquals([0, 1, 3], $argUnique->column('x')->toArray());
+
+        $counts = $df->select([Expr::col('x')->valueCounts(sort: true)])->unnest(['x']);
+        $this->assertEquals(['x', 'count'], $counts->columns);
+        $this->assertEquals([3, 1, 2], $counts->column('x')->toArray());
+        $this->assertEquals([3, 2, 1], $counts->column('count')->toArray());
+    }
+
+    public function testNullAndNanMasks(): void
+    {
+        $df = new DataFrame([
+            'i' => [1, null, 3],
+            'f' => [1.0, NAN, INF],
+        ]);
+
+        $result = $df->select([
+            Expr::col('i')->isNull()->alias('null'),
+            Expr::col('i')->isNotNull()->alias('not_null'),
+            Expr::col('f')->isNan()->alias('nan'),
+            Expr::col('f')->isNotNan()->alias('not_nan'),
+            Expr::col('f')->isFinite()->alias('finite'),
+            Expr::col('f')->isInfinite()->alias('infinite'),
+        ]);
+
+        $this->assertEquals([false, true, false], $result->column('null')->toArray());
+        $this->assertEquals([true, false, true], $result->column('not_null')->toArray());
+        $this->assertEquals([false, true, false], $result->column('nan')->toArray());
+        $this->assertEquals([true, false, true], $result->column('not_nan')->toArray());
+        $this->assertEquals([true, false, false], $result->column('finite')->toArray());
+        $this->assertEquals([false, false, true], $result->column('infinite')->toArray());
+    }
+
+    public function testFillNullValueAndStrategies(): void
+    {
+        $df = new DataFrame(['x' => [1, null, null, 4]]);
+
+        $result = $df->select([
+            Expr::col('x')->fillNull(0)->alias('value'),
+            Expr::col('x')->fillNull(strategy: 'forward')->alias('forward'),
+            Expr::col('x')->fillNull(strategy: 'backward', limit: 1)->alias('backward'),
+            Expr::col('x')->fillNull(strategy: 'max')->alias('max'),
+            Expr::col('x')->forwardFill(1)->alias('forward_limited'),
+            Expr::col('x')->backwardFill()->alias('backward_fill'),
+        ]);
+
+        $this->assertEquals([1, 0, 0, 4], $result->column('value')->toArray());
+        $this->assertEquals([1, 1, 1, 4], $result->column('forward')->toArray());
+        $this->assertEquals([1, null, 4, 4], $result->column('backward')->toArray());
+        $this->assertEquals([1, 4, 4, 4], $result->column('max')->toArray());
+        $this->assertEquals([1, 1, null, 4], $result->column('forward_limited')->toArray());
+        $this->assertEquals([1, 4, 4, 4], $result->column('backward_fill')->toArray());
+    }
+
+    public function testFillNanAndDrop(): void
+    {
+        $df = new DataFrame(['f' => [1.0, NAN, 3.0], 'i' => [1, null, 3]]);
+
+        $filled = $df->select([Expr::col('f')->fillNan(0.0)]);
+        $this->assertEquals([1.0, 0.0, 3.0], $filled->column('f')->toArray());
+
+        $this->assertEquals([1.0, 3.0], $df->select([Expr::col('f')->dropNans()])->column('f')->toArray());
+        $this->assertEquals([1, 3], $df->select([Expr::col('i')->dropNulls()])->column('i')->toArray());
+    }
+
+    public function testInterpolate(): void
+    {
+        $df = new DataFrame(['x' => [1.0, null, null, 4.0, null]]);
+
+        $result = $df->select([
+            Expr::col('x')->interpolate()->alias('linear'),
+            Expr::col('x')->interpolate('nearest')->alias('nearest'),
+        ]);
+
+        $this->assertEquals([1.0, 2.0, 3.0, 4.0, null], $result->column('linear')->toArray());
+        $this->assertEquals([1.0, 1.0, 4.0, 4.0, null], $result->column('nearest')->toArray());
+    }
+
+    public function testHorizontalReductions(): void
+    {
+        $df = new DataFrame([
+            'a' => [1, null, 3],
+            'b' => [4, 5, 1],
+        ]);
+
+        $result = $df->select([
+            Expr::sumHorizontal(['a', 'b'])->alias('sum'),
+            Expr::sumHorizontal(['a', 'b'], ignoreNulls: false)->alias('sum_strict'),
+            Expr::meanHorizontal(['a', 'b'])->alias('mean'),
+            Expr::minHorizontal(['a', 'b'])->alias('min'),
+            Expr::maxHorizontal([Expr::col('a'), Expr::col('b')])->alias('max'),
+        ]);
+
+        $this->assertEquals([5, 5, 4], $result->column('sum')->toArray());
+        $this->assertEquals([5, null, 4], $result->column('sum_strict')->toArray());
+        $this->assertEquals([2.5, 5.0, 2.0], $result->column('mean')->toArray());
+        $this->assertEquals([1, 5, 1], $result->column('min')->toArray());
+        $this->assertEquals([4, 5, 3], $result->column('max')->toArray());
+    }
+
+    public function testAnyAndAllHorizontal(): void
+    {
+        $df = new DataFrame([
+            'p' => [true, true, false],
+            'q' => [true, false, false],
+        ]);
+
+        $result = $df->select([
+            Expr::anyHorizontal(['p', 'q'])->alias('any'),
+            Expr::allHorizontal(['p', 'q'])->alias('all'),
+        ]);
+
+        $this->assertEquals([true, true, false], $result->column('any')->toArray());
+        $this->assertEquals([true, false, false], $result->column('all')->toArray());
+    }
+
+    public function testCoalesceAndConcat(): void
+    {
+        $df = new DataFrame([
+            'nickname' => [null, 'Bobby', null],
+            'name' => ['Alice', 'Bob', 'Carol'],
+            'last' => ['Smith', null, 'Jones'],
+        ]);
+
+        $result = $df->select([
+            Expr::coalesce(['nickname', 'name'])->alias('display'),
+            Expr::concatStr(['name', 'last'], separator: ' ')->alias('full'),
+            Expr::concatStr(['name', 'last'], separator: ' ', ignoreNulls: true)->alias('full_ignore'),
+            Expr::concatList(['name', 'last'])->alias('list'),
+        ]);
+
+        $this->assertEquals(['Alice', 'Bobby', 'Carol'], $result->column('display')->toArray());
+        $this->assertEquals(['Alice Smith', null, 'Carol Jones'], $result->column('full')->toArray());
+        $this->assertEquals(['Alice Smith', 'Bob', 'Carol Jones'], $result->column('full_ignore')->toArray());
+        $this->assertEquals(
+            ['Alice', 'Smith', 'Bob', null, 'Carol', 'Jones'],
+            $result->select([Expr::col('list')])->explode(['list'])->column('list')->toArray()
+        );
+    }
+
+    public function testFoldAndReduce(): void
+    {
+        $df = new DataFrame(['a' => [1, 2], 'b' => [3, 4], 'c' => [5, 6]]);
+
+        $result = $df->select([
+            Expr::fold(10, fn (Expr $acc, Expr $x) => $acc->add($x), ['a', 'b', 'c'])->alias('fold'),
+            Expr::reduce(fn (Expr $acc, Expr $x) => $acc->mul($x), ['a', 'b', 'c'])->alias('reduce'),
+        ]);
+
+        $this->assertEquals([19, 22], $result->column('fold')->toArray());
+        $this->assertEquals([15, 48], $result->column('reduce')->toArray());
+    }
+
+    public function testLitWithDtype(): void
+    {
+        $df = new DataFrame(['x' => [1, 2]]);
+
+        $result = $df->withColumns([
+            Expr::lit(1, 'int8')->alias('flag'),
+            Expr::lit('2024-03-01', 'date')->alias('day'),
+        ]);
+
+        $this->assertEquals('i8', (string) $result->dtypes[1]);
+        $this->assertEquals('date', (string) $result->dtypes[2]);
+        $this->assertEquals(['2024-03-01', '2024-03-01'], $result->column('day')->toArray());
+    }
+
+    public function testIntRange(): void
+    {
+        $df = new DataFrame(['x' => ['a', 'b', 'c']]);
+
+        $result = $df->withColumns([
+            Expr::intRange()->alias('row_nr'),
+            Expr::intRange(1, Expr::col('x')->len()->add(1))->alias('one_based'),
+        ]);
+
+        $this->assertEquals([0, 1, 2], $result->column('row_nr')->toArray());
+        $this->assertEquals([1, 2, 3], $result->column('one_based')->toArray());
+
+        $evens = (new DataFrame([]))->select([Expr::intRange(0, 10, 3)->alias('n')]);
+        $this->assertEquals([0, 3, 6, 9], $evens->column('n')->toArray());
+    }
+
+    public function testIntRanges(): void
+    {
+        $df = new DataFrame(['start' => [1, 2], 'end' => [3, 3]]);
+
+        $result = $df->select([Expr::intRanges('start', 'end')->alias('r')])->explode(['r']);
+
+        $this->assertEquals([1, 2, 2], $result->column('r')->toArray());
+    }
+
+    public function testDateAndTimeRanges(): void
+    {
+        $calendar = (new DataFrame([]))->select([
+            Expr::dateRange('2024-01-30', '2024-02-02')->alias('day'),
+        ]);
+        $this->assertEquals(
+            ['2024-01-30', '2024-01-31', '2024-02-01', '2024-02-02'],
+            $calendar->column('day')->toArray()
+        );
+
+        $weeks = (new DataFrame([]))->select([
+            Expr::dateRange('2024-01-01', '2024-01-15', '1w', ClosedInterval::Left)->alias('week'),
+        ]);
+        $this->assertEquals(['2024-01-01', '2024-01-08'], $weeks->column('week')->toArray());
+
+        $hours = (new DataFrame([]))->select([
+            Expr::datetimeRange('2024-01-01 00:00:00', '2024-01-02 00:00:00', '6h')->alias('ts'),
+        ]);
+        $this->assertEquals(5, $hours->height());
+
+        $slots = (new DataFrame([]))->select([
+            Expr::timeRange('08:00', '10:00', '1h')->alias('slot'),
+        ]);
+        $this->assertEquals(['08:00:00', '09:00:00', '10:00:00'], $slots->column('slot')->toArray());
+
+        $day = (new DataFrame([]))->select([Expr::timeRange()->alias('hour')]);
+        $this->assertEquals(24, $day->height());
+    }
+
+    public function testRepeatZerosAndOnes(): void
+    {
+        $df = new DataFrame(['x' => [10, 20, 30]]);
+
+        $result = $df->withColumns([
+            Expr::repeat('a')->alias('constant'),
+            Expr::zeros()->alias('zeros'),
+            Expr::ones(dtype: 'int64')->alias('ones'),
+        ]);
+
+        $this->assertEquals(['a', 'a', 'a'], $result->column('constant')->toArray());
+        $this->assertEquals([0.0, 0.0, 0.0], $result->column('zeros')->toArray());
+        $this->assertEquals([1, 1, 1], $result->column('ones')->toArray());
+
+        $repeated = (new DataFrame([]))->select([Expr::repeat(7, 2)]);
+        $this->assertEquals([7, 7], $repeated->column('repeat')->toArray());
+    }
+
+    public function testNamePrefixAndSuffix(): void
+    {
+        $df = new DataFrame(['a' => [1, 2], 'b' => [3, 4]]);
+
+        $sums = $df->select([Expr::all()->sum()->name()->suffix('_sum')]);
+        $this->assertEquals(['a_sum', 'b_sum'], $sums->columns);
+        $this->assertEquals([7], $sums->column('b_sum')->toArray());
+
+        $prefixed = $df->select([Expr::col('a')->name()->prefix('x_')]);
+        $this->assertEquals(['x_a'], $prefixed->columns);
+    }
+
+    public function testNameKeepAndCase(): void
+    {
+        $df = new DataFrame(['Price' => [1.5], 'qty' => [2]]);
+
+        $kept = $df->select([Expr::col('Price')->alias('other')->name()->keep()]);
+        $this->assertEquals(['Price'], $kept->columns);
+
+        $this->assertEquals(['price', 'qty'], $df->select([Expr::all()->name()->toLowercase()])->columns);
+        $this->assertEquals(['PRICE', 'QTY'], $df->select([Expr::all()->name()->toUppercase()])->columns);
+    }
+
+    public function testNameMap(): void
+    {
+        $df = new DataFrame(['first' => [1], 'second' => [2]]);
+
+        $result = $df->select([Expr::all()->name()->map(fn (string $name) => strtoupper($name[0]) . '_col')]);
+        $this->assertEquals(['F_col', 'S_col'], $result->columns);
+    }
+
+    public function testNameMapMustReturnString(): void
+    {
+        $df = new DataFrame(['a' => [1]]);
+
+        $this->expectException(\Polars\Exception::class);
+        $df->select([Expr::col('a')->name()->map(fn (string $name) => 42)]);
+    }
+
+    public function testNamePrefixAndSuffixFields(): void
+    {
+        $df = new DataFrame(['x' => [1, 1, 2]]);
+
+        $prefixed = $df->select([Expr::col('x')->valueCounts(sort: true)->name()->prefixFields('p_')])->unnest(['x']);
+        $this->assertEquals(['p_x', 'p_count'], $prefixed->columns);
+
+        $suffixed = $df->select([Expr::col('x')->valueCounts(sort: true)->name()->suffixFields('_s')])->unnest(['x']);
+        $this->assertEquals(['x_s', 'count_s'], $suffixed->columns);
+    }
+
+    public function testCut(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4, 5]]);
+
+        $right = $df->select([Expr::col('x')->cut([2, 4], ['low', 'mid', 'high'])]);
+        $this->assertEquals(['low', 'low', 'mid', 'mid', 'high'], $right->column('x')->toArray());
+
+        $left = $df->select([Expr::col('x')->cut([2, 4], ['low', 'mid', 'high'], leftClosed: true)]);
+        $this->assertEquals(['low', 'mid', 'mid', 'high', 'high'], $left->column('x')->toArray());
+    }
+
+    public function testCutIncludeBreaks(): void
+    {
+        $df = new DataFrame(['x' => [1, 3]]);
+
+        $result = $df->select([Expr::col('x')->cut([2, 4], ['low', 'mid', 'high'], includeBreaks: true)])
+            ->unnest(['x']);
+        $this->assertEquals(['breakpoint', 'category'], $result->columns);
+        $this->assertEquals([2.0, 4.0], $result->column('breakpoint')->toArray());
+        $this->assertEquals(['low', 'mid'], $result->column('category')->toArray());
+    }
+
+    public function testQcut(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4, 5]]);
+
+        $byCount = $df->select([Expr::col('x')->qcut(2, ['a', 'b'])]);
+        $this->assertEquals(['a', 'a', 'a', 'b', 'b'], $byCount->column('x')->toArray());
+
+        $byQuantiles = $df->select([Expr::col('x')->qcut([0.5], ['a', 'b'])]);
+        $this->assertEquals(['a', 'a', 'a', 'b', 'b'], $byQuantiles->column('x')->toArray());
+    }
+
+    public function testQcutDuplicateBreaks(): void
+    {
+        $df = new DataFrame(['x' => [1, 1, 1, 1, 2]]);
+
+        $result = $df->select([Expr::col('x')->qcut([0.25, 0.5], allowDuplicates: true)]);
+        $this->assertEquals(5, $result->height());
+
+        $this->expectException(\Polars\Exception::class);
+        $df->select([Expr::col('x')->qcut([0.25, 0.5])]);
+    }
+
+    public function testCorrAndCov(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4], 'y' => [2, 4, 6, 8], 'z' => [1, 3, 2, 4]]);
+
+        $result = $df->select([
+            Expr::corr('x', 'y')->alias('pearson'),
+            Expr::corr('x', 'z', method: 'spearman')->alias('spearman'),
+            Expr::cov('x', 'y')->alias('cov'),
+            Expr::cov('x', 'y', ddof: 0)->alias('cov_population'),
+        ]);
+
+        $this->assertEqualsWithDelta(1.0, $result->column('pearson')[0], 1e-12);
+        $this->assertEqualsWithDelta(0.8, $result->column('spearman')[0], 1e-12);
+        $this->assertEqualsWithDelta(10 / 3, $result->column('cov')[0], 1e-12);
+        $this->assertEqualsWithDelta(2.5, $result->column('cov_population')[0], 1e-12);
+    }
+
+    public function testCorrPerGroup(): void
+    {
+        $df = new DataFrame(['g' => ['a', 'a', 'a', 'b', 'b', 'b'], 'x' => [1, 2, 3, 1, 2, 3], 'y' => [1, 2, 3, 3, 2, 1]]);
+
+        $result = $df->groupBy([Expr::col('g')])->agg([Expr::corr('x', 'y')->alias('r')])->sort('g');
+        $this->assertEqualsWithDelta([1.0, -1.0], $result->column('r')->toArray(), 1e-12);
+    }
+
+    public function testEntropy(): void
+    {
+        $df = new DataFrame(['p' => [1, 1]]);
+
+        $this->assertEqualsWithDelta(1.0, $df->select([Expr::col('p')->entropy(2)])->column('p')[0], 1e-12);
+        $this->assertEqualsWithDelta(log(2), $df->select([Expr::col('p')->entropy()])->column('p')[0], 1e-12);
+    }
+
+    public function testApproxQuantile(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4, 5]]);
+
+        $this->assertEquals(3, $df->select([Expr::col('x')->approxQuantile(0.5)])->column('x')[0]);
+        $this->assertEquals(5, $df->select([Expr::col('x')->approxQuantile(1.0)])->column('x')[0]);
+
+        $data = new DataFrame(['x' => [7, 1, 9, 4, 12, 3]]);
+        $nearest = $data->select([Expr::col('x')->quantile(0.3, QuantileMethod::Nearest)])->column('x')[0];
+        $this->assertEquals($nearest, $data->select([Expr::col('x')->approxQuantile(0.3)])->column('x')[0]);
+    }
+
+    public function testFilterInAggregation(): void
+    {
+        $df = new DataFrame([
+            'customer' => ['a', 'a', 'b', 'b', 'b'],
+            'status' => ['paid', 'open', 'paid', 'paid', 'open'],
+            'amount' => [10, 20, 30, 40, 50],
+        ]);
+
+        $result = $df->groupBy([Expr::col('customer')])->agg([
+            Expr::col('amount')->filter(Expr::col('status')->eq('paid'))->sum()->alias('paid'),
+        ])->sort('customer');
+
+        $this->assertEquals([10, 70], $result->column('paid')->toArray());
+    }
+
+    public function testGatherAndGet(): void
+    {
+        $df = new DataFrame(['x' => [5, 1, 4, 2]]);
+
+        $this->assertEquals([5, 2], $df->select([Expr::col('x')->gather([0, -1])])->column('x')->toArray());
+        $this->assertEquals([4], $df->select([Expr::col('x')->gather(new Series('i', [2]))])->column('x')->toArray());
+        $this->assertEquals([1], $df->select([Expr::col('x')->get(1)])->column('x')->toArray());
+    }
+
+    public function testGetPerGroup(): void
+    {
+        $df = new DataFrame(['g' => ['a', 'a', 'b'], 'x' => [1, 2, 3]]);
+
+        $result = $df->groupBy([Expr::col('g')])->agg([Expr::col('x')->get(0)])->sort('g');
+        $this->assertEquals([1, 3], $result->column('x')->toArray());
+    }
+
+    public function testHeadTailLimitAndSlice(): void
+    {
+        $df = new DataFrame(['x' => [1, 2, 3, 4, 5]]);
+
+        $this->assertEquals([1, 2], $df->select([Expr::col('x')->head(2)])->column('x')->toArray());
+        $this->assertEquals([4, 5], $df->select([Expr::col('x')->tail(2)])->column('x')->toArray());
+        $this->assertEquals([1, 2, 3], $df->select([Expr::col('x')->limit(3)])->column('x')->toArray());
+        $this->assertEquals([2, 3], $df->select([Expr::col('x')->slice(1, 2)])->column('x')->toArray());
+        $this->assertEquals([4, 5], $df->select([Expr::col('x')->slice(-2)])->column('x')->toArray());
+        $this->assertEquals([3, 4, 5], $df->select([Expr::col('x')->slice(2)])->column('x')->toArray());
+    }
+
+    public function testArgTrueAndArgWhere(): void
+    {
+        $df = new DataFrame(['x' => [5, 1, 4, 2]]);
+
+        $this->assertEquals([0, 2], $df->select([Expr::col('x')->gt(3)->argTrue()])->column('x')->toArray());
+        $this->assertEquals([1, 3], $df->select([Expr::argWhere(Expr::col('x')->lt(3))])->column('x')->toArray());
+    }
+
+    public function testExplodeAndFlatten(): void
+    {
+        $df = new DataFrame(['g' => ['a', 'a', 'b'], 'x' => [1, 2, 3]]);
+
+        $lists = $df->groupBy([Expr::col('g')])->agg([Expr::col('x')])->sort('g');
+        $this->assertEquals([1, 2, 3], $lists->select([Expr::col('x')->explode()])->column('x')->toArray());
+        $this->assertEquals([1, 2, 3], $lists->select([Expr::col('x')->flatten()])->column('x')->toArray());
+    }
+
+    public function testAppendExpr(): void
+    {
+        $df = new DataFrame(['x' => [1, 2], 'y' => [2.5, 3.5]]);
+
+        $result = $df->select([Expr::col('x')->appendExpr(Expr::col('y'))]);
+        $this->assertEquals([1.0, 2.0, 2.5, 3.5], $result->column('x')->toArray());
+    }
 }
@@ -86,7 +86,7 @@ class DataFrameMethodsTest extends TestCase
             'group' => ['a', 'a', 'b', 'b'],
             'value' => [1, 2, 3, 4],
         ]);
-        $result = $df->groupBy([Expr::col('group')])->sum()->collect();
+        $result = $df->groupBy([Expr::col('group')])->sum();
         $this->assertInstanceOf(DataFrame::class, $result);
         $this->assertEquals(2, $result->height());
     }
@@ -136,6 +136,61 @@ class DataFrameMethodsTest extends TestCase
         $this->assertEquals(24, $result->item());
     }
 
+    public function testSumAndMeanHorizontal(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, null], 'b' => [3, 4, 5]]);
+
+        $sum = $df->sumHorizontal();
+        $this->assertInstanceOf(Series::class, $sum);
+        $this->assertEquals('sum', $sum->name);
+        $this->assertEquals([4, 6, 5], $sum->toArray());
+        $this->assertEquals([4, 6, null], $df->sumHorizontal(ignoreNulls: false)->toArray());
+
+        $this->assertEquals([2.0, 3.0, 5.0], $df->meanHorizontal()->toArray());
+        $this->assertEquals([2.0, 3.0, null], $df->meanHorizontal(false)->toArray());
+    }
+
+    public function testMinAndMaxHorizontal(): void
+    {
+        $df = new DataFrame(['a' => [1, 8, 3], 'b' => [4, 2, 6]]);
+
+        $this->assertEquals([1, 2, 3], $df->minHorizontal()->toArray());
+        $this->assertEquals([4, 8, 6], $df->maxHorizontal()->toArray());
+        $this->assertEquals('max', $df->maxHorizontal()->name);
+    }
+
+    public function testHorizontalOnEmptyDataFrame(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new DataFrame([]))->sumHorizontal();
+    }
+
+    public function testCorr(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, 3], 'b' => [3, 2, 1], 'c' => [2, 4, 6]]);
+
+        $corr = $df->corr();
+        $this->assertEquals(['a', 'b', 'c'], $corr->columns);
+        $this->assertEquals(3, $corr->height());
+        $this->assertEqualsWithDelta([1.0, -1.0, 1.0], $corr->column('a')->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([-1.0, 1.0, -1.0], $corr->column('b')->toArray(), 1e-12);
+
+        $spearman = $df->corr(method: 'spearman');
+        $this->assertEqualsWithDelta([1.0, -1.0, 1.0], $spearman->column('c')->toArray(), 1e-12);
+    }
+
+    public function testCorrNonNumericThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new DataFrame(['a' => [1, 2], 'b' => ['x', 'y']]))->corr();
+    }
+
+    public function testCorrInvalidMethodThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new DataFrame(['a' => [1, 2]]))->corr('kendall');
+    }
+
     public function testUnique(): void
     {
         $df = new DataFrame([
@@ -523,6 +578,42 @@ class DataFrameMethodsTest extends TestCase
         $this->assertEquals(3, $result->height());
     }
 
+    public function testJoinSemiAndAnti(): void
+    {
+        $df1 = new DataFrame(['id' => [1, 2, 3], 'val' => ['a', 'b', 'c']]);
+        $df2 = new DataFrame(['id' => [2, 3, 4]]);
+        $this->assertEquals([2, 3], $df1->join($df2, [Expr::col('id')], 'semi')->sort('id')->column('id')->toArray());
+        $this->assertEquals([1], $df1->join($df2, [Expr::col('id')], 'anti')->column('id')->toArray());
+    }
+
+    public function testJoinNullsEqualAndMaintainOrder(): void
+    {
+        $df1 = new DataFrame(['id' => [null, 2, 1], 'val' => ['a', 'b', 'c']]);
+        $df2 = new DataFrame(['id' => [1, 2, null], 'data' => ['x', 'y', 'z']]);
+        $result = $df1->join($df2, [Expr::col('id')], nullsEqual: true, maintainOrder: 'left');
+        $this->assertEquals(['a', 'b', 'c'], $result->column('val')->toArray());
+        $this->assertEquals(['z', 'y', 'x'], $result->column('data')->toArray());
+    }
+
+    public function testGroupByDynamic(): void
+    {
+        $df = new DataFrame(['t' => [0, 1, 2, 3, 4], 'v' => [1, 2, 3, 4, 5]]);
+        $result = $df->groupByDynamic('t', '2i', offset: '1i')
+            ->agg([Expr::col('v')->sum()])
+            ->collect();
+        $this->assertEquals([-1, 1, 3], $result->column('t')->toArray());
+        $this->assertEquals([1, 5, 9], $result->column('v')->toArray());
+    }
+
+    public function testRolling(): void
+    {
+        $df = new DataFrame(['t' => [1, 2, 4, 8], 'v' => [1, 1, 1, 1]]);
+        $result = $df->rolling('t', '3i')
+            ->agg([Expr::col('v')->sum()->alias('n')])
+            ->collect();
+        $this->assertEquals([1, 2, 2, 1], $result->column('n')->toArray());
+    }
+
     public function testSampleWithFraction(): void
     {
         $df = $this->createDf();
@@ -550,6 +641,35 @@ class DataFrameMethodsTest extends TestCase
         $this->assertEquals(25, $result->row(0)['age']);
     }
 
+    public function testSortPerKeyDescendingAndExpr(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, 1, 2], 'b' => [1, 2, 3, 4]]);
+        $result = $df->sort([Expr::col('a'), 'b'], descending: [true, false]);
+        $this->assertEquals([2, 2, 1, 1], $result->column('a')->toArray());
+        $this->assertEquals([2, 4, 1, 3], $result->column('b')->toArray());
+    }
+
+    public function testTopKMultipleKeysWithReverse(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, 2], 'b' => [5, 6, 7]]);
+        $result = $df->topK(2, ['a', 'b'], reverse: [false, true]);
+        $this->assertEquals([6, 7], $result->column('b')->toArray());
+    }
+
+    public function testSetSortedMultipleColumns(): void
+    {
+        $df = new DataFrame(['a' => [1, 2], 'b' => [3, 4]]);
+        $df->setSorted(['a', 'b']);
+        $this->assertEquals(2, $df->height());
+    }
+
+    public function testSetSortedMissingColumnThrows(): void
+    {
+        $df = new DataFrame(['a' => [1, 2]]);
+        $this->expectException(\Polars\Exception::class);
+        $df->setSorted('missing');
+    }
+
     public function testTransposeWithColumnNames(): void
     {
         $df = new DataFrame([
@@ -841,6 +961,21 @@ class DataFrameMethodsTest extends TestCase
         $this->assertEquals(3, $result->height());
     }
 
+    public function testJoinAsofWithLeftByRightBy(): void
+    {
+        $df1 = new DataFrame(['time' => [1, 2], 'key' => ['a', 'b']]);
+        $df2 = new DataFrame(['time' => [0, 0], 'k' => ['b', 'a'], 'data' => ['x', 'y']]);
+        $result = $df1->joinAsof($df2, 'time', leftBy: 'key', rightBy: ['k']);
+        $this->assertEquals(['y', 'x'], $result->column('data')->toArray());
+    }
+
+    public function testJoinAsofInvalidToleranceThrows(): void
+    {
+        $df = new DataFrame(['time' => [1]]);
+        $this->expectException(\Polars\Exception::class);
+        $df->joinAsof($df, 'time', tolerance: [1]);
+    }
+
     public function testSql(): void
     {
         $df = $this->createDf();
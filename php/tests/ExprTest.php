@@ -6,7 +6,9 @@ use PHPUnit\Framework\TestCase;
 use Polars\ClosedInterval;
 use Polars\DataFrame;
 use Polars\Expr;
+use Polars\ExprNameNameSpace;
 use Polars\QuantileMethod;
+use Polars\Series;
 
 class ExprTest extends TestCase
 {
@@ -221,6 +223,56 @@ class ExprTest extends TestCase
         $this->assertInstanceOf(Expr::class, $expr->bitwiseXor());
     }
 
+    public function testBitwiseElementWise(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseAnd(Expr::col('mask')));
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseOr(1));
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseXor(Expr::col('mask')));
+    }
+
+    public function testBitwiseCounts(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseCountOnes());
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseCountZeros());
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseLeadingOnes());
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseLeadingZeros());
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseTrailingOnes());
+        $this->assertInstanceOf(Expr::class, $expr->bitwiseTrailingZeros());
+    }
+
+    public function testShiftLeftAndRight(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->shiftLeft(2));
+        $this->assertInstanceOf(Expr::class, $expr->shiftRight(2));
+    }
+
+    public function testShiftOutOfRangeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->shiftLeft(64);
+    }
+
+    public function testHash(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->hash());
+        $this->assertInstanceOf(Expr::class, $expr->hash(42, 1, 2, 3));
+    }
+
+    public function testHashNegativeSeedThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->hash(-1);
+    }
+
+    public function testReinterpret(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->reinterpret(false));
+    }
+
     public function testImplode(): void
     {
         $expr = Expr::col('abc');
@@ -594,6 +646,823 @@ class ExprTest extends TestCase
         $this->assertEquals([true, true, true], $result->column('result')->toArray());
     }
 
+    // Literal and range functions
+
+    public function testLit(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::lit(1));
+        $this->assertInstanceOf(Expr::class, Expr::lit(1, 'int8'));
+        $this->assertInstanceOf(Expr::class, Expr::lit('2024-01-01', 'date'));
+        $this->assertInstanceOf(Expr::class, Expr::lit(Expr::col('a'), 'float32'));
+    }
+
+    public function testLitInvalidDtype(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::lit(1, 'decimal');
+    }
+
+    public function testRanges(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::intRange());
+        $this->assertInstanceOf(Expr::class, Expr::intRange(0, 10, 2, 'int32'));
+        $this->assertInstanceOf(Expr::class, Expr::intRanges('a', 'b'));
+        $this->assertInstanceOf(Expr::class, Expr::dateRange('2024-01-01', '2024-02-01', '1w'));
+        $this->assertInstanceOf(Expr::class, Expr::datetimeRange('2024-01-01 00:00:00', '2024-01-02 00:00:00', '6h', timeUnit: 'ms'));
+        $this->assertInstanceOf(Expr::class, Expr::timeRange('08:00', '17:30:00', '30m', ClosedInterval::Left));
+    }
+
+    public function testDateRangeInvalidInterval(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::dateRange('2024-01-01', '2024-02-01', 'weekly');
+    }
+
+    public function testDatetimeRangeInvalidTimeUnit(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::datetimeRange('2024-01-01 00:00:00', '2024-01-02 00:00:00', timeUnit: 's');
+    }
+
+    public function testTimeRangeInvalidTime(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::timeRange('25:00');
+    }
+
+    public function testRepeatZerosAndOnes(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::repeat('x', 3));
+        $this->assertInstanceOf(Expr::class, Expr::repeat(1));
+        $this->assertInstanceOf(Expr::class, Expr::zeros(3));
+        $this->assertInstanceOf(Expr::class, Expr::ones(dtype: 'int64'));
+    }
+
+    // Horizontal functions
+
+    public function testHorizontalFunctions(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::sumHorizontal(['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::meanHorizontal([Expr::col('a'), 'b'], ignoreNulls: false));
+        $this->assertInstanceOf(Expr::class, Expr::minHorizontal(['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::maxHorizontal('a'));
+        $this->assertInstanceOf(Expr::class, Expr::anyHorizontal(['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::allHorizontal(['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::coalesce(['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::concatStr(['a', 'b'], '-', true));
+        $this->assertInstanceOf(Expr::class, Expr::concatList(['a', 'b']));
+    }
+
+    public function testHorizontalFunctionWithoutColumns(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::sumHorizontal([]);
+    }
+
+    public function testFoldAndReduce(): void
+    {
+        $add = fn (Expr $acc, Expr $x) => $acc->add($x);
+        $this->assertInstanceOf(Expr::class, Expr::fold(0, $add, ['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::fold(Expr::col('c'), $add, ['a', 'b']));
+        $this->assertInstanceOf(Expr::class, Expr::reduce($add, ['a', 'b']));
+    }
+
+    public function testFoldFunctionMustReturnExpr(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::fold(0, fn (Expr $acc, Expr $x) => 1, ['a']);
+    }
+
+    public function testReduceWithoutColumns(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::reduce(fn (Expr $acc, Expr $x) => $acc, []);
+    }
+
+    // Correlation functions
+
+    public function testCorrAndCovReturnExpr(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::corr('a', 'b'));
+        $this->assertInstanceOf(Expr::class, Expr::corr(Expr::col('a'), 'b', method: 'spearman', propagateNans: true));
+        $this->assertInstanceOf(Expr::class, Expr::cov('a', Expr::col('b'), ddof: 0));
+    }
+
+    public function testCorrInvalidMethod(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::corr('a', 'b', method: 'kendall');
+    }
+
+    public function testEntropyAndApproxQuantileReturnExpr(): void
+    {
+        $expr = Expr::col('a');
+        $this->assertInstanceOf(Expr::class, $expr->entropy());
+        $this->assertInstanceOf(Expr::class, $expr->entropy(2, false));
+        $this->assertInstanceOf(Expr::class, $expr->approxQuantile(0.9));
+    }
+
+    public function testApproxQuantileOutOfRange(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->approxQuantile(1.5);
+    }
+
+    // Math methods
+
+    public function testAbs(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->abs());
+    }
+
+    public function testSqrt(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->sqrt());
+    }
+
+    public function testCbrt(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cbrt());
+    }
+
+    public function testExp(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->exp());
+    }
+
+    public function testLog1p(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->log1p());
+    }
+
+    public function testLog10(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->log10());
+    }
+
+    public function testSin(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->sin());
+    }
+
+    public function testCos(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cos());
+    }
+
+    public function testTan(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->tan());
+    }
+
+    public function testCot(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cot());
+    }
+
+    public function testArcsin(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->arcsin());
+    }
+
+    public function testArccos(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->arccos());
+    }
+
+    public function testArctan(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->arctan());
+    }
+
+    public function testSinh(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->sinh());
+    }
+
+    public function testCosh(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cosh());
+    }
+
+    public function testTanh(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->tanh());
+    }
+
+    public function testArcsinh(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->arcsinh());
+    }
+
+    public function testArccosh(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->arccosh());
+    }
+
+    public function testArctanh(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->arctanh());
+    }
+
+    public function testDegrees(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->degrees());
+    }
+
+    public function testRadians(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->radians());
+    }
+
+    public function testFloor(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->floor());
+    }
+
+    public function testCeil(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->ceil());
+    }
+
+    public function testSign(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->sign());
+    }
+
+    public function testLog(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->log());
+        $this->assertInstanceOf(Expr::class, $expr->log(2.0));
+    }
+
+    public function testRound(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->round());
+        $this->assertInstanceOf(Expr::class, $expr->round(2, 'half_away_from_zero'));
+    }
+
+    public function testRoundInvalidMode(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->round(2, 'up');
+    }
+
+    public function testRoundSigFigs(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->roundSigFigs(2));
+    }
+
+    public function testClip(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->clip(0, 10));
+        $this->assertInstanceOf(Expr::class, $expr->clip(lowerBound: 0));
+        $this->assertInstanceOf(Expr::class, $expr->clip(upperBound: Expr::col('def')));
+    }
+
+    public function testDot(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->dot(Expr::col('def')));
+    }
+
+    // Cumulative methods
+
+    public function testCumSum(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cumSum());
+        $this->assertInstanceOf(Expr::class, $expr->cumSum(reverse: true));
+    }
+
+    public function testCumProd(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cumProd());
+    }
+
+    public function testCumMin(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cumMin());
+    }
+
+    public function testCumMax(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cumMax());
+    }
+
+    public function testCumCount(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cumCount());
+    }
+
+    public function testDiff(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->diff());
+        $this->assertInstanceOf(Expr::class, $expr->diff(2, 'drop'));
+    }
+
+    public function testDiffInvalidNullBehavior(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->diff(1, 'fill');
+    }
+
+    public function testPctChange(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->pctChange());
+    }
+
+    public function testCumulativeEval(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->cumulativeEval(Expr::element()->sum()));
+    }
+
+    public function testCumulativeEvalNegativeMinSamplesThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->cumulativeEval(Expr::element()->sum(), -1);
+    }
+
+    // Rolling methods
+
+    public function testRollingFixedWindow(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->rollingSum(3));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMean(3, 1, true));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMin(3));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMax(3));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMedian(3));
+        $this->assertInstanceOf(Expr::class, $expr->rollingStd(3, ddof: 0));
+        $this->assertInstanceOf(Expr::class, $expr->rollingVar(3));
+        $this->assertInstanceOf(Expr::class, $expr->rollingSum(2, weights: [0.5, 1.0]));
+    }
+
+    public function testRollingNegativeWindowSizeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->rollingSum(-1);
+    }
+
+    public function testRollingNegativeMinPeriodsThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->rollingMean(3, minPeriods: -1);
+    }
+
+    public function testRollingByNegativeMinPeriodsThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->rollingSumBy('date', '7d', minPeriods: -1);
+    }
+
+    public function testRollingQuantile(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->rollingQuantile(0.5, 3));
+        $this->assertInstanceOf(Expr::class, $expr->rollingQuantile(0.25, 3, QuantileMethod::Linear));
+    }
+
+    public function testRollingByWindow(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->rollingSumBy('date', '7d'));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMeanBy(Expr::col('date'), '1w', 2, ClosedInterval::Both));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMinBy('date', '2h'));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMaxBy('date', '1mo'));
+        $this->assertInstanceOf(Expr::class, $expr->rollingMedianBy('date', '3d'));
+        $this->assertInstanceOf(Expr::class, $expr->rollingStdBy('date', '3d'));
+        $this->assertInstanceOf(Expr::class, $expr->rollingVarBy('date', '3d', ddof: 0));
+        $this->assertInstanceOf(Expr::class, $expr->rollingQuantileBy('date', '3d', 0.9));
+    }
+
+    public function testRollingByInvalidWindowSize(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->rollingMeanBy('date', 'seven days');
+    }
+
+    // Exponentially weighted methods
+
+    public function testEwmMean(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->ewmMean(com: 1.0));
+        $this->assertInstanceOf(Expr::class, $expr->ewmMean(span: 3, adjust: false));
+        $this->assertInstanceOf(Expr::class, $expr->ewmMean(halfLife: 2.0, minPeriods: 2));
+        $this->assertInstanceOf(Expr::class, $expr->ewmMean(alpha: 0.3, ignoreNulls: true));
+    }
+
+    public function testEwmStdAndVar(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->ewmStd(alpha: 0.5));
+        $this->assertInstanceOf(Expr::class, $expr->ewmVar(span: 5, bias: true));
+    }
+
+    public function testEwmMeanBy(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->ewmMeanBy('date', '1d'));
+        $this->assertInstanceOf(Expr::class, $expr->ewmMeanBy(Expr::col('date'), '12h'));
+    }
+
+    public function testEwmWithoutDecayParameter(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->ewmMean();
+    }
+
+    public function testEwmWithMultipleDecayParameters(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->ewmMean(com: 1.0, alpha: 0.5);
+    }
+
+    public function testEwmWithNegativeMinPeriods(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->ewmStd(alpha: 0.5, minPeriods: -1);
+    }
+
+    public function testEwmWithInvalidAlpha(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->ewmMean(alpha: 1.5);
+    }
+
+    // Ordering methods
+
+    public function testRank(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->rank());
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->rank('dense', descending: true));
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->rank('random', seed: 42));
+    }
+
+    public function testRankInvalidMethod(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->rank('first');
+    }
+
+    public function testSort(): void
+    {
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->sort());
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->sort(descending: true, nullsLast: false));
+        $this->assertInstanceOf(Expr::class, Expr::col('abc')->argSort(true));
+    }
+
+    public function testSortBy(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->sortBy('other'));
+        $this->assertInstanceOf(Expr::class, $expr->sortBy(['a', Expr::col('b')], [true, false]));
+        $this->assertInstanceOf(Expr::class, $expr->sortBy(['a', 'b'], descending: true, maintainOrder: true));
+    }
+
+    public function testSortByDescendingLengthMismatch(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->sortBy(['a', 'b'], [true]);
+    }
+
+    public function testTopKAndBottomK(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->topK());
+        $this->assertInstanceOf(Expr::class, $expr->bottomK(3));
+        $this->assertInstanceOf(Expr::class, $expr->topKBy('other', 3));
+        $this->assertInstanceOf(Expr::class, $expr->bottomKBy(['a', 'b'], 3, [false, true]));
+    }
+
+    public function testTopKByInvalidReverse(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->topKBy('other', 3, ['yes']);
+    }
+
+    public function testReverseShuffleAndSample(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->reverse());
+        $this->assertInstanceOf(Expr::class, $expr->shuffle(seed: 1));
+        $this->assertInstanceOf(Expr::class, $expr->sample(n: 2));
+        $this->assertInstanceOf(Expr::class, $expr->sample(fraction: 0.5, withReplacement: true, seed: 1));
+    }
+
+    public function testSampleRequiresExactlyOneSize(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->sample(n: 2, fraction: 0.5);
+    }
+
+    public function testNegativeSeedThrows(): void
+    {
+        foreach ([
+            fn () => Expr::col('abc')->rank('random', seed: -1),
+            fn () => Expr::col('abc')->shuffle(seed: -1),
+            fn () => Expr::col('abc')->sample(n: 2, seed: -1),
+        ] as $call) {
+            try {
+                $call();
+                $this->fail('Expected Polars\Exception for a negative seed');
+            } catch (\Polars\Exception $e) {
+                $this->assertStringContainsString('Invalid seed', $e->getMessage());
+            }
+        }
+    }
+
+    // Set methods
+
+    public function testIsIn(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->isIn([1, 2, 3]));
+        $this->assertInstanceOf(Expr::class, $expr->isIn(new Series('values', ['a', 'b'])));
+        $this->assertInstanceOf(Expr::class, $expr->isIn(Expr::col('other')->implode(), nullsEqual: true));
+    }
+
+    public function testIsInInvalidArgument(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->isIn('a');
+    }
+
+    public function testReplace(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->replace([1 => 10, 2 => 20]));
+        $this->assertInstanceOf(Expr::class, $expr->replace(['a' => 'x'], default: 'other'));
+        $this->assertInstanceOf(Expr::class, $expr->replaceStrict(['a' => 1.5], default: 0.0, returnDtype: 'float64'));
+    }
+
+    public function testReplaceStrictInvalidDtype(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->replaceStrict(['a' => 1], returnDtype: 'nope');
+    }
+
+    public function testUniquenessMethods(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->isDuplicated());
+        $this->assertInstanceOf(Expr::class, $expr->isUnique());
+        $this->assertInstanceOf(Expr::class, $expr->isFirstDistinct());
+        $this->assertInstanceOf(Expr::class, $expr->isLastDistinct());
+        $this->assertInstanceOf(Expr::class, $expr->unique());
+        $this->assertInstanceOf(Expr::class, $expr->unique(maintainOrder: true));
+        $this->assertInstanceOf(Expr::class, $expr->argUnique());
+        $this->assertInstanceOf(Expr::class, $expr->valueCounts(sort: true, normalize: true));
+    }
+
+    // Missing data methods
+
+    public function testNullAndNanMasks(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->isNull());
+        $this->assertInstanceOf(Expr::class, $expr->isNotNull());
+        $this->assertInstanceOf(Expr::class, $expr->isNan());
+        $this->assertInstanceOf(Expr::class, $expr->isNotNan());
+        $this->assertInstanceOf(Expr::class, $expr->isFinite());
+        $this->assertInstanceOf(Expr::class, $expr->isInfinite());
+    }
+
+    public function testFillNull(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->fillNull(0));
+        $this->assertInstanceOf(Expr::class, $expr->fillNull(Expr::col('other')));
+        $this->assertInstanceOf(Expr::class, $expr->fillNull(strategy: 'forward', limit: 1));
+        $this->assertInstanceOf(Expr::class, $expr->fillNull(strategy: 'mean'));
+        $this->assertInstanceOf(Expr::class, $expr->fillNan(0.0));
+        $this->assertInstanceOf(Expr::class, $expr->forwardFill());
+        $this->assertInstanceOf(Expr::class, $expr->backwardFill(2));
+    }
+
+    public function testFillNegativeLimitThrows(): void
+    {
+        foreach ([
+            fn () => Expr::col('abc')->forwardFill(-1),
+            fn () => Expr::col('abc')->backwardFill(-1),
+            fn () => Expr::col('abc')->fillNull(strategy: 'forward', limit: -1),
+        ] as $call) {
+            try {
+                $call();
+                $this->fail('Expected Polars\Exception for a negative limit');
+            } catch (\Polars\Exception $e) {
+                $this->assertStringContainsString('Invalid limit', $e->getMessage());
+            }
+        }
+    }
+
+    public function testFillNullRequiresValueOrStrategy(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->fillNull();
+    }
+
+    public function testFillNullInvalidStrategy(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->fillNull(strategy: 'median');
+    }
+
+    public function testFillNullLimitRequiresDirectionalStrategy(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->fillNull(strategy: 'zero', limit: 1);
+    }
+
+    public function testDropAndInterpolate(): void
+    {
+        $expr = Expr::col('abc');
+        $this->assertInstanceOf(Expr::class, $expr->dropNulls());
+        $this->assertInstanceOf(Expr::class, $expr->dropNans());
+        $this->assertInstanceOf(Expr::class, $expr->interpolate());
+        $this->assertInstanceOf(Expr::class, $expr->interpolate('nearest'));
+    }
+
+    public function testInterpolateInvalidMethod(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->interpolate('cubic');
+    }
+
+    // Name methods
+
+    public function testNameReturnsNameSpace(): void
+    {
+        $this->assertInstanceOf(ExprNameNameSpace::class, Expr::col('a')->name());
+    }
+
+    public function testNameMethodsReturnExpr(): void
+    {
+        $name = Expr::col('a')->name();
+        $this->assertInstanceOf(Expr::class, $name->keep());
+        $this->assertInstanceOf(Expr::class, $name->prefix('p_'));
+        $this->assertInstanceOf(Expr::class, $name->suffix('_s'));
+        $this->assertInstanceOf(Expr::class, $name->toLowercase());
+        $this->assertInstanceOf(Expr::class, $name->toUppercase());
+        $this->assertInstanceOf(Expr::class, $name->map(fn (string $n) => $n));
+        $this->assertInstanceOf(Expr::class, $name->prefixFields('p_'));
+        $this->assertInstanceOf(Expr::class, $name->suffixFields('_s'));
+    }
+
+    public function testNameMapRequiresCallable(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->name()->map('not_a_function');
+    }
+
+    // Discretization methods
+
+    public function testCutReturnsExpr(): void
+    {
+        $expr = Expr::col('a');
+        $this->assertInstanceOf(Expr::class, $expr->cut([1, 2]));
+        $this->assertInstanceOf(Expr::class, $expr->cut([1.5], ['low', 'high'], leftClosed: true, includeBreaks: true));
+    }
+
+    public function testCutLabelCountMismatch(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->cut([1, 2], ['low', 'high']);
+    }
+
+    public function testQcutReturnsExpr(): void
+    {
+        $expr = Expr::col('a');
+        $this->assertInstanceOf(Expr::class, $expr->qcut(4));
+        $this->assertInstanceOf(Expr::class, $expr->qcut([0.25, 0.75], ['a', 'b', 'c'], allowDuplicates: true));
+    }
+
+    public function testQcutInvalidQuantile(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->qcut([0.5, 1.5]);
+    }
+
+    public function testQcutInvalidBinCount(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->qcut(0);
+    }
+
+    public function testQcutLabelCountMismatch(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->qcut(3, ['a', 'b']);
+    }
+
+    // Selection methods
+
+    public function testSelectionMethodsReturnExpr(): void
+    {
+        $expr = Expr::col('a');
+        $this->assertInstanceOf(Expr::class, $expr->filter(Expr::col('b')->gt(1)));
+        $this->assertInstanceOf(Expr::class, $expr->filter('flag'));
+        $this->assertInstanceOf(Expr::class, $expr->gather(1));
+        $this->assertInstanceOf(Expr::class, $expr->gather([0, -1]));
+        $this->assertInstanceOf(Expr::class, $expr->gather(new Series('idx', [0, 1])));
+        $this->assertInstanceOf(Expr::class, $expr->gather(Expr::col('idx')));
+        $this->assertInstanceOf(Expr::class, $expr->get(0));
+        $this->assertInstanceOf(Expr::class, $expr->get(Expr::lit(0)));
+        $this->assertInstanceOf(Expr::class, $expr->head());
+        $this->assertInstanceOf(Expr::class, $expr->tail(3));
+        $this->assertInstanceOf(Expr::class, $expr->limit(2));
+        $this->assertInstanceOf(Expr::class, $expr->slice(-2));
+        $this->assertInstanceOf(Expr::class, $expr->slice(1, 2));
+        $this->assertInstanceOf(Expr::class, $expr->argTrue());
+        $this->assertInstanceOf(Expr::class, Expr::argWhere('flag'));
+        $this->assertInstanceOf(Expr::class, $expr->explode());
+        $this->assertInstanceOf(Expr::class, $expr->flatten());
+        $this->assertInstanceOf(Expr::class, $expr->appendExpr(Expr::col('b'), upcast: false));
+    }
+
+    public function testGatherInvalidIndices(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->gather(['x']);
+    }
+
+    public function testGetInvalidIndex(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->get('x');
+    }
+
+    public function testHeadNegativeLength(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->head(-1);
+    }
+
+    public function testSliceNegativeLength(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('a')->slice(0, -1);
+    }
+
+    // Window methods
+
+    public function testOver(): void
+    {
+        $expr = Expr::col('abc')->sum();
+        $this->assertInstanceOf(Expr::class, $expr->over('group'));
+        $this->assertInstanceOf(Expr::class, $expr->over(['group', Expr::col('other')]));
+        $this->assertInstanceOf(Expr::class, $expr->over('group', orderBy: 'date', descending: true));
+        $this->assertInstanceOf(Expr::class, $expr->over(orderBy: 'date'));
+    }
+
+    public function testOverWithoutPartitionOrOrder(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->sum()->over();
+    }
+
+    public function testOverInvalidMappingStrategy(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Expr::col('abc')->sum()->over('group', mappingStrategy: 'merge');
+    }
+
     // Utility methods
 
     public function testHasNulls(): void
@@ -0,0 +1,215 @@
+<?php
+
+namespace Tests\Polars;
+
+use PHPUnit\Framework\TestCase;
+use Polars\DataFrame;
+use Polars\Expr;
+use Polars\GroupBy;
+use Polars\QuantileMethod;
+
+class GroupByTest extends TestCase
+{
+    private function createDf(): DataFrame
+    {
+        return new DataFrame([
+            'group' => ['b', 'a', 'b', 'c', 'a', 'b'],
+            'value' => [1, 2, 3, 4, 5, 6],
+            'score' => [10, 20, 30, 40, 50, 60],
+        ]);
+    }
+
+    public function testGroupByReturnsGroupBy(): void
+    {
+        $gb = $this->createDf()->groupBy([Expr::col('group')]);
+        $this->assertInstanceOf(GroupBy::class, $gb);
+        $this->assertInstanceOf(\IteratorAggregate::class, $gb);
+    }
+
+    public function testAggReturnsDataFrame(): void
+    {
+        $result = $this->createDf()
+            ->groupBy([Expr::col('group')])
+            ->agg([Expr::col('value')->sum()])
+            ->sort('group');
+        $this->assertInstanceOf(DataFrame::class, $result);
+        $this->assertEquals(['a', 'b', 'c'], $result->column('group')->toArray());
+        $this->assertEquals([7, 10, 4], $result->column('value')->toArray());
+    }
+
+    public function testMaintainOrder(): void
+    {
+        $result = $this->createDf()
+            ->groupBy([Expr::col('group')], maintainOrder: true)
+            ->first();
+        $this->assertEquals(['b', 'a', 'c'], $result->column('group')->toArray());
+        $this->assertEquals([1, 2, 4], $result->column('value')->toArray());
+    }
+
+    public function testIterateGroups(): void
+    {
+        $keys = [];
+        $values = [];
+        foreach ($this->createDf()->groupBy([Expr::col('group')], maintainOrder: true) as $key => $group) {
+            $this->assertInstanceOf(DataFrame::class, $group);
+            $keys[] = $key;
+            $values[] = $group->column('value')->toArray();
+        }
+        $this->assertEquals([['b'], ['a'], ['c']], $keys);
+        $this->assertEquals([[1, 3, 6], [2, 5], [4]], $values);
+    }
+
+    public function testIterateMultipleKeys(): void
+    {
+        $df = new DataFrame([
+            'a' => [1, 1, 2],
+            'b' => ['x', 'y', 'x'],
+            'v' => [10, 20, 30],
+        ]);
+        $keys = [];
+        foreach ($df->groupBy([Expr::col('a'), Expr::col('b')], maintainOrder: true) as $key => $group) {
+            $keys[] = $key;
+            $this->assertEquals(1, $group->height());
+        }
+        $this->assertEquals([[1, 'x'], [1, 'y'], [2, 'x']], $keys);
+    }
+
+    public function testIterateTwice(): void
+    {
+        $gb = $this->createDf()->groupBy([Expr::col('group')]);
+        $this->assertCount(3, iterator_to_array($gb->getIterator(), false));
+        $this->assertCount(3, iterator_to_array($gb->getIterator(), false));
+    }
+
+    public function testLen(): void
+    {
+        $result = $this->createDf()->groupBy([Expr::col('group')])->len()->sort('group');
+        $this->assertEquals(['group', 'len'], $result->columns);
+        $this->assertEquals([2, 3, 1], $result->column('len')->toArray());
+    }
+
+    public function testLenCustomName(): void
+    {
+        $result = $this->createDf()->groupBy([Expr::col('group')])->len('rows');
+        $this->assertEquals(['group', 'rows'], $result->columns);
+    }
+
+    public function testCount(): void
+    {
+        $df = new DataFrame(['group' => ['a', 'a', 'b'], 'value' => [1, null, 3]]);
+        $result = $df->groupBy([Expr::col('group')])->count()->sort('group');
+        $this->assertEquals([1, 1], $result->column('value')->toArray());
+    }
+
+    public function testSumMeanMinMax(): void
+    {
+        $gb = $this->createDf()->groupBy([Expr::col('group')]);
+        $this->assertEquals([7, 10, 4], $gb->sum()->sort('group')->column('value')->toArray());
+        $this->assertEqualsWithDelta([3.5, 10 / 3, 4.0], $gb->mean()->sort('group')->column('value')->toArray(), 1e-12);
+        $this->assertEquals([3.5, 3.0, 4.0], $gb->median()->sort('group')->column('value')->toArray());
+        $this->assertEquals([2, 1, 4], $gb->min()->sort('group')->column('value')->toArray());
+        $this->assertEquals([5, 6, 4], $gb->max()->sort('group')->column('value')->toArray());
+    }
+
+    public function testFirstLastHeadTail(): void
+    {
+        $gb = $this->createDf()->groupBy([Expr::col('group')], maintainOrder: true);
+        $this->assertEquals([1, 2, 4], $gb->first()->column('value')->toArray());
+        $this->assertEquals([6, 5, 4], $gb->last()->column('value')->toArray());
+        $this->assertEquals(5, $gb->head(2)->height());
+        $this->assertEquals(3, $gb->tail(1)->height());
+    }
+
+    public function testHeadNegativeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->groupBy([Expr::col('group')])->head(-1);
+    }
+
+    public function testTailNegativeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->groupBy([Expr::col('group')])->tail(-1);
+    }
+
+    public function testNUnique(): void
+    {
+        $df = new DataFrame(['group' => ['a', 'a', 'a', 'b'], 'value' => [1, 1, 2, 3]]);
+        $result = $df->groupBy([Expr::col('group')])->nUnique()->sort('group');
+        $this->assertEquals([2, 1], $result->column('value')->toArray());
+    }
+
+    public function testQuantile(): void
+    {
+        $df = new DataFrame(['group' => ['a', 'a', 'a', 'b'], 'value' => [1, 2, 3, 4]]);
+        $gb = $df->groupBy([Expr::col('group')]);
+        $this->assertEquals([3.0, 4.0], $gb->quantile(1.0)->sort('group')->column('value')->toArray());
+        $this->assertEquals(
+            [1.5, 4.0],
+            $gb->quantile(0.25, QuantileMethod::Linear)->sort('group')->column('value')->toArray()
+        );
+    }
+
+    public function testQuantileOutOfRangeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->groupBy([Expr::col('group')])->quantile(1.5);
+    }
+
+    public function testAll(): void
+    {
+        $result = $this->createDf()->groupBy([Expr::col('group')])->all()->sort('group');
+        $this->assertEquals(3, $result->height());
+        $exploded = $result->select([Expr::col('value')->explode()]);
+        $this->assertEquals([2, 5, 1, 3, 6, 4], $exploded->column('value')->toArray());
+    }
+
+    public function testHaving(): void
+    {
+        $result = $this->createDf()
+            ->groupBy([Expr::col('group')])
+            ->having(Expr::col('value')->len()->gt(1))
+            ->sum()
+            ->sort('group');
+        $this->assertEquals(['a', 'b'], $result->column('group')->toArray());
+        $this->assertEquals([7, 10], $result->column('value')->toArray());
+    }
+
+    public function testHavingKeepsWholeGroups(): void
+    {
+        $result = $this->createDf()
+            ->groupBy([Expr::col('group')])
+            ->having(Expr::col('value')->max()->gt(5))
+            ->len();
+        $this->assertEquals(['b'], $result->column('group')->toArray());
+        $this->assertEquals([3], $result->column('len')->toArray());
+    }
+
+    public function testHavingRejectsRowPredicate(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->groupBy([Expr::col('group')])->having(Expr::col('value')->gt(1));
+    }
+
+    public function testHavingReturnsGroupBy(): void
+    {
+        $gb = $this->createDf()->groupBy([Expr::col('group')])->having(Expr::col('value')->sum()->gt(5));
+        $this->assertInstanceOf(GroupBy::class, $gb);
+        $this->assertEquals(2, $gb->len()->height());
+    }
+
+    public function testMapGroups(): void
+    {
+        $result = $this->createDf()
+            ->groupBy([Expr::col('group')], maintainOrder: true)
+            ->mapGroups(fn (DataFrame $group) => $group->tail(1));
+        $this->assertEquals(['b', 'a', 'c'], $result->column('group')->toArray());
+        $this->assertEquals([6, 5, 4], $result->column('value')->toArray());
+    }
+
+    public function testMapGroupsMustReturnDataFrame(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->groupBy([Expr::col('group')])->mapGroups(fn (DataFrame $group) => 1);
+    }
+}
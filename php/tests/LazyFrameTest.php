@@ -4,6 +4,7 @@ namespace Tests\Polars;
 
 use Exception;
 use PHPUnit\Framework\TestCase;
+use Polars\ClosedInterval;
 use Polars\DataFrame;
 use Polars\Expr;
 use Polars\LazyFrame;
@@ -135,6 +136,133 @@ class LazyFrameTest extends TestCase
         $this->assertEquals(2, $result->height());
     }
 
+    public function testGroupByDynamicIntegerIndex(): void
+    {
+        $df = new DataFrame(['t' => [0, 1, 2, 3, 4, 5], 'v' => [1, 2, 3, 4, 5, 6]]);
+        $gb = $df->lazy()->groupByDynamic('t', '2i');
+        $this->assertInstanceOf(LazyGroupBy::class, $gb);
+
+        $result = $gb->agg([Expr::col('v')->sum()])->collect();
+        $this->assertEquals([0, 2, 4], $result->column('t')->toArray());
+        $this->assertEquals([3, 7, 11], $result->column('v')->toArray());
+    }
+
+    public function testGroupByDynamicPeriodAndLabel(): void
+    {
+        $df = new DataFrame(['t' => [0, 1, 2, 3, 4, 5], 'v' => [1, 2, 3, 4, 5, 6]]);
+        $result = $df->lazy()
+            ->groupByDynamic('t', '2i', period: '3i', label: 'right')
+            ->agg([Expr::col('v')->sum()])
+            ->collect();
+        $this->assertEquals([3, 5, 7], $result->column('t')->toArray());
+        $this->assertEquals([6, 12, 11], $result->column('v')->toArray());
+    }
+
+    public function testGroupByDynamicIncludeBoundariesAndClosed(): void
+    {
+        $df = new DataFrame(['t' => [0, 1, 2, 3], 'v' => [1, 2, 3, 4]]);
+        $result = $df->lazy()
+            ->groupByDynamic('t', '2i', includeBoundaries: true, closed: ClosedInterval::Both)
+            ->agg([Expr::col('v')->sum()])
+            ->collect();
+        $this->assertContains('_lower_boundary', $result->columns);
+        $this->assertContains('_upper_boundary', $result->columns);
+        $this->assertEquals([6, 7], $result->column('v')->toArray());
+    }
+
+    public function testGroupByDynamicWithGroupBy(): void
+    {
+        $df = new DataFrame([
+            'tenant' => ['a', 'a', 'a', 'b', 'b', 'b'],
+            't' => [0, 1, 2, 0, 1, 2],
+            'v' => [1, 2, 3, 4, 5, 6],
+        ]);
+        $result = $df->lazy()
+            ->groupByDynamic('t', '2i', groupBy: 'tenant')
+            ->agg([Expr::col('v')->sum()])
+            ->sort(['tenant', 't'])
+            ->collect();
+        $this->assertEquals(['a', 'a', 'b', 'b'], $result->column('tenant')->toArray());
+        $this->assertEquals([3, 3, 9, 6], $result->column('v')->toArray());
+    }
+
+    public function testGroupByDynamicDates(): void
+    {
+        $df = (new DataFrame(['x' => [0]]))->select([
+            Expr::dateRange('2024-01-01', '2024-01-10')->alias('day'),
+        ]);
+        $result = $df->lazy()
+            ->groupByDynamic('day', '1w')
+            ->agg([Expr::col('day')->count()->alias('n')])
+            ->collect();
+        $this->assertEquals(['2024-01-01', '2024-01-08'], $result->column('day')->toArray());
+        $this->assertEquals([7, 3], $result->column('n')->toArray());
+    }
+
+    public function testRolling(): void
+    {
+        $df = new DataFrame(['t' => [0, 1, 2, 3], 'v' => [1, 2, 3, 4]]);
+        $gb = $df->lazy()->rolling('t', '2i');
+        $this->assertInstanceOf(LazyGroupBy::class, $gb);
+
+        $result = $gb->agg([Expr::col('v')->sum()])->collect();
+        $this->assertEquals([0, 1, 2, 3], $result->column('t')->toArray());
+        $this->assertEquals([1, 3, 5, 7], $result->column('v')->toArray());
+    }
+
+    public function testRollingClosedAndOffset(): void
+    {
+        $df = new DataFrame(['t' => [0, 1, 2, 3], 'v' => [1, 2, 3, 4]]);
+
+        $both = $df->lazy()->rolling('t', '2i', closed: ClosedInterval::Both)
+            ->agg([Expr::col('v')->sum()])
+            ->collect();
+        $this->assertEquals([1, 3, 6, 9], $both->column('v')->toArray());
+
+        $forward = $df->lazy()->rolling('t', '2i', offset: '0i')
+            ->agg([Expr::col('v')->sum()])
+            ->collect();
+        $this->assertEquals([5, 7, 4, 0], $forward->column('v')->toArray());
+    }
+
+    public function testRollingWithGroupBy(): void
+    {
+        $df = new DataFrame([
+            'customer' => ['a', 'a', 'a', 'b', 'b'],
+            'day' => [1, 5, 40, 2, 3],
+            'amount' => [10, 20, 30, 5, 6],
+        ]);
+        $result = $df->lazy()
+            ->rolling('day', '30i', groupBy: ['customer'])
+            ->agg([
+                Expr::col('amount')->count()->alias('orders'),
+                Expr::col('amount')->sum()->alias('total'),
+            ])
+            ->sort(['customer', 'day'])
+            ->collect();
+        $this->assertEquals([1, 2, 1, 1, 2], $result->column('orders')->toArray());
+        $this->assertEquals([10, 30, 30, 5, 11], $result->column('total')->toArray());
+    }
+
+    public function testRollingInvalidPeriodThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new DataFrame(['t' => [0, 1]]))->lazy()->rolling('t', 'nope');
+    }
+
+    public function testGroupByDynamicInvalidOptionsThrow(): void
+    {
+        $lf = (new DataFrame(['t' => [0, 1]]))->lazy();
+        foreach ([['every' => 'x'], ['label' => 'middle'], ['startBy' => 'someday']] as $args) {
+            try {
+                $lf->groupByDynamic('t', ...array_merge(['every' => '1i'], $args));
+                $this->fail('Expected exception for ' . json_encode($args));
+            } catch (\Polars\Exception) {
+                $this->addToAssertionCount(1);
+            }
+        }
+    }
+
     // Sort
 
     public function testSort(): void
@@ -153,6 +281,72 @@ class LazyFrameTest extends TestCase
         $this->assertEquals(4, $result->height());
     }
 
+    public function testSortMultipleKeysWithPerKeyFlags(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, 1, 2], 'b' => [1, 2, 3, 4]]);
+        $result = $df->lazy()->sort(['a', 'b'], descending: [false, true])->collect();
+        $this->assertEquals([1, 1, 2, 2], $result->column('a')->toArray());
+        $this->assertEquals([3, 1, 4, 2], $result->column('b')->toArray());
+    }
+
+    public function testSortByExpression(): void
+    {
+        $df = new DataFrame(['a' => [-3, 1, -2]]);
+        $result = $df->lazy()->sort(Expr::col('a')->abs())->collect();
+        $this->assertEquals([1, -2, -3], $result->column('a')->toArray());
+    }
+
+    public function testSortNullsLastPerKey(): void
+    {
+        $df = new DataFrame(['a' => [2, null, 1]]);
+        $this->assertEquals([null, 1, 2], $df->lazy()->sort('a', nullsLast: false)->collect()->column('a')->toArray());
+        $this->assertEquals([1, 2, null], $df->lazy()->sort('a')->collect()->column('a')->toArray());
+    }
+
+    public function testSortMaintainOrder(): void
+    {
+        $df = new DataFrame(['a' => [1, 1, 0], 'b' => ['x', 'y', 'z']]);
+        $result = $df->lazy()->sort('a', maintainOrder: true, multithreaded: false)->collect();
+        $this->assertEquals(['z', 'x', 'y'], $result->column('b')->toArray());
+    }
+
+    public function testSortFlagsLengthMismatchThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->sort(['name', 'age'], descending: [true]);
+    }
+
+    public function testTopKAndBottomK(): void
+    {
+        $df = new DataFrame(['a' => [3, 1, 4, 1, 5], 'b' => ['c', 'a', 'd', 'b', 'e']]);
+
+        $top = $df->lazy()->topK(2, 'a')->collect();
+        $this->assertEquals([5, 4], $top->column('a')->toArray());
+
+        $bottom = $df->lazy()->bottomK(2, ['a', 'b'])->collect();
+        $this->assertEquals(['a', 'b'], $bottom->column('b')->toArray());
+
+        $reversed = $df->lazy()->topK(2, 'a', reverse: true)->collect();
+        $this->assertEquals([1, 1], $reversed->column('a')->toArray());
+    }
+
+    public function testTopKNegativeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->topK(-1, 'age');
+    }
+
+    public function testSetSorted(): void
+    {
+        $df = new DataFrame(['a' => [1, 2, 3], 'b' => [3, 2, 1]]);
+        $result = $df->lazy()
+            ->setSorted('a')
+            ->setSorted(['b'], descending: true)
+            ->collect();
+        $this->assertEquals([1, 2, 3], $result->column('a')->toArray());
+        $this->assertEquals(['a', 'b'], $result->columns);
+    }
+
     // Row Operations
 
     public function testHead(): void
@@ -331,6 +525,197 @@ class LazyFrameTest extends TestCase
         $this->assertEquals(3, $result->height());
     }
 
+    public function testJoinLeftOnRightOnWithSuffix(): void
+    {
+        $df1 = new DataFrame(['id' => [1, 2, 3], 'val' => ['a', 'b', 'c']]);
+        $df2 = new DataFrame(['key' => [1, 2, 4], 'val' => ['x', 'y', 'z']]);
+
+        $result = $df1->lazy()
+            ->join($df2->lazy(), [], 'inner', leftOn: [Expr::col('id')], rightOn: [Expr::col('key')], suffix: '_other')
+            ->collect();
+        $this->assertEquals(2, $result->height());
+        $this->assertContains('val_other', $result->columns);
+    }
+
+    public function testJoinSemiAndAnti(): void
+    {
+        $df1 = new DataFrame(['key' => [1, 2, 3], 'val1' => ['a', 'b', 'c']]);
+        $df2 = new DataFrame(['key' => [1, 2, 4], 'val2' => ['x', 'y', 'z']]);
+
+        $semi = $df1->lazy()->join($df2->lazy(), [Expr::col('key')], how: 'semi')->collect();
+        $this->assertEquals(['key', 'val1'], $semi->columns);
+        $this->assertEquals([1, 2], $semi->sort('key')->column('key')->toArray());
+
+        $anti = $df1->lazy()->join($df2->lazy(), [Expr::col('key')], how: 'anti')->collect();
+        $this->assertEquals([3], $anti->column('key')->toArray());
+    }
+
+    public function testJoinNullsEqual(): void
+    {
+        $df1 = new DataFrame(['key' => [1, null], 'val1' => ['a', 'b']]);
+        $df2 = new DataFrame(['key' => [1, null], 'val2' => ['x', 'y']]);
+
+        $default = $df1->lazy()->join($df2->lazy(), [Expr::col('key')])->collect();
+        $this->assertEquals(1, $default->height());
+
+        $result = $df1->lazy()->join($df2->lazy(), [Expr::col('key')], nullsEqual: true)->collect();
+        $this->assertEquals(2, $result->height());
+    }
+
+    public function testJoinMaintainOrder(): void
+    {
+        $df1 = new DataFrame(['key' => [3, 1, 2], 'val1' => ['c', 'a', 'b']]);
+        $df2 = new DataFrame(['key' => [1, 2, 3], 'val2' => ['x', 'y', 'z']]);
+
+        $result = $df1->lazy()
+            ->join($df2->lazy(), [Expr::col('key')], maintainOrder: 'left')
+            ->collect();
+        $this->assertEquals([3, 1, 2], $result->column('key')->toArray());
+    }
+
+    public function testJoinValidateAndCoalesce(): void
+    {
+        $df1 = new DataFrame(['key' => [1, 2], 'val1' => ['a', 'b']]);
+        $df2 = new DataFrame(['key' => [1, 3], 'val2' => ['x', 'y']]);
+
+        $result = $df1->lazy()
+            ->join($df2->lazy(), [Expr::col('key')], how: 'full', validate: '1:1', coalesce: false)
+            ->collect();
+        $this->assertEquals(['key', 'val1', 'key_right', 'val2'], $result->columns);
+    }
+
+    public function testJoinParallelHints(): void
+    {
+        $df1 = new DataFrame(['key' => [1, 2], 'val1' => ['a', 'b']]);
+        $df2 = new DataFrame(['key' => [1, 2], 'val2' => ['x', 'y']]);
+
+        $result = $df1->lazy()
+            ->join($df2->lazy(), [Expr::col('key')], allowParallel: false, forceParallel: false)
+            ->collect();
+        $this->assertEquals(2, $result->height());
+    }
+
+    public function testJoinInvalidArgumentsThrow(): void
+    {
+        $lf = (new DataFrame(['key' => [1]]))->lazy();
+        foreach ([['how' => 'outer'], ['maintainOrder' => 'both'], ['validate' => '2:2']] as $args) {
+            try {
+                $lf->join($lf, [Expr::col('key')], ...$args);
+                $this->fail('Expected exception for ' . json_encode($args));
+            } catch (\Polars\Exception) {
+                $this->addToAssertionCount(1);
+            }
+        }
+    }
+
+    public function testJoinOnlyLeftOnThrows(): void
+    {
+        $lf = (new DataFrame(['key' => [1]]))->lazy();
+        $this->expectException(\Polars\Exception::class);
+        $lf->join($lf, [], leftOn: [Expr::col('key')]);
+    }
+
+    public function testJoinAsof(): void
+    {
+        $left = new DataFrame(['time' => [1, 5, 10], 'val' => ['a', 'b', 'c']]);
+        $right = new DataFrame(['time' => [3, 7, 12], 'data' => ['x', 'y', 'z']]);
+
+        $backward = $left->lazy()->joinAsof($right->lazy(), 'time')->collect();
+        $this->assertEquals([null, 'x', 'y'], $backward->column('data')->toArray());
+
+        $forward = $left->lazy()->joinAsof($right->lazy(), 'time', 'forward')->collect();
+        $this->assertEquals(['x', 'y', 'z'], $forward->column('data')->toArray());
+    }
+
+    public function testJoinAsofByMultipleColumns(): void
+    {
+        $left = new DataFrame([
+            'time' => [1, 2, 3, 4],
+            'g1' => ['a', 'a', 'b', 'b'],
+            'g2' => [1, 2, 1, 1],
+        ]);
+        $right = new DataFrame([
+            'time' => [0, 0, 0],
+            'g1' => ['a', 'a', 'b'],
+            'g2' => [1, 2, 1],
+            'data' => ['a1', 'a2', 'b1'],
+        ]);
+
+        $result = $left->lazy()->joinAsof($right->lazy(), 'time', by: ['g1', 'g2'])->collect();
+        $this->assertEquals(['a1', 'a2', 'b1', 'b1'], $result->column('data')->toArray());
+    }
+
+    public function testJoinAsofLeftOnRightOnWithTolerance(): void
+    {
+        $left = new DataFrame(['t' => [1, 5, 10], 'val' => ['a', 'b', 'c']]);
+        $right = new DataFrame(['u' => [0, 4, 9], 'data' => ['x', 'y', 'z']]);
+
+        $result = $left->lazy()
+            ->joinAsof($right->lazy(), leftOn: 't', rightOn: 'u', tolerance: 1)
+            ->collect();
+        $this->assertEquals(['x', 'y', 'z'], $result->column('data')->toArray());
+
+        $strict = $left->lazy()
+            ->joinAsof($right->lazy(), leftOn: 't', rightOn: 'u', tolerance: 0)
+            ->collect();
+        $this->assertEquals([null, null, null], $strict->column('data')->toArray());
+    }
+
+    public function testJoinAsofWithoutExactMatches(): void
+    {
+        $left = new DataFrame(['time' => [3, 7]]);
+        $right = new DataFrame(['time' => [3, 7], 'data' => ['x', 'y']]);
+
+        $result = $left->lazy()
+            ->joinAsof($right->lazy(), 'time', allowExactMatches: false)
+            ->collect();
+        $this->assertEquals([null, 'x'], $result->column('data')->toArray());
+    }
+
+    public function testJoinAsofUnsortedKeysThrowOnCollect(): void
+    {
+        $left = new DataFrame(['time' => [5, 1]]);
+        $right = new DataFrame(['time' => [3, 7], 'data' => ['x', 'y']]);
+        $lf = $left->lazy()->joinAsof($right->lazy(), 'time');
+
+        $this->expectException(\Polars\Exception::class);
+        $lf->collect();
+    }
+
+    public function testJoinAsofMissingKeyThrows(): void
+    {
+        $lf = (new DataFrame(['time' => [1]]))->lazy();
+        $this->expectException(\Polars\Exception::class);
+        $lf->joinAsof($lf, leftOn: 'time');
+    }
+
+    public function testJoinWhere(): void
+    {
+        $events = new DataFrame(['time' => [1, 5, 9]]);
+        $windows = new DataFrame(['start' => [0, 4], 'end' => [4, 8], 'label' => ['w1', 'w2']]);
+
+        $result = $events->lazy()
+            ->joinWhere($windows->lazy(), [
+                Expr::col('time')->ge(Expr::col('start')),
+                Expr::col('time')->lt(Expr::col('end')),
+            ])
+            ->collect()
+            ->sort('time');
+        $this->assertEquals([1, 5], $result->column('time')->toArray());
+        $this->assertEquals(['w1', 'w2'], $result->column('label')->toArray());
+    }
+
+    public function testCrossJoin(): void
+    {
+        $df1 = new DataFrame(['a' => [1, 2], 'val' => ['x', 'y']]);
+        $df2 = new DataFrame(['b' => [10, 20, 30], 'val' => ['p', 'q', 'r']]);
+
+        $result = $df1->lazy()->crossJoin($df2->lazy(), '_other')->collect();
+        $this->assertEquals(6, $result->height());
+        $this->assertEquals(['a', 'val', 'b', 'val_other'], $result->columns);
+        $this->assertEquals([1, 1, 1, 2, 2, 2], $result->column('a')->toArray());
+    }
+
     // Miscellaneous
 
     public function testReverse(): void
@@ -357,6 +742,70 @@ class LazyFrameTest extends TestCase
         $this->assertNotEmpty($plan);
     }
 
+    public function testCollectWithEngine(): void
+    {
+        $lf = $this->createDf()->lazy()->filter(Expr::col('age')->gt(25));
+        $expected = $lf->collect()->height();
+        $this->assertEquals($expected, $lf->collect(engine: 'in-memory')->height());
+        $this->assertEquals($expected, $lf->collect(engine: 'streaming')->height());
+        $this->assertEquals($expected, $lf->collect(engine: 'auto')->height());
+    }
+
+    public function testCollectInvalidEngineThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->collect(engine: 'gpu');
+    }
+
+    public function testCollectWithOptimizationToggles(): void
+    {
+        $lf = $this->createDf()->lazy()
+            ->filter(Expr::col('age')->gt(25))
+            ->select([Expr::col('name')]);
+        $result = $lf->collect(predicatePushdown: false, projectionPushdown: false, commSubplanElim: false);
+        $this->assertEquals($lf->collect()->toArray(), $result->toArray());
+        $this->assertEquals($lf->collect()->toArray(), $lf->collect(noOptimization: true)->toArray());
+    }
+
+    public function testWithOptimizations(): void
+    {
+        $lf = $this->createDf()->lazy()
+            ->filter(Expr::col('age')->gt(25))
+            ->select([Expr::col('name')]);
+        $unoptimized = $lf->withOptimizations(noOptimization: true);
+        $this->assertInstanceOf(LazyFrame::class, $unoptimized);
+        $this->assertNotEquals($lf->explain(), $unoptimized->explain());
+        $this->assertEquals($lf->collect()->toArray(), $unoptimized->collect()->toArray());
+    }
+
+    public function testExplainFormats(): void
+    {
+        $lf = $this->createDf()->lazy()->filter(Expr::col('age')->gt(25));
+        $this->assertEquals($lf->explain(), $lf->explain(format: 'plain'));
+        $this->assertNotEmpty($lf->explain(format: 'tree'));
+        $this->assertNotEmpty($lf->explain(optimized: false, format: 'tree'));
+        $this->assertStringContainsString('digraph', $lf->explain(format: 'dot'));
+        $this->assertStringContainsString('digraph', $lf->explain(optimized: false, format: 'dot'));
+    }
+
+    public function testExplainInvalidFormatThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->explain(format: 'svg');
+    }
+
+    public function testProfile(): void
+    {
+        $lf = $this->createDf()->lazy()->filter(Expr::col('age')->gt(25));
+        $profile = $lf->profile();
+        $this->assertCount(2, $profile);
+        [$result, $timings] = $profile;
+        $this->assertInstanceOf(DataFrame::class, $result);
+        $this->assertEquals($lf->collect()->toArray(), $result->toArray());
+        $this->assertEquals(['node', 'start', 'end'], $timings->columns);
+        $this->assertGreaterThan(0, $timings->height());
+    }
+
     public function testCache(): void
     {
         $lf = $this->createDf()->lazy()->cache();
@@ -399,4 +848,123 @@ class LazyFrameTest extends TestCase
         $this->assertEquals(2, $result->height());
         $this->assertEquals(2, $result->width());
     }
+
+    public function testShiftAndGatherEvery(): void
+    {
+        $lf = (new DataFrame(['x' => [1, 2, 3, 4, 5]]))->lazy();
+        $this->assertEquals([null, 1, 2, 3, 4], $lf->shift(1)->collect()->column('x')->toArray());
+        $this->assertEquals([2, 3, 4, 5, null], $lf->shift(-1)->collect()->column('x')->toArray());
+        $this->assertEquals([1, 3, 5], $lf->gatherEvery(2)->collect()->column('x')->toArray());
+        $this->assertEquals([2, 4], $lf->gatherEvery(2, 1)->collect()->column('x')->toArray());
+    }
+
+    public function testGatherEveryInvalidArgumentsThrow(): void
+    {
+        $lf = (new DataFrame(['x' => [1, 2, 3]]))->lazy();
+        foreach ([fn () => $lf->gatherEvery(0), fn () => $lf->gatherEvery(-2), fn () => $lf->gatherEvery(2, -1)] as $call) {
+            try {
+                $call();
+                $this->fail('Expected Polars\\Exception');
+            } catch (\Polars\Exception $e) {
+                $this->assertStringContainsString('Invalid', $e->getMessage());
+            }
+        }
+    }
+
+    public function testCast(): void
+    {
+        $result = (new DataFrame(['x' => [1, 2], 'y' => ['3', 'a']]))->lazy()
+            ->cast(['x' => 'float64', 'y' => 'int64'])
+            ->collect();
+        $this->assertEquals([1.0, 2.0], $result->column('x')->toArray());
+        $this->assertEquals([3, null], $result->column('y')->toArray());
+    }
+
+    public function testCastStrictThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new DataFrame(['y' => ['a']]))->lazy()->cast(['y' => 'int64'], strict: true)->collect();
+    }
+
+    public function testExplode(): void
+    {
+        $lists = (new DataFrame(['g' => ['a', 'a', 'b'], 'x' => [1, 2, 3]]))->lazy()
+            ->groupBy([Expr::col('g')], maintainOrder: true)
+            ->agg([Expr::col('x')]);
+        $result = $lists->explode(['x'])->collect();
+        $this->assertEquals(['a', 'a', 'b'], $result->column('g')->toArray());
+        $this->assertEquals([1, 2, 3], $result->column('x')->toArray());
+    }
+
+    public function testUnnest(): void
+    {
+        $result = (new DataFrame(['x' => [1, 1, 2]]))->lazy()
+            ->select([Expr::col('x')->valueCounts(sort: true)])
+            ->unnest(['x'])
+            ->collect();
+        $this->assertEquals(['x', 'count'], $result->columns);
+        $this->assertEquals([2, 1], $result->column('count')->toArray());
+    }
+
+    public function testUnpivotAndMelt(): void
+    {
+        $lf = (new DataFrame(['id' => [1, 2], 'a' => [10, 20], 'b' => [30, 40]]))->lazy();
+        $result = $lf->unpivot(['a', 'b'], ['id'])->collect();
+        $this->assertEquals(['id', 'variable', 'value'], $result->columns);
+        $this->assertEquals([10, 20, 30, 40], $result->column('value')->toArray());
+
+        $melted = $lf->melt(['a'], ['id'], 'key', 'val')->collect();
+        $this->assertEquals(['id', 'key', 'val'], $melted->columns);
+    }
+
+    public function testPivot(): void
+    {
+        $lf = (new DataFrame([
+            'name' => ['Alice', 'Bob', 'Alice', 'Bob'],
+            'subject' => ['math', 'math', 'art', 'art'],
+            'score' => [90, 80, 85, 75],
+        ]))->lazy();
+        $result = $lf->pivot('subject', ['math', 'art', 'music'], index: ['name'])->collect();
+        $this->assertEquals(['name', 'math', 'art', 'music'], $result->columns);
+        $this->assertEquals(['Alice', 'Bob'], $result->column('name')->toArray());
+        $this->assertEquals([90, 80], $result->column('math')->toArray());
+        $this->assertEquals([85, 75], $result->column('art')->toArray());
+        $this->assertEquals([null, null], $result->column('music')->toArray());
+    }
+
+    public function testPivotAggregateAndMultipleValues(): void
+    {
+        $lf = (new DataFrame([
+            'name' => ['Alice', 'Alice', 'Bob'],
+            'subject' => ['math', 'math', 'math'],
+            'score' => [90, 10, 80],
+            'bonus' => [1, 2, 3],
+        ]))->lazy();
+        $result = $lf->pivot('subject', ['math'], index: ['name'], aggregateFunction: 'sum')->collect();
+        $this->assertEquals(['name', 'score_math', 'bonus_math'], $result->columns);
+        $this->assertEquals([100, 80], $result->column('score_math')->toArray());
+        $this->assertEquals([3, 3], $result->column('bonus_math')->toArray());
+    }
+
+    public function testPivotWithoutIndexAndValuesThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new DataFrame(['a' => [1], 'b' => [2]]))->lazy()->pivot('a', [1]);
+    }
+
+    public function testDropNansAndInterpolate(): void
+    {
+        $lf = (new DataFrame(['x' => [1.0, NAN, 3.0], 'y' => [1.0, null, 3.0]]))->lazy();
+        $this->assertEquals(2, $lf->dropNans()->collect()->height());
+        $this->assertEquals(3, $lf->dropNans(['y'])->collect()->height());
+        $this->assertEquals([1.0, 2.0, 3.0], $lf->interpolate()->collect()->column('y')->toArray());
+    }
+
+    public function testMergeSorted(): void
+    {
+        $left = (new DataFrame(['a' => [1, 3, 5]]))->lazy();
+        $right = (new DataFrame(['a' => [2, 4, 6]]))->lazy();
+        $result = $left->mergeSorted($right, 'a')->collect();
+        $this->assertEquals([1, 2, 3, 4, 5, 6], $result->column('a')->toArray());
+    }
 }
@@ -107,6 +107,18 @@ class LazyGroupByTest extends TestCase
         $this->assertEquals(3, $result->height());
     }
 
+    public function testHeadNegativeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->groupBy([Expr::col('group')])->head(-1);
+    }
+
+    public function testTailNegativeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->groupBy([Expr::col('group')])->tail(-1);
+    }
+
     // Aggregation convenience methods
 
     public function testSum(): void
@@ -180,4 +192,170 @@ class LazyGroupByTest extends TestCase
             ->collect();
         $this->assertEquals(2, $result->width());
     }
+
+    // Statistics
+
+    public function testLen(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->len()
+            ->sort('group')
+            ->collect();
+        $this->assertEquals(['group', 'len'], $result->columns);
+        $this->assertEquals([2, 2, 2], $result->column('len')->toArray());
+    }
+
+    public function testLenCustomName(): void
+    {
+        $result = $this->createDf()->lazy()->groupBy([Expr::col('group')])->len('rows')->collect();
+        $this->assertEquals(['group', 'rows'], $result->columns);
+    }
+
+    public function testNUnique(): void
+    {
+        $df = new DataFrame(['group' => ['a', 'a', 'a', 'b'], 'value' => [1, 1, 2, 3]]);
+        $result = $df->lazy()->groupBy([Expr::col('group')])->nUnique()->sort('group')->collect();
+        $this->assertEquals([2, 1], $result->column('value')->toArray());
+    }
+
+    public function testStdAndVariance(): void
+    {
+        $gb = $this->createDf()->lazy()->groupBy([Expr::col('group')]);
+        $this->assertEqualsWithDelta(
+            [sqrt(0.5), sqrt(0.5), sqrt(0.5)],
+            $gb->std()->sort('group')->collect()->column('value')->toArray(),
+            1e-12
+        );
+        $this->assertEqualsWithDelta(
+            [0.5, 0.5, 0.5],
+            $gb->variance()->sort('group')->collect()->column('value')->toArray(),
+            1e-12
+        );
+        $this->assertEqualsWithDelta(
+            [0.25, 0.25, 0.25],
+            $gb->variance(0)->sort('group')->collect()->column('value')->toArray(),
+            1e-12
+        );
+    }
+
+    public function testQuantile(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->quantile(1.0)
+            ->sort('group')
+            ->collect();
+        $this->assertEquals([2.0, 4.0, 6.0], $result->column('value')->toArray());
+    }
+
+    public function testQuantileOutOfRangeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->groupBy([Expr::col('group')])->quantile(-0.1);
+    }
+
+    public function testAll(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->all()
+            ->sort('group')
+            ->collect();
+        $this->assertEquals(3, $result->height());
+        $exploded = $result->select([Expr::col('value')->explode()]);
+        $this->assertEquals([1, 2, 3, 4, 5, 6], $exploded->column('value')->toArray());
+    }
+
+    // Maintain order
+
+    public function testMaintainOrder(): void
+    {
+        $df = new DataFrame(['group' => ['c', 'a', 'c', 'b'], 'value' => [1, 2, 3, 4]]);
+        $result = $df->lazy()
+            ->groupBy([Expr::col('group')], maintainOrder: true)
+            ->sum()
+            ->collect();
+        $this->assertEquals(['c', 'a', 'b'], $result->column('group')->toArray());
+        $this->assertEquals([4, 2, 4], $result->column('value')->toArray());
+    }
+
+    // Having
+
+    public function testHaving(): void
+    {
+        $gb = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->having(Expr::col('value')->sum()->gt(3));
+        $this->assertInstanceOf(LazyGroupBy::class, $gb);
+
+        $result = $gb->sum()->sort('group')->collect();
+        $this->assertEquals(['group', 'value', 'score'], $result->columns);
+        $this->assertEquals(['b', 'c'], $result->column('group')->toArray());
+        $this->assertEquals([7, 11], $result->column('value')->toArray());
+    }
+
+    public function testHavingMultiplePredicates(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->having(Expr::col('value')->sum()->gt(3))
+            ->having(Expr::col('score')->max()->lt(50))
+            ->agg([Expr::col('value')->max()])
+            ->collect();
+        $this->assertEquals(['b'], $result->column('group')->toArray());
+    }
+
+    public function testHavingWithHead(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')], maintainOrder: true)
+            ->having(Expr::col('value')->sum()->gt(3))
+            ->head(1)
+            ->collect();
+        $this->assertEquals(['group', 'value', 'score'], $result->columns);
+        $this->assertEquals([3, 5], $result->column('value')->toArray());
+    }
+
+    // Map groups
+
+    public function testMapGroups(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->mapGroups(fn (DataFrame $group) => $group->head(1))
+            ->sort('group')
+            ->collect();
+        $this->assertEquals(['group', 'value', 'score'], $result->columns);
+        $this->assertEquals([1, 3, 5], $result->column('value')->toArray());
+    }
+
+    public function testMapGroupsWithSchema(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->mapGroups(
+                fn (DataFrame $group) => $group->select([Expr::col('value')->sum()->alias('total')]),
+                ['total' => 'int64']
+            );
+        $this->assertEquals(['total'], $result->getColumns());
+        $this->assertEquals([3, 7, 11], $result->sort('total')->collect()->column('total')->toArray());
+    }
+
+    public function testMapGroupsWithHaving(): void
+    {
+        $result = $this->createDf()->lazy()
+            ->groupBy([Expr::col('group')])
+            ->having(Expr::col('value')->sum()->gt(3))
+            ->mapGroups(fn (DataFrame $group) => $group->tail(1))
+            ->sort('group')
+            ->collect();
+        $this->assertEquals([4, 6], $result->column('value')->toArray());
+    }
+
+    public function testMapGroupsNotCallableThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->lazy()->groupBy([Expr::col('group')])->mapGroups('not a function');
+    }
 }
@@ -0,0 +1,167 @@
+<?php
+
+namespace Tests\Polars;
+
+use PHPUnit\Framework\TestCase;
+use Polars\DataFrame;
+use Polars\Expr;
+use Polars\Selector;
+use Polars\Selectors;
+
+class SelectorsTest extends TestCase
+{
+    private function createDf(): DataFrame
+    {
+        return new DataFrame([
+            'name' => ['Alice', 'Bob'],
+            'age' => [25, 30],
+            'score' => [95.5, 87.3],
+            'active' => [true, false],
+            'name_upper' => ['ALICE', 'BOB'],
+        ]);
+    }
+
+    public function testFactoriesReturnSelector(): void
+    {
+        $this->assertInstanceOf(Selector::class, Selectors::all());
+        $this->assertInstanceOf(Selector::class, Selectors::numeric());
+        $this->assertInstanceOf(Selector::class, Selectors::byDtype('int64'));
+    }
+
+    public function testAll(): void
+    {
+        $df = $this->createDf()->select([Selectors::all()]);
+        $this->assertEquals(['name', 'age', 'score', 'active', 'name_upper'], $df->columns);
+    }
+
+    public function testByName(): void
+    {
+        $df = $this->createDf()->select([Selectors::byName(['score', 'name'])]);
+        $this->assertEquals(['score', 'name'], $df->columns);
+    }
+
+    public function testByNameMissingColumnThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        $this->createDf()->select([Selectors::byName(['missing'])]);
+    }
+
+    public function testByIndex(): void
+    {
+        $df = $this->createDf()->select([Selectors::byIndex([0, -1])]);
+        $this->assertEquals(['name', 'name_upper'], $df->columns);
+    }
+
+    public function testFirstAndLast(): void
+    {
+        $this->assertEquals(['name'], $this->createDf()->select([Selectors::first()])->columns);
+        $this->assertEquals(['name_upper'], $this->createDf()->select([Selectors::last()])->columns);
+    }
+
+    public function testByDtype(): void
+    {
+        $df = $this->createDf()->select([Selectors::byDtype(['int64', 'bool'])]);
+        $this->assertEquals(['age', 'active'], $df->columns);
+    }
+
+    public function testByDtypeWithDataTypeObject(): void
+    {
+        $dtype = $this->createDf()->dtypes[2];
+        $df = $this->createDf()->select([Selectors::byDtype($dtype)]);
+        $this->assertEquals(['score'], $df->columns);
+    }
+
+    public function testByDtypeInvalidThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        Selectors::byDtype('not_a_type');
+    }
+
+    public function testDtypeGroups(): void
+    {
+        $df = $this->createDf();
+        $this->assertEquals(['age', 'score'], $df->select([Selectors::numeric()])->columns);
+        $this->assertEquals(['age'], $df->select([Selectors::integer()])->columns);
+        $this->assertEquals(['score'], $df->select([Selectors::float()])->columns);
+        $this->assertEquals(['name', 'name_upper'], $df->select([Selectors::string()])->columns);
+        $this->assertEquals(['active'], $df->select([Selectors::boolean()])->columns);
+    }
+
+    public function testTemporal(): void
+    {
+        $df = (new DataFrame(['a' => [1]]))->select([
+            Expr::col('a'),
+            Expr::lit('2024-01-01', 'date')->alias('day'),
+        ]);
+        $this->assertEquals(['day'], $df->select([Selectors::temporal()])->columns);
+    }
+
+    public function testNamePatterns(): void
+    {
+        $df = $this->createDf();
+        $this->assertEquals(['name', 'name_upper'], $df->select([Selectors::startsWith(['name'])])->columns);
+        $this->assertEquals(['name_upper'], $df->select([Selectors::endsWith(['_upper'])])->columns);
+        $this->assertEquals(['score', 'active'], $df->select([Selectors::contains(['co', 'tiv'])])->columns);
+        $this->assertEquals(['age', 'score'], $df->select([Selectors::matches('^(age|score)$')])->columns);
+    }
+
+    public function testPatternIsEscaped(): void
+    {
+        $df = new DataFrame(['a.b' => [1], 'axb' => [2]]);
+        $this->assertEquals(['a.b'], $df->select([Selectors::contains(['a.b'])])->columns);
+    }
+
+    public function testUnion(): void
+    {
+        $df = $this->createDf()->select([Selectors::boolean()->union(Selectors::integer())]);
+        $this->assertEquals(['age', 'active'], $df->columns);
+    }
+
+    public function testIntersection(): void
+    {
+        $df = $this->createDf()->select([Selectors::string()->intersection(Selectors::endsWith(['_upper']))]);
+        $this->assertEquals(['name_upper'], $df->columns);
+    }
+
+    public function testDifference(): void
+    {
+        $df = $this->createDf()->select([Selectors::all()->difference(Selectors::string())]);
+        $this->assertEquals(['age', 'score', 'active'], $df->columns);
+    }
+
+    public function testExclusiveOr(): void
+    {
+        $df = $this->createDf()->select([Selectors::numeric()->exclusiveOr(Selectors::contains(['e']))]);
+        $this->assertEquals(['name', 'age', 'active', 'name_upper'], $df->columns);
+    }
+
+    public function testComplement(): void
+    {
+        $df = $this->createDf()->select([Selectors::numeric()->complement()]);
+        $this->assertEquals(['name', 'active', 'name_upper'], $df->columns);
+    }
+
+    public function testAsExpr(): void
+    {
+        $expr = Selectors::numeric()->asExpr();
+        $this->assertInstanceOf(Expr::class, $expr);
+
+        $df = $this->createDf()->select([$expr->max()]);
+        $this->assertEquals(['age', 'score'], $df->columns);
+        $this->assertEquals([30], $df->column('age')->toArray());
+    }
+
+    public function testWithColumns(): void
+    {
+        $df = $this->createDf()->withColumns([Selectors::integer()->asExpr()->mul(2)]);
+        $this->assertEquals([50, 60], $df->column('age')->toArray());
+    }
+
+    public function testGroupBy(): void
+    {
+        $df = new DataFrame(['key' => ['a', 'a', 'b'], 'value' => [1, 2, 3]]);
+        $result = $df->groupBy([Selectors::string()])->agg([Expr::col('value')->sum()])->sort('key');
+        $this->assertEquals(['key', 'value'], $result->columns);
+        $this->assertEquals([3, 3], $result->column('value')->toArray());
+    }
+}
@@ -428,6 +428,224 @@ class SeriesTest extends TestCase
         $this->assertFalse($result[3]);
     }
 
+    // ==================== Math Operations Tests ====================
+
+    public function testAbs(): void
+    {
+        $s = new Series('x', [-2, 0, 3]);
+        $this->assertEquals([2, 0, 3], $s->abs()->toArray());
+    }
+
+    public function testSqrtAndCbrt(): void
+    {
+        $s = new Series('x', [4.0, 27.0]);
+        $this->assertEqualsWithDelta([2.0, 5.196], $s->sqrt()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([1.587, 3.0], $s->cbrt()->toArray(), 0.001);
+    }
+
+    public function testExpAndLog(): void
+    {
+        $s = new Series('x', [1.0, 10.0]);
+        $this->assertEqualsWithDelta([0.0, 2.302], $s->log()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([0.0, 1.0], $s->log10()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([0.0, 3.321], $s->log(2.0)->toArray(), 0.001);
+        $this->assertEqualsWithDelta([0.693, 2.397], $s->log1p()->toArray(), 0.001);
+        $this->assertEqualsWithDelta(2.718, $s->exp()[0], 0.001);
+    }
+
+    public function testTrigonometry(): void
+    {
+        $s = new Series('x', [0.0, 1.0]);
+        $this->assertEqualsWithDelta([0.0, 0.841], $s->sin()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([1.0, 0.540], $s->cos()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([0.0, 0.785], $s->arctan()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([0.0, 0.761], $s->tanh()->toArray(), 0.001);
+        $this->assertEqualsWithDelta([0.0, 57.295], $s->degrees()->toArray(), 0.001);
+    }
+
+    public function testFloorCeilRound(): void
+    {
+        $s = new Series('x', [1.25, -1.75, 2.5]);
+        $this->assertEquals([1.0, -2.0, 2.0], $s->floor()->toArray());
+        $this->assertEquals([2.0, -1.0, 3.0], $s->ceil()->toArray());
+        $this->assertEquals([1.2, -1.8, 2.5], $s->round(1)->toArray());
+        $this->assertEquals([1.0, -2.0, 3.0], $s->round(0, 'half_away_from_zero')->toArray());
+    }
+
+    public function testRoundSigFigs(): void
+    {
+        $s = new Series('x', [1234.5, 0.012345]);
+        $this->assertEqualsWithDelta([1200.0, 0.012], $s->roundSigFigs(2)->toArray(), 0.0001);
+    }
+
+    public function testClip(): void
+    {
+        $s = new Series('x', [-5, 0, 5, 10]);
+        $this->assertEquals([0, 0, 5, 6], $s->clip(0, 6)->toArray());
+        $this->assertEquals([0, 0, 5, 10], $s->clip(lowerBound: 0)->toArray());
+        $this->assertEquals([-5, 0, 5, 6], $s->clip(upperBound: 6)->toArray());
+    }
+
+    public function testSign(): void
+    {
+        $s = new Series('x', [-2.5, 0.0, 3.0]);
+        $this->assertEquals([-1.0, 0.0, 1.0], $s->sign()->toArray());
+    }
+
+    public function testMathKeepsName(): void
+    {
+        $s = new Series('values', [1.0, 4.0]);
+        $this->assertEquals('values', $s->sqrt()->name);
+    }
+
+    public function testDot(): void
+    {
+        $a = new Series('a', [1, 2, 3]);
+        $b = new Series('b', [4, 5, 6]);
+        $this->assertEquals(32, $a->dot($b));
+    }
+
+    public function testDotLengthMismatch(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new Series('a', [1, 2, 3]))->dot(new Series('b', [1, 2]));
+    }
+
+    // ==================== Cumulative Operations Tests ====================
+
+    public function testCumSum(): void
+    {
+        $s = new Series('x', [1, 2, 4, 7]);
+        $this->assertEquals([1, 3, 7, 14], $s->cumSum()->toArray());
+        $this->assertEquals([14, 13, 11, 7], $s->cumSum(reverse: true)->toArray());
+    }
+
+    public function testCumProdMinMax(): void
+    {
+        $s = new Series('x', [3, 1, 4, 2]);
+        $this->assertEquals([3, 3, 12, 24], $s->cumProd()->toArray());
+        $this->assertEquals([3, 1, 1, 1], $s->cumMin()->toArray());
+        $this->assertEquals([3, 3, 4, 4], $s->cumMax()->toArray());
+    }
+
+    public function testCumCount(): void
+    {
+        $s = new Series('x', [1, null, 3]);
+        $this->assertEquals([1, 1, 2], $s->cumCount()->toArray());
+    }
+
+    public function testDiff(): void
+    {
+        $s = new Series('x', [10, 15, 12, 24]);
+        $this->assertEquals([null, 5, -3, 12], $s->diff()->toArray());
+        $this->assertEquals([5, -3, 12], $s->diff(1, 'drop')->toArray());
+    }
+
+    public function testPctChange(): void
+    {
+        $s = new Series('x', [10.0, 15.0, 30.0]);
+        $result = $s->pctChange()->toArray();
+        $this->assertNull($result[0]);
+        $this->assertEqualsWithDelta(0.5, $result[1], 0.0001);
+        $this->assertEqualsWithDelta(1.0, $result[2], 0.0001);
+    }
+
+    public function testCumulativeEval(): void
+    {
+        $s = new Series('x', [1, 2, 3]);
+        $result = $s->cumulativeEval(\Polars\Expr::element()->sum());
+        $this->assertEquals([1, 3, 6], $result->toArray());
+    }
+
+    public function testCumulativeEvalNegativeMinSamplesThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new Series('x', [1, 2, 3]))->cumulativeEval(\Polars\Expr::element()->sum(), -1);
+    }
+
+    // ==================== Rolling Window Tests ====================
+
+    public function testRollingMean(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0, 4.0]);
+        $this->assertEquals([null, 1.5, 2.5, 3.5], $s->rollingMean(2)->toArray());
+        $this->assertEquals([1.0, 1.5, 2.5, 3.5], $s->rollingMean(2, minPeriods: 1)->toArray());
+    }
+
+    public function testRollingNegativeWindowSizeThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new Series('x', [1.0, 2.0]))->rollingMean(-2);
+    }
+
+    public function testRollingSumMinMax(): void
+    {
+        $s = new Series('x', [3.0, 1.0, 4.0, 1.0, 5.0]);
+        $this->assertEquals([null, null, 8.0, 6.0, 10.0], $s->rollingSum(3)->toArray());
+        $this->assertEquals([null, null, 1.0, 1.0, 1.0], $s->rollingMin(3)->toArray());
+        $this->assertEquals([null, null, 4.0, 4.0, 5.0], $s->rollingMax(3)->toArray());
+        $this->assertEquals([null, null, 3.0, 1.0, 4.0], $s->rollingMedian(3)->toArray());
+    }
+
+    public function testRollingVarWithWeights(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0]);
+        $this->assertEqualsWithDelta([null, 0.5, 0.5], $s->rollingVar(2)->toArray(), 0.0001);
+        $this->assertEquals([null, 2.5, 4.0], $s->rollingSum(2, weights: [0.5, 1.0])->toArray());
+    }
+
+    public function testRollingQuantile(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0, 4.0]);
+        $this->assertEquals([null, 2.0, 3.0, 4.0], $s->rollingQuantile(1.0, 2)->toArray());
+    }
+
+    public function testRollingSumBy(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0, 4.0, 5.0]);
+        $idx = new Series('idx', [0, 1, 2, 5, 6]);
+        $result = $s->rollingSumBy($idx, '2i');
+        $this->assertEquals('x', $result->name);
+        $this->assertEquals([1.0, 3.0, 5.0, 4.0, 9.0], $result->toArray());
+    }
+
+    public function testRollingByLengthMismatch(): void
+    {
+        $this->expectException(Exception::class);
+        (new Series('x', [1.0, 2.0]))->rollingMeanBy(new Series('idx', [0, 1, 2]), '2i');
+    }
+
+    // ==================== Exponentially Weighted Tests ====================
+
+    public function testEwmMean(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0]);
+        $this->assertEqualsWithDelta([1.0, 1.5, 2.25], $s->ewmMean(alpha: 0.5, adjust: false)->toArray(), 0.0001);
+        $this->assertEqualsWithDelta([1.0, 1.6667, 2.4286], $s->ewmMean(span: 3)->toArray(), 0.0001);
+    }
+
+    public function testEwmStdAndVar(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0, 4.0]);
+        $std = $s->ewmStd(alpha: 0.5)->toArray();
+        $var = $s->ewmVar(alpha: 0.5)->toArray();
+        $this->assertCount(4, $std);
+        $this->assertEqualsWithDelta($var[3], $std[3] ** 2, 0.0001);
+    }
+
+    public function testEwmMeanBy(): void
+    {
+        $s = new Series('x', [1.0, 2.0, 3.0]);
+        $result = $s->ewmMeanBy(new Series('t', [0, 1, 2]), '1i');
+        $this->assertEqualsWithDelta([1.0, 1.5, 2.25], $result->toArray(), 0.0001);
+    }
+
+    public function testEwmMeanRequiresDecayParameter(): void
+    {
+        $this->expectException(Exception::class);
+        (new Series('x', [1.0, 2.0]))->ewmMean();
+    }
+
     // ==================== Data Manipulation Tests ====================
 
     public function testSort(): void
@@ -554,4 +772,61 @@ class SeriesTest extends TestCase
         $this->assertInstanceOf(Series::class, $seriesArr[0]);
         $this->assertInstanceOf(Series::class, $seriesArr[1]);
     }
+
+    public function testHistWithBins(): void
+    {
+        $s = new Series('x', [1, 2, 3, 4, 5]);
+
+        $hist = $s->hist(bins: [0, 2, 4, 6]);
+        $this->assertInstanceOf(DataFrame::class, $hist);
+        $this->assertEquals(['breakpoint', 'category', 'count'], $hist->columns);
+        $this->assertEquals([2.0, 4.0, 6.0], $hist->column('breakpoint')->toArray());
+        $this->assertEquals([2, 2, 1], $hist->column('count')->toArray());
+    }
+
+    public function testHistWithBinCount(): void
+    {
+        $s = new Series('x', [0, 1, 2, 3]);
+
+        $hist = $s->hist(binCount: 2, includeCategory: false);
+        $this->assertEquals(['breakpoint', 'count'], $hist->columns);
+        $this->assertEquals([2, 2], $hist->column('count')->toArray());
+    }
+
+    public function testHistCountOnly(): void
+    {
+        $s = new Series('x', [1, 2, 3]);
+
+        $hist = $s->hist(bins: [0, 10], includeCategory: false, includeBreakpoint: false);
+        $this->assertEquals(['count'], $hist->columns);
+        $this->assertEquals([3], $hist->column('count')->toArray());
+    }
+
+    public function testHistBinsAndBinCountThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new Series('x', [1, 2]))->hist(bins: [0, 1], binCount: 2);
+    }
+
+    public function testCorr(): void
+    {
+        $x = new Series('x', [1, 2, 3, 4]);
+
+        $this->assertEqualsWithDelta(-1.0, $x->corr(new Series('y', [8, 6, 4, 2])), 1e-12);
+        $this->assertEqualsWithDelta(0.8, $x->corr(new Series('z', [1, 3, 2, 4]), 'spearman'), 1e-12);
+    }
+
+    public function testCov(): void
+    {
+        $x = new Series('x', [1, 2, 3]);
+
+        $this->assertEqualsWithDelta(2.0, $x->cov(new Series('y', [2, 4, 6])), 1e-12);
+        $this->assertEqualsWithDelta(4 / 3, $x->cov(new Series('y', [2, 4, 6]), ddof: 0), 1e-12);
+    }
+
+    public function testCorrLengthMismatchThrows(): void
+    {
+        $this->expectException(\Polars\Exception::class);
+        (new Series('x', [1, 2, 3]))->corr(new Series('y', [1, 2]));
+    }
 }
@@ -1,8 +1,18 @@
-use ext_php_rs::types::{ZendHashTable, Zval};
-use polars::prelude::{AnyValue, DataType};
-use polars::lazy::dsl::Expr;
+use ext_php_rs::convert::IntoZvalDyn;
+use ext_php_rs::types::{ZendCallable, ZendHashTable, Zval};
+use polars::error::{polars_bail, polars_err};
+use polars::prelude::{
+    AnyValue, AsOfOptions, AsofStrategy, DataType, Field, JoinBuilder, JoinCoalesce, JoinType,
+    IdxSize, JoinValidation, LazyFrame, MaintainOrderJoin, PlSmallStr, Scalar,
+    PolarsResult, Schema, Selector, SortMultipleOptions, TimeUnit, UnpivotArgsDSL,
+};
+use polars::lazy::dsl::{Expr, col};
 use crate::exception::{ExtResult, PolarsException};
+use crate::data_type::PolarsDataType;
 use crate::expression::PolarsExpr;
+use crate::selector::PolarsSelector;
+use std::mem::ManuallyDrop;
+use std::thread::ThreadId;
 
 /// Parse a string dtype name to a Polars DataType
 pub fn parse_dtype(dtype: &str) -> ExtResult<DataType> {
@@ -19,17 +29,45 @@ pub fn parse_dtype(dtype: &str) -> ExtResult<DataType> {
         "float64" | "f64" => Ok(DataType::Float64),
         "bool" | "boolean" => Ok(DataType::Boolean),
         "string" | "str" | "utf8" => Ok(DataType::String),
+        "date" => Ok(DataType::Date),
+        "datetime" => Ok(DataType::Datetime(TimeUnit::Microseconds, None)),
+        "time" => Ok(DataType::Time),
         _ => Err(PolarsException::new(format!(
-            "Unknown data type: {}. Supported: int8, int16, int32, int64, uint8, uint16, uint32, uint64, float32, float64, bool, string",
+            "Unknown data type: {}. Supported: int8, int16, int32, int64, uint8, uint16, uint32, uint64, float32, float64, bool, string, date, datetime, time",
             dtype
         ))),
     }
 }
 
-/// Extract Vec<Expr> from a PHP ZendHashTable containing PolarsExpr objects
+/// Convert a dtype name or a Polars\DataType object to a Polars DataType
+pub fn zval_to_dtype(value: &Zval) -> ExtResult<DataType> {
+    if let Some(name) = value.str() {
+        return parse_dtype(name);
+    }
+    if let Some(dtype) = value.extract::<&PolarsDataType>() {
+        return Ok(dtype.into());
+    }
+    Err(PolarsException::new(
+        "Data type must be a string or Polars\\DataType".to_string(),
+    ))
+}
+
+/// Convert a PHP array of column name => data type into a Polars Schema
+pub fn zval_to_schema(schema: &ZendHashTable) -> ExtResult<Schema> {
+    schema
+        .iter()
+        .map(|(key, value)| Ok(Field::new(key.to_string().into(), zval_to_dtype(value)?)))
+        .collect()
+}
+
+/// Extract Vec<Expr> from a PHP ZendHashTable containing PolarsExpr or PolarsSelector objects
 pub fn extract_exprs(expressions: &ZendHashTable) -> ExtResult<Vec<Expr>> {
     let mut exprs: Vec<Expr> = Vec::new();
     for (_, value) in expressions.iter() {
+        if let Some(selector) = value.extract::<&PolarsSelector>() {
+            exprs.push(selector.get_selector().clone().as_expr());
+            continue;
+        }
         let expr: &PolarsExpr = match value.extract::<&PolarsExpr>() {
             Some(expr) => expr,
             None => {
@@ -43,6 +81,128 @@ pub fn extract_exprs(expressions: &ZendHashTable) -> ExtResult<Vec<Expr>> {
     Ok(exprs)
 }
 
+/// Convert a column name, a \Polars\Expr or a \Polars\Selector to Expr. Strings are treated as column names.
+pub fn zval_to_column_expr(value: &Zval) -> ExtResult<Expr> {
+    if let Some(name) = value.str() {
+        return Ok(col(name));
+    }
+    if let Some(expr) = value.extract::<&PolarsExpr>() {
+        return Ok(expr.get_expr().clone());
+    }
+    if let Some(selector) = value.extract::<&PolarsSelector>() {
+        return Ok(selector.get_selector().clone().as_expr());
+    }
+    Err(PolarsException::new(
+        "Expected a column name or \\Polars\\Expr".to_string(),
+    ))
+}
+
+/// Convert a column name, a \Polars\Expr, a \Polars\Selector or an array of them to Vec<Expr>.
+/// Strings are treated as column names, not literals.
+pub fn zval_to_column_exprs(value: &Zval) -> ExtResult<Vec<Expr>> {
+    if let Some(name) = value.str() {
+        return Ok(vec![col(name)]);
+    }
+    if let Some(expr) = value.extract::<&PolarsExpr>() {
+        return Ok(vec![expr.get_expr().clone()]);
+    }
+    if let Some(selector) = value.extract::<&PolarsSelector>() {
+        return Ok(vec![selector.get_selector().clone().as_expr()]);
+    }
+    if let Some(array) = value.array() {
+        let mut exprs: Vec<Expr> = Vec::with_capacity(array.len());
+        for (_, item) in array.iter() {
+            if let Some(name) = item.str() {
+                exprs.push(col(name));
+            } else if let Some(expr) = item.extract::<&PolarsExpr>() {
+                exprs.push(expr.get_expr().clone());
+            } else if let Some(selector) = item.extract::<&PolarsSelector>() {
+                exprs.push(selector.get_selector().clone().as_expr());
+            } else {
+                return Err(PolarsException::new(
+                    "Array elements must be column names or \\Polars\\Expr objects".to_string(),
+                ));
+            }
+        }
+        return Ok(exprs);
+    }
+    Err(PolarsException::new(
+        "Expected a column name, \\Polars\\Expr or an array of them".to_string(),
+    ))
+}
+
+/// Build sort options from per-key `descending` and `nullsLast` flags, each a bool or an array of bools.
+/// Null means ascending order with nulls last.
+pub fn sort_options(
+    descending: Option<&Zval>,
+    nulls_last: Option<&Zval>,
+    len: usize,
+    maintain_order: bool,
+    multithreaded: bool,
+) -> ExtResult<SortMultipleOptions> {
+    let descending = zval_to_bools(descending, len, "descending")?;
+    let nulls_last = match nulls_last {
+        Some(value) if !value.is_null() => zval_to_bools(Some(value), len, "nullsLast")?,
+        _ => vec![true; len],
+    };
+    Ok(SortMultipleOptions::new()
+        .with_order_descending_multi(descending)
+        .with_nulls_last_multi(nulls_last)
+        .with_maintain_order(maintain_order)
+        .with_multithreaded(multithreaded))
+}
+
+/// Convert a PHP int to a number of rows
+pub fn zval_to_row_count(k: i64) -> ExtResult<IdxSize> {
+    IdxSize::try_from(k)
+        .map_err(|_| PolarsException::new(format!("Invalid k: {}. Must not be negative", k)))
+}
+
+/// Convert a PHP int to a length or count, `name` is the parameter named in the error
+pub fn zval_to_length(n: i64, name: &str) -> ExtResult<usize> {
+    usize::try_from(n)
+        .map_err(|_| PolarsException::new(format!("Invalid {}: {}. Must not be negative", name, n)))
+}
+
+/// Convert a PHP int to a random or hash seed
+pub fn zval_to_seed(seed: i64) -> ExtResult<u64> {
+    u64::try_from(seed)
+        .map_err(|_| PolarsException::new(format!("Invalid seed: {}. Must not be negative", seed)))
+}
+
+/// Convert a bool or an array of bools to one flag per key.
+/// A single bool (or null, meaning false) is broadcast to all `len` keys.
+pub fn zval_to_bools(value: Option<&Zval>, len: usize, name: &str) -> ExtResult<Vec<bool>> {
+    let value = match value {
+        Some(value) if !value.is_null() => value,
+        _ => return Ok(vec![false; len]),
+    };
+    if let Some(flag) = value.bool() {
+        return Ok(vec![flag; len]);
+    }
+    if let Some(array) = value.array() {
+        let mut flags = Vec::with_capacity(array.len());
+        for (_, item) in array.iter() {
+            flags.push(item.bool().ok_or_else(|| {
+                PolarsException::new(format!("'{}' array must contain only booleans", name))
+            })?);
+        }
+        if flags.len() != len {
+            return Err(PolarsException::new(format!(
+                "'{}' has {} elements but {} keys were given",
+                name,
+                flags.len(),
+                len
+            )));
+        }
+        return Ok(flags);
+    }
+    Err(PolarsException::new(format!(
+        "'{}' must be a boolean or an array of booleans",
+        name
+    )))
+}
+
 /// Convert a Polars AnyValue to a PHP Zval
 pub fn any_value_to_zval(value: AnyValue) -> ExtResult<Zval> {
     let mut zval = Zval::new();
@@ -104,6 +264,15 @@ pub fn any_value_to_zval(value: AnyValue) -> ExtResult<Zval> {
             })?;
             Ok(zval)
         }
+        AnyValue::Categorical(..)
+        | AnyValue::CategoricalOwned(..)
+        | AnyValue::Enum(..)
+        | AnyValue::EnumOwned(..) => {
+            zval.set_string(&value.str_value(), false).map_err(|e| {
+                PolarsException::new(format!("Failed to set string: {}", e))
+            })?;
+            Ok(zval)
+        }
         _ => {
             zval.set_string(&format!("{}", value), false).map_err(|e| {
                 PolarsException::new(format!("Failed to set string: {}", e))
@@ -112,3 +281,330 @@ pub fn any_value_to_zval(value: AnyValue) -> ExtResult<Zval> {
         }
     }
 }
+
+/// Join two LazyFrames using the arguments shared by `DataFrame::join()` and `LazyFrame::join()`.
+/// `leftOn`/`rightOn` override `on` and must be given together.
+#[allow(clippy::too_many_arguments)]
+pub fn join_lazy_frames(
+    left: LazyFrame,
+    right: LazyFrame,
+    on: &ZendHashTable,
+    how: &str,
+    left_on: Option<&ZendHashTable>,
+    right_on: Option<&ZendHashTable>,
+    suffix: Option<String>,
+    validate: Option<String>,
+    coalesce: Option<bool>,
+    nulls_equal: bool,
+    maintain_order: &str,
+    allow_parallel: bool,
+    force_parallel: bool,
+) -> ExtResult<LazyFrame> {
+    let join_type = match how {
+        "inner" => JoinType::Inner,
+        "left" => JoinType::Left,
+        "right" => JoinType::Right,
+        "full" => JoinType::Full,
+        "cross" => JoinType::Cross,
+        "semi" => JoinType::Semi,
+        "anti" => JoinType::Anti,
+        _ => {
+            return Err(PolarsException::new(format!(
+                "Invalid join type: {}. Use 'inner', 'left', 'right', 'full', 'cross', 'semi', or 'anti'",
+                how
+            )));
+        }
+    };
+
+    let (left_exprs, right_exprs) = match (left_on, right_on) {
+        (Some(left), Some(right)) => (extract_exprs(left)?, extract_exprs(right)?),
+        (None, None) => {
+            let exprs = extract_exprs(on)?;
+            (exprs.clone(), exprs)
+        }
+        _ => {
+            return Err(PolarsException::new(
+                "Both leftOn and rightOn must be provided together".to_string(),
+            ));
+        }
+    };
+
+    let maintain_order = match maintain_order {
+        "none" => MaintainOrderJoin::None,
+        "left" => MaintainOrderJoin::Left,
+        "right" => MaintainOrderJoin::Right,
+        "left_right" => MaintainOrderJoin::LeftRight,
+        "right_left" => MaintainOrderJoin::RightLeft,
+        _ => {
+            return Err(PolarsException::new(format!(
+                "Invalid maintainOrder: {}. Use 'none', 'left', 'right', 'left_right', or 'right_left'",
+                maintain_order
+            )));
+        }
+    };
+
+    let mut builder = JoinBuilder::new(left)
+        .with(right)
+        .how(join_type)
+        .left_on(left_exprs)
+        .right_on(right_exprs)
+        .join_nulls(nulls_equal)
+        .maintain_order(maintain_order)
+        .allow_parallel(allow_parallel)
+        .force_parallel(force_parallel);
+
+    if let Some(s) = suffix {
+        builder = builder.suffix(s);
+    }
+
+    if let Some(v) = validate {
+        builder = builder.validate(match v.as_str() {
+            "m:m" => JoinValidation::ManyToMany,
+            "m:1" => JoinValidation::ManyToOne,
+            "1:m" => JoinValidation::OneToMany,
+            "1:1" => JoinValidation::OneToOne,
+            _ => {
+                return Err(PolarsException::new(format!(
+                    "Invalid validation: {}. Use 'm:m', 'm:1', '1:m', or '1:1'",
+                    v
+                )));
+            }
+        });
+    }
+
+    if let Some(c) = coalesce {
+        builder = builder.coalesce(if c {
+            JoinCoalesce::CoalesceColumns
+        } else {
+            JoinCoalesce::KeepColumns
+        });
+    }
+
+    Ok(builder.finish())
+}
+
+/// Convert a column name or an array of column names to a list of names
+pub fn zval_to_names(value: &Zval, name: &str) -> ExtResult<Vec<PlSmallStr>> {
+    if let Some(s) = value.str() {
+        return Ok(vec![PlSmallStr::from(s)]);
+    }
+    if let Some(array) = value.array() {
+        return array
+            .iter()
+            .map(|(_, item)| {
+                item.str().map(PlSmallStr::from).ok_or_else(|| {
+                    PolarsException::new(format!("{} must contain only column names", name))
+                })
+            })
+            .collect();
+    }
+    Err(PolarsException::new(format!(
+        "{} must be a column name or an array of column names",
+        name
+    )))
+}
+
+/// As-of join two LazyFrames using the arguments shared by `DataFrame::joinAsof()` and `LazyFrame::joinAsof()`.
+/// The key is `on`, or `leftOn`/`rightOn`; groups are `by`, or `leftBy`/`rightBy`.
+#[allow(clippy::too_many_arguments)]
+pub fn join_asof_lazy_frames(
+    left: LazyFrame,
+    right: LazyFrame,
+    on: Option<String>,
+    strategy: Option<String>,
+    left_by: Option<&Zval>,
+    right_by: Option<&Zval>,
+    tolerance: Option<&Zval>,
+    by: Option<&Zval>,
+    left_on: Option<String>,
+    right_on: Option<String>,
+    suffix: Option<String>,
+    allow_exact_matches: bool,
+    check_sortedness: bool,
+) -> ExtResult<LazyFrame> {
+    let strategy_str = strategy.as_deref().unwrap_or("backward");
+    let strategy = match strategy_str {
+        "backward" => AsofStrategy::Backward,
+        "forward" => AsofStrategy::Forward,
+        "nearest" => AsofStrategy::Nearest,
+        _ => {
+            return Err(PolarsException::new(format!(
+                "Invalid asof strategy: {}. Use 'backward', 'forward', or 'nearest'",
+                strategy_str
+            )));
+        }
+    };
+
+    let (left_key, right_key) = match (on, left_on, right_on) {
+        (Some(on), None, None) => (on.clone(), on),
+        (None, Some(left), Some(right)) => (left, right),
+        _ => {
+            return Err(PolarsException::new(
+                "Provide either on, or both leftOn and rightOn".to_string(),
+            ));
+        }
+    };
+
+    let (left_by, right_by) = match (by, left_by, right_by) {
+        (Some(by), None, None) => {
+            let names = zval_to_names(by, "by")?;
+            (Some(names.clone()), Some(names))
+        }
+        (None, Some(left), Some(right)) => (
+            Some(zval_to_names(left, "leftBy")?),
+            Some(zval_to_names(right, "rightBy")?),
+        ),
+        (None, None, None) => (None, None),
+        _ => {
+            return Err(PolarsException::new(
+                "Provide either by, or both leftBy and rightBy".to_string(),
+            ));
+        }
+    };
+
+    let (tolerance, tolerance_str) = match tolerance {
+        None => (None, None),
+        Some(value) => {
+            if let Some(s) = value.str() {
+                (None, Some(PlSmallStr::from(s)))
+            } else if let Some(i) = value.long() {
+                (Some(Scalar::from(i)), None)
+            } else if let Some(f) = value.double() {
+                (Some(Scalar::from(f)), None)
+            } else {
+                return Err(PolarsException::new(
+                    "Tolerance must be a number or a duration string".to_string(),
+                ));
+            }
+        }
+    };
+
+    let options = AsOfOptions {
+        strategy,
+        tolerance,
+        tolerance_str,
+        left_by,
+        right_by,
+        allow_eq: allow_exact_matches,
+        check_sortedness,
+    };
+
+    let mut builder = JoinBuilder::new(left)
+        .with(right)
+        .how(JoinType::AsOf(Box::new(options)))
+        .left_on([col(left_key)])
+        .right_on([col(right_key)]);
+    if let Some(s) = suffix {
+        builder = builder.suffix(s);
+    }
+    Ok(builder.finish())
+}
+
+/// Build cast expressions from a PHP array of column name => data type name,
+/// shared by `DataFrame::cast()` and `LazyFrame::cast()`
+pub fn cast_exprs(dtypes: &ZendHashTable, strict: bool) -> ExtResult<Vec<Expr>> {
+    let mut exprs: Vec<Expr> = Vec::new();
+    for (key, value) in dtypes.iter() {
+        let col_name = key.to_string();
+        let dtype_str = value.string().ok_or_else(|| {
+            PolarsException::new(format!(
+                "Data type for column '{}' must be a string",
+                col_name
+            ))
+        })?;
+        let target_type = parse_dtype(&dtype_str)?;
+        if strict {
+            exprs.push(col(&col_name).strict_cast(target_type));
+        } else {
+            exprs.push(col(&col_name).cast(target_type));
+        }
+    }
+    Ok(exprs)
+}
+
+/// Build unpivot arguments, shared by `DataFrame::unpivot()` and `LazyFrame::unpivot()`
+pub fn unpivot_args(
+    on: Vec<String>,
+    index: Vec<String>,
+    variable_name: Option<String>,
+    value_name: Option<String>,
+) -> UnpivotArgsDSL {
+    let on_names: Vec<PlSmallStr> = on.into_iter().map(PlSmallStr::from).collect();
+    let index_names: Vec<PlSmallStr> = index.into_iter().map(PlSmallStr::from).collect();
+    UnpivotArgsDSL {
+        on: Selector::ByName {
+            names: on_names.into(),
+            strict: false,
+        },
+        index: Selector::ByName {
+            names: index_names.into(),
+            strict: false,
+        },
+        variable_name: variable_name.map(PlSmallStr::from),
+        value_name: value_name.map(PlSmallStr::from),
+    }
+}
+
+/// Apply a pivot aggregate function to an expression
+pub fn pivot_aggregation(expr: Expr, function: &str) -> ExtResult<Expr> {
+    match function {
+        "first" => Ok(expr.first()),
+        "last" => Ok(expr.last()),
+        "sum" => Ok(expr.sum()),
+        "mean" => Ok(expr.mean()),
+        "median" => Ok(expr.median()),
+        "min" => Ok(expr.min()),
+        "max" => Ok(expr.max()),
+        "count" => Ok(expr.count()),
+        "len" => Ok(expr.len()),
+        _ => Err(PolarsException::new(format!(
+            "Invalid aggregate function: '{}'. Use 'first', 'last', 'sum', 'mean', 'median', 'min', 'max', 'count', or 'len'",
+            function
+        ))),
+    }
+}
+
+/// PHP callable stored inside a Polars plan. Polars may call or drop the plan on its own
+/// worker threads, so the callable only runs on the thread that created it.
+pub struct PhpCallable {
+    function: ManuallyDrop<Zval>,
+    name: &'static str,
+    thread: ThreadId,
+}
+
+// SAFETY: the PHP value is only called or released on the thread that created it. `call`
+// fails on any other thread and `drop` leaks the value there instead of releasing it.
+unsafe impl Send for PhpCallable {}
+unsafe impl Sync for PhpCallable {}
+
+impl PhpCallable {
+    /// Wrap a callable, `name` describes it in error messages
+    pub fn new(function: &Zval, name: &'static str) -> Self {
+        Self {
+            function: ManuallyDrop::new(function.shallow_clone()),
+            name,
+            thread: std::thread::current().id(),
+        }
+    }
+
+    /// Call the function, failing when not on the thread that created it
+    pub fn call(&self, params: Vec<&dyn IntoZvalDyn>) -> PolarsResult<Zval> {
+        if std::thread::current().id() != self.thread {
+            polars_bail!(ComputeError: "{} can only be called on the PHP thread that created it", self.name);
+        }
+        let function = ZendCallable::new(&self.function)
+            .map_err(|e| polars_err!(ComputeError: "{} is not callable: {}", self.name, e))?;
+        function
+            .try_call(params)
+            .map_err(|e| polars_err!(ComputeError: "{} failed: {}", self.name, e))
+    }
+}
+
+impl Drop for PhpCallable {
+    fn drop(&mut self) {
+        if std::thread::current().id() == self.thread {
+            // SAFETY: the value is not used after this point
+            unsafe { ManuallyDrop::drop(&mut self.function) };
+        }
+    }
+}
@@ -1,20 +1,27 @@
 #![allow(non_snake_case)]
 
-use crate::common::{any_value_to_zval, extract_exprs, parse_dtype};
+use crate::common::{
+    any_value_to_zval, cast_exprs, extract_exprs, join_asof_lazy_frames, join_lazy_frames,
+    pivot_aggregation, sort_options, unpivot_args, zval_to_column_exprs, zval_to_names,
+    zval_to_row_count, zval_to_seed,
+};
 use crate::data_type::PolarsDataType;
 use crate::exception::{ExtResult, PolarsException};
-use crate::expression::PolarsExpr;
-use crate::lazy_group_by::PhpLazyGroupBy;
+use crate::expression::{
+    PolarsClosedInterval, PolarsExpr, correlation, parse_correlation_method,
+};
+use crate::group_by::PhpGroupBy;
+use crate::lazy_group_by::{PhpLazyGroupBy, group_by_dynamic, group_by_rolling};
 use crate::series::PhpSeries;
 use ext_php_rs::flags::DataType as PhpDataType;
 use ext_php_rs::prelude::*;
 use ext_php_rs::types::{ArrayKey, ZendHashTable, Zval};
 use ext_php_rs::zend::ce;
-use polars::lazy::dsl::{all, col, lit, Expr};
+use polars::lazy::dsl::{all, col, lit, Expr, FunctionExpr};
 use polars::prelude::{
-    Column, CsvParseOptions, CsvReadOptions, CsvWriter, DataFrame, IntoLazy, IntoSeries, JoinArgs,
-    JoinCoalesce, JoinType, JoinValidation, JsonFormat, JsonReader, JsonWriter, OptFlags,
-    ParquetWriter, PlSmallStr, QuantileMethod, Selector, SerReader, SerWriter, SortMultipleOptions,
+    Column, CsvParseOptions, CsvReadOptions, CsvWriter, DataFrame, DataType, IntoLazy, IntoSeries,
+    JsonFormat, JsonReader, JsonWriter, OptFlags,
+    ParquetWriter, PlSmallStr, QuantileMethod, Selector, SerReader, SerWriter,
     UniqueKeepStrategy,
 };
 use std::collections::HashMap;
@@ -384,11 +391,86 @@ impl PhpDataFrame {
         Ok(Self { inner })
     }
 
-    // /// Get the maximum value horizontally across columns.
-    // pub fn max_horizontal(&self) -> ExtResult<Self> {
-    //     //use select https://github.com/pola-rs/polars/blob/py-1.35.2/py-polars/src/polars/dataframe/frame.py#L10422
-    //     todo!()
-    // }
+    /// Get the maximum value horizontally across columns.
+    /// @return \Polars\Series
+    #[php(name = "maxHorizontal")]
+    pub fn max_horizontal(&self) -> ExtResult<PhpSeries> {
+        self.horizontal(FunctionExpr::MaxHorizontal, "max")
+    }
+
+    /// Get the minimum value horizontally across columns.
+    /// @return \Polars\Series
+    #[php(name = "minHorizontal")]
+    pub fn min_horizontal(&self) -> ExtResult<PhpSeries> {
+        self.horizontal(FunctionExpr::MinHorizontal, "min")
+    }
+
+    /// Sum all values horizontally across columns.
+    /// @return \Polars\Series
+    #[php(name = "sumHorizontal", defaults(ignoreNulls = true))]
+    pub fn sum_horizontal(&self, ignoreNulls: bool) -> ExtResult<PhpSeries> {
+        let function = FunctionExpr::SumHorizontal {
+            ignore_nulls: ignoreNulls,
+        };
+        self.horizontal(function, "sum")
+    }
+
+    /// Take the mean of all values horizontally across columns.
+    /// @return \Polars\Series
+    #[php(name = "meanHorizontal", defaults(ignoreNulls = true))]
+    pub fn mean_horizontal(&self, ignoreNulls: bool) -> ExtResult<PhpSeries> {
+        let function = FunctionExpr::MeanHorizontal {
+            ignore_nulls: ignoreNulls,
+        };
+        self.horizontal(function, "mean")
+    }
+
+    /// Compute the pairwise correlation between all columns.
+    /// The result has one row and one column per input column, in the same order
+    /// @param string $method One of: 'pearson', 'spearman'
+    /// @throws Polars\Exception
+    #[php(defaults(method = "pearson".to_string(), propagateNans = false))]
+    pub fn corr(&self, method: String, propagateNans: bool) -> ExtResult<Self> {
+        let method = parse_correlation_method(&method, propagateNans)?;
+        let names: Vec<PlSmallStr> = self.inner.get_column_names_owned();
+        for column in self.inner.get_columns() {
+            if !column.dtype().is_primitive_numeric() {
+                return Err(PolarsException::new(format!(
+                    "Cannot compute correlation: column '{}' is not numeric ({})",
+                    column.name(),
+                    column.dtype()
+                )));
+            }
+        }
+        let mut exprs = Vec::with_capacity(names.len() * names.len());
+        for (i, a) in names.iter().enumerate() {
+            for (j, b) in names.iter().enumerate() {
+                exprs.push(
+                    correlation(col(a.clone()), col(b.clone()), method)
+                        .cast(DataType::Float64)
+                        .alias(format!("{}_{}", i, j)),
+                );
+            }
+        }
+        let pairs = self._do_select(exprs)?.inner;
+        let mut columns = Vec::with_capacity(names.len());
+        for (j, name) in names.iter().enumerate() {
+            let mut values = Vec::with_capacity(names.len());
+            for i in 0..names.len() {
+                let value = pairs
+                    .column(&format!("{}_{}", i, j))
+                    .and_then(|c| c.get(0))
+                    .map_err(|e| {
+                        PolarsException::new(format!("Cannot compute correlation: {}", e))
+                    })?;
+                values.push(value.extract::<f64>());
+            }
+            columns.push(Column::new(name.clone(), values));
+        }
+        let inner = DataFrame::new(columns)
+            .map_err(|e| PolarsException::new(format!("Cannot compute correlation: {}", e)))?;
+        Ok(Self { inner })
+    }
 
     /// Aggregate the columns of this DataFrame to their mean value.
     pub fn mean(&self) -> ExtResult<Self> {
@@ -659,54 +741,29 @@ impl PhpDataFrame {
         Ok(())
     }
 
-    /// Sort DataFrame by one or more columns
-    /// @param string|string[] $by Column name or array of column names to sort by
-    /// @param bool $descending Sort order (applies to all columns)
-    /// @param bool $nullsLast Put null values last
+    /// Sort DataFrame by one or more columns or expressions
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to sort by
+    /// @param bool|bool[]|null $descending Sort in descending order, one flag per key or a single flag for all
+    /// @param bool|bool[]|null $nullsLast Put null values last, one flag per key or a single flag for all (default: true)
     /// @param bool $maintainOrder Maintain order of equal elements (stable sort)
     /// @param bool $multithreaded Use multithreaded sorting
-    #[php(defaults(
-        descending = false,
-        nullsLast = true,
-        maintainOrder = false,
-        multithreaded = true
-    ))]
+    /// @throws Polars\Exception
+    #[php(defaults(maintainOrder = false, multithreaded = true))]
     pub fn sort(
         &self,
         by: &Zval,
-        descending: bool,
-        nullsLast: bool,
+        descending: Option<&Zval>,
+        nullsLast: Option<&Zval>,
         maintainOrder: bool,
         multithreaded: bool,
     ) -> ExtResult<Self> {
-        let columns: Vec<PlSmallStr> = if let Some(s) = by.str() {
-            vec![PlSmallStr::from(s)]
-        } else if let Some(arr) = by.array() {
-            let mut cols = Vec::new();
-            for (_, val) in arr.iter() {
-                let s = val.str().ok_or_else(|| {
-                    PolarsException::new("sort 'by' array must contain strings".to_string())
-                })?;
-                cols.push(PlSmallStr::from(s));
-            }
-            cols
-        } else {
-            return Err(PolarsException::new(
-                "sort 'by' must be a string or array of strings".to_string(),
-            ));
-        };
-
-        let opts = SortMultipleOptions::new()
-            .with_order_descending(descending)
-            .with_nulls_last(nullsLast)
-            .with_maintain_order(maintainOrder)
-            .with_multithreaded(multithreaded);
-
+        let by = zval_to_column_exprs(by)?;
+        let opts = sort_options(descending, nullsLast, by.len(), maintainOrder, multithreaded)?;
         let inner = self
             .inner
             .clone()
             .lazy()
-            .sort(columns, opts)
+            .sort_by_exprs(by, opts)
             .with_optimizations(OptFlags::EAGER)
             .collect()
             .map_err(|e| PolarsException::new(format!("Sort failed: {}", e)))?;
@@ -777,13 +834,95 @@ impl PhpDataFrame {
         Ok(Self { inner })
     }
 
-    /// Group by expressions
+    /// Group by expressions. Aggregations on the returned GroupBy are executed eagerly
+    /// and iterating over it yields every group as a DataFrame.
     /// @param \Polars\Expr[] $expressions
-    /// @return \Polars\LazyGroupBy
-    #[php(name = "groupBy")]
-    pub fn group_by(&self, expressions: &ZendHashTable) -> ExtResult<PhpLazyGroupBy> {
+    /// @param bool $maintainOrder Keep groups in the order of their first appearance
+    /// @return \Polars\GroupBy
+    #[php(name = "groupBy", defaults(maintainOrder = false))]
+    pub fn group_by(
+        &self,
+        expressions: &ZendHashTable,
+        maintainOrder: bool,
+    ) -> ExtResult<PhpGroupBy> {
         let exprs = extract_exprs(expressions)?;
-        Ok(PhpLazyGroupBy::new(self.inner.clone().lazy(), exprs))
+        Ok(PhpGroupBy::new(self.inner.clone(), exprs, maintainOrder))
+    }
+
+    /// Group by time windows of the index column, optionally per group of `groupBy` keys.
+    /// The index column must be sorted (within each group)
+    /// @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+    /// @param string $every Interval between the starts of consecutive windows, e.g. '15m' or '2i'
+    /// @param string|null $period Length of each window (default: $every)
+    /// @param string|null $offset Offset of the window starts (default: zero)
+    /// @param bool $includeBoundaries Add '_lower_boundary' and '_upper_boundary' columns
+    /// @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+    /// @param string $label Window label in the index column: 'left', 'right', 'datapoint'
+    /// @param string $startBy Start of the first window: 'window', 'datapoint' or a day of the week such as 'monday'
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+    /// @return \Polars\LazyGroupBy
+    /// @throws Polars\Exception
+    #[php(name = "groupByDynamic")]
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(
+        includeBoundaries = false,
+        closed = PolarsClosedInterval::Left,
+        label = "left".to_string(),
+        startBy = "window".to_string()
+    ))]
+    pub fn group_by_dynamic(
+        &self,
+        indexColumn: &Zval,
+        every: String,
+        period: Option<String>,
+        offset: Option<String>,
+        includeBoundaries: bool,
+        closed: PolarsClosedInterval,
+        label: String,
+        startBy: String,
+        groupBy: Option<&Zval>,
+    ) -> ExtResult<PhpLazyGroupBy> {
+        group_by_dynamic(
+            self.inner.clone().lazy(),
+            indexColumn,
+            &every,
+            period,
+            offset,
+            includeBoundaries,
+            closed,
+            &label,
+            &startBy,
+            groupBy,
+        )
+    }
+
+    /// Group by lookback windows anchored on every row of the index column, optionally per group of `groupBy` keys.
+    /// The window of a row with index value `t` is `(t + offset, t + offset + period]` by default.
+    /// The index column must be sorted (within each group)
+    /// @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+    /// @param string $period Length of each window, e.g. '30d' or '3i'
+    /// @param string|null $offset Offset of the window starts (default: -$period)
+    /// @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+    /// @return \Polars\LazyGroupBy
+    /// @throws Polars\Exception
+    #[php(defaults(closed = PolarsClosedInterval::Right))]
+    pub fn rolling(
+        &self,
+        indexColumn: &Zval,
+        period: String,
+        offset: Option<String>,
+        closed: PolarsClosedInterval,
+        groupBy: Option<&Zval>,
+    ) -> ExtResult<PhpLazyGroupBy> {
+        group_by_rolling(
+            self.inner.clone().lazy(),
+            indexColumn,
+            &period,
+            offset,
+            closed,
+            groupBy,
+        )
     }
 
     /// Aggregate the columns to their sum value.
@@ -989,14 +1128,25 @@ impl PhpDataFrame {
     /// Join with another DataFrame
     /// @param \Polars\DataFrame $other The right DataFrame
     /// @param \Polars\Expr[] $on Join columns (used for both left and right when leftOn/rightOn not given)
-    /// @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross'
+    /// @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross', 'semi', 'anti'
     /// @param \Polars\Expr[]|null $leftOn Left join columns (overrides $on for the left side)
     /// @param \Polars\Expr[]|null $rightOn Right join columns (overrides $on for the right side)
     /// @param string|null $suffix Suffix for duplicate column names (default: '_right')
     /// @param string|null $validate Join validation: 'm:m', 'm:1', '1:m', '1:1'
     /// @param bool|null $coalesce Coalesce join columns
+    /// @param bool $nullsEqual Match null keys with each other
+    /// @param string $maintainOrder Row order to preserve: 'none', 'left', 'right', 'left_right', 'right_left'
+    /// @param bool $allowParallel Allow evaluating both sides in parallel
+    /// @param bool $forceParallel Force evaluating both sides in parallel
+    /// @throws Polars\Exception
     #[allow(clippy::too_many_arguments)]
-    #[php(defaults(how = "inner".to_string()))]
+    #[php(defaults(
+        how = "inner".to_string(),
+        nullsEqual = false,
+        maintainOrder = "none".to_string(),
+        allowParallel = true,
+        forceParallel = false
+    ))]
     pub fn join(
         &self,
         other: &PhpDataFrame,
@@ -1007,76 +1157,29 @@ impl PhpDataFrame {
         suffix: Option<String>,
         validate: Option<String>,
         coalesce: Option<bool>,
+        nullsEqual: bool,
+        maintainOrder: String,
+        allowParallel: bool,
+        forceParallel: bool,
     ) -> ExtResult<Self> {
-        let join_type = match how.as_str() {
-            "inner" => JoinType::Inner,
-            "left" => JoinType::Left,
-            "right" => JoinType::Right,
-            "full" => JoinType::Full,
-            "cross" => JoinType::Cross,
-            _ => {
-                return Err(PolarsException::new(format!(
-                    "Invalid join type: {}. Use 'inner', 'left', 'right', 'full', or 'cross'",
-                    how
-                )));
-            }
-        };
-
-        let (left_exprs, right_exprs) = match (leftOn, rightOn) {
-            (Some(left), Some(right)) => (extract_exprs(left)?, extract_exprs(right)?),
-            (None, None) => {
-                let exprs = extract_exprs(on)?;
-                (exprs.clone(), exprs)
-            }
-            _ => {
-                return Err(PolarsException::new(
-                    "Both leftOn and rightOn must be provided together".to_string(),
-                ));
-            }
-        };
-
-        let mut join_args = JoinArgs::new(join_type);
-
-        if let Some(s) = suffix {
-            join_args = join_args.with_suffix(Some(PlSmallStr::from(s)));
-        }
-
-        if let Some(v) = validate {
-            join_args.validation = match v.as_str() {
-                "m:m" => JoinValidation::ManyToMany,
-                "m:1" => JoinValidation::ManyToOne,
-                "1:m" => JoinValidation::OneToMany,
-                "1:1" => JoinValidation::OneToOne,
-                _ => {
-                    return Err(PolarsException::new(format!(
-                        "Invalid validation: {}. Use 'm:m', 'm:1', '1:m', or '1:1'",
-                        v
-                    )));
-                }
-            };
-        }
-
-        if let Some(c) = coalesce {
-            join_args = join_args.with_coalesce(if c {
-                JoinCoalesce::CoalesceColumns
-            } else {
-                JoinCoalesce::KeepColumns
-            });
-        }
-
-        let inner = self
-            .inner
-            .clone()
-            .lazy()
-            .join(
-                other.inner.clone().lazy(),
-                &left_exprs,
-                &right_exprs,
-                join_args,
-            )
-            .with_optimizations(OptFlags::EAGER)
-            .collect()
-            .map_err(|e| PolarsException::new(format!("Join failed: {}", e)))?;
+        let inner = join_lazy_frames(
+            self.inner.clone().lazy(),
+            other.inner.clone().lazy(),
+            on,
+            &how,
+            leftOn,
+            rightOn,
+            suffix,
+            validate,
+            coalesce,
+            nullsEqual,
+            &maintainOrder,
+            allowParallel,
+            forceParallel,
+        )?
+        .with_optimizations(OptFlags::EAGER)
+        .collect()
+        .map_err(|e| PolarsException::new(format!("Join failed: {}", e)))?;
         Ok(Self { inner })
     }
 
@@ -1275,26 +1378,7 @@ impl PhpDataFrame {
     /// @param array $dtypes Associative array of column name => data type string
     #[php(defaults(strict = false))]
     pub fn cast(&self, dtypes: &ZendHashTable, strict: bool) -> ExtResult<Self> {
-        let mut exprs: Vec<Expr> = Vec::new();
-        for (key, value) in dtypes.iter() {
-            let col_name = match key {
-                ArrayKey::String(s) => s,
-                ArrayKey::Str(s) => s.to_string(),
-                ArrayKey::Long(i) => i.to_string(),
-            };
-            let dtype_str = value.string().ok_or_else(|| {
-                PolarsException::new(format!(
-                    "Data type for column '{}' must be a string",
-                    col_name
-                ))
-            })?;
-            let target_type = parse_dtype(&dtype_str)?;
-            if strict {
-                exprs.push(col(&col_name).strict_cast(target_type));
-            } else {
-                exprs.push(col(&col_name).cast(target_type));
-            }
-        }
+        let exprs = cast_exprs(dtypes, strict)?;
         let inner = self
             .inner
             .clone()
@@ -1318,21 +1402,7 @@ impl PhpDataFrame {
         variableName: Option<String>,
         valueName: Option<String>,
     ) -> ExtResult<Self> {
-        use polars::prelude::UnpivotArgsDSL;
-        let on_names: Vec<PlSmallStr> = on.into_iter().map(PlSmallStr::from).collect();
-        let index_names: Vec<PlSmallStr> = index.into_iter().map(PlSmallStr::from).collect();
-        let args = UnpivotArgsDSL {
-            on: Selector::ByName {
-                names: on_names.into(),
-                strict: false,
-            },
-            index: Selector::ByName {
-                names: index_names.into(),
-                strict: false,
-            },
-            variable_name: variableName.map(PlSmallStr::from),
-            value_name: valueName.map(PlSmallStr::from),
-        };
+        let args = unpivot_args(on, index, variableName, valueName);
         let inner = self
             .inner
             .clone()
@@ -1486,7 +1556,7 @@ impl PhpDataFrame {
         seed: Option<i64>,
     ) -> ExtResult<Self> {
         use polars::prelude::{IdxSize, NamedFrom, Series};
-        let seed = seed.map(|s| s as u64);
+        let seed = seed.map(zval_to_seed).transpose()?;
         let inner = if let Some(frac) = fraction {
             let frac_series = Series::new("frac".into(), &[frac]);
             self.inner
@@ -1530,36 +1600,40 @@ impl PhpDataFrame {
         Ok(Self { inner })
     }
 
-    /// Get the top k rows by a column
+    /// Get the k rows with the largest values of the `by` column(s)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+    /// @param bool|bool[]|null $reverse Take the smallest values instead, one flag per key or a single flag for all
+    /// @throws Polars\Exception
     #[php(name = "topK")]
-    pub fn top_k(&self, k: i64, by: String) -> ExtResult<Self> {
-        let opts = SortMultipleOptions::new()
-            .with_order_descending(true)
-            .with_nulls_last(true);
+    pub fn top_k(&self, k: i64, by: &Zval, reverse: Option<&Zval>) -> ExtResult<Self> {
+        let k = zval_to_row_count(k)?;
+        let by = zval_to_column_exprs(by)?;
+        let opts = sort_options(reverse, None, by.len(), false, true)?;
         let inner = self
             .inner
             .clone()
             .lazy()
-            .sort([&by], opts)
-            .limit(k as u32)
+            .top_k(k, by, opts)
             .with_optimizations(OptFlags::EAGER)
             .collect()
             .map_err(|e| PolarsException::new(format!("topK failed: {}", e)))?;
         Ok(Self { inner })
     }
 
-    /// Get the bottom k rows by a column
+    /// Get the k rows with the smallest values of the `by` column(s)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+    /// @param bool|bool[]|null $reverse Take the largest values instead, one flag per key or a single flag for all
+    /// @throws Polars\Exception
     #[php(name = "bottomK")]
-    pub fn bottom_k(&self, k: i64, by: String) -> ExtResult<Self> {
-        let opts = SortMultipleOptions::new()
-            .with_order_descending(false)
-            .with_nulls_last(true);
+    pub fn bottom_k(&self, k: i64, by: &Zval, reverse: Option<&Zval>) -> ExtResult<Self> {
+        let k = zval_to_row_count(k)?;
+        let by = zval_to_column_exprs(by)?;
+        let opts = sort_options(reverse, None, by.len(), false, true)?;
         let inner = self
             .inner
             .clone()
             .lazy()
-            .sort([&by], opts)
-            .limit(k as u32)
+            .bottom_k(k, by, opts)
             .with_optimizations(OptFlags::EAGER)
             .collect()
             .map_err(|e| PolarsException::new(format!("bottomK failed: {}", e)))?;
@@ -1668,25 +1742,30 @@ impl PhpDataFrame {
         self.with_row_index(name, offset)
     }
 
-    /// Set the sorted flag on a column
+    /// Set the sorted flag on one or more columns
+    /// @param string|string[] $column Column name(s)
+    /// @param bool $descending Whether the columns are sorted in descending order
+    /// @throws Polars\Exception
     #[php(name = "setSorted")]
     #[php(defaults(descending = false))]
-    pub fn set_sorted(&mut self, column: String, descending: bool) -> ExtResult<()> {
+    pub fn set_sorted(&mut self, column: &Zval, descending: bool) -> ExtResult<()> {
         use polars::series::IsSorted;
         let sorted = if descending {
             IsSorted::Descending
         } else {
             IsSorted::Ascending
         };
-        let idx = self
-            .inner
-            .get_column_index(&column)
-            .ok_or_else(|| PolarsException::new(format!("Column '{}' not found", column)))?;
-        let mut col = self.inner.get_columns()[idx].clone();
-        col.set_sorted_flag(sorted);
-        self.inner
-            .replace_column(idx, col)
-            .map_err(|e| PolarsException::new(format!("setSorted failed: {}", e)))?;
+        for column in zval_to_names(column, "column")? {
+            let idx = self
+                .inner
+                .get_column_index(&column)
+                .ok_or_else(|| PolarsException::new(format!("Column '{}' not found", column)))?;
+            let mut col = self.inner.get_columns()[idx].clone();
+            col.set_sorted_flag(sorted);
+            self.inner
+                .replace_column(idx, col)
+                .map_err(|e| PolarsException::new(format!("setSorted failed: {}", e)))?;
+        }
         Ok(())
     }
 
@@ -1843,24 +1922,9 @@ impl PhpDataFrame {
         aggregateFunction: Option<String>,
         sortColumns: bool,
     ) -> ExtResult<Self> {
-        let agg_expr = match aggregateFunction.as_deref() {
-            Some("first") => Some(col("").first()),
-            Some("last") => Some(col("").last()),
-            Some("sum") => Some(col("").sum()),
-            Some("mean") => Some(col("").mean()),
-            Some("median") => Some(col("").median()),
-            Some("min") => Some(col("").min()),
-            Some("max") => Some(col("").max()),
-            Some("count") => Some(col("").count()),
-            Some("len") => Some(col("").len()),
-            None => None,
-            Some(other) => {
-                return Err(PolarsException::new(format!(
-                    "Invalid aggregate function: '{}'. Use 'first', 'last', 'sum', 'mean', 'median', 'min', 'max', 'count', or 'len'",
-                    other
-                )));
-            }
-        };
+        let agg_expr = aggregateFunction
+            .map(|function| pivot_aggregation(col(""), &function))
+            .transpose()?;
         let inner = polars::lazy::frame::pivot::pivot_stable(
             &self.inner,
             on,
@@ -1886,64 +1950,54 @@ impl PhpDataFrame {
 
     /// Perform an asof join with another DataFrame
     /// @param \Polars\DataFrame $other The right DataFrame
-    /// @param string $on Column to join on (must be sorted)
-    /// @param string|null $leftBy Group by column for left DataFrame
-    /// @param string|null $rightBy Group by column for right DataFrame
-    /// @param string|null $tolerance Tolerance for the asof join (time duration string e.g. "5m")
-    /// @param string $strategy Join strategy: 'backward', 'forward', 'nearest'
+    /// @param string|null $on Key column of both sides (must be sorted)
+    /// @param string|null $strategy Join strategy: 'backward', 'forward', 'nearest'
+    /// @param string|string[]|null $leftBy Group by columns of the left side
+    /// @param string|string[]|null $rightBy Group by columns of the right side
+    /// @param int|float|string|null $tolerance Maximum key distance, as a number or a duration string (e.g. "5m")
+    /// @param string|string[]|null $by Group by columns of both sides
+    /// @param string|null $leftOn Key column of the left side
+    /// @param string|null $rightOn Key column of the right side
+    /// @param string|null $suffix Suffix for duplicate column names (default: '_right')
+    /// @param bool $allowExactMatches Allow matching equal keys
+    /// @param bool $checkSortedness Check that the keys are sorted
+    /// @throws Polars\Exception
     #[php(name = "joinAsof")]
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(allowExactMatches = true, checkSortedness = true))]
     pub fn join_asof(
         &self,
         other: &PhpDataFrame,
-        on: String,
+        on: Option<String>,
         strategy: Option<String>,
-        leftBy: Option<String>,
-        rightBy: Option<String>,
-        tolerance: Option<String>,
+        leftBy: Option<&Zval>,
+        rightBy: Option<&Zval>,
+        tolerance: Option<&Zval>,
+        by: Option<&Zval>,
+        leftOn: Option<String>,
+        rightOn: Option<String>,
+        suffix: Option<String>,
+        allowExactMatches: bool,
+        checkSortedness: bool,
     ) -> ExtResult<Self> {
-        use polars::prelude::{AsOfOptions, AsofStrategy};
-
-        let strategy_str = strategy.as_deref().unwrap_or("backward");
-        let asof_strategy = match strategy_str {
-            "backward" => AsofStrategy::Backward,
-            "forward" => AsofStrategy::Forward,
-            "nearest" => AsofStrategy::Nearest,
-            _ => {
-                return Err(PolarsException::new(format!(
-                    "Invalid asof strategy: {}. Use 'backward', 'forward', or 'nearest'",
-                    strategy_str
-                )));
-            }
-        };
-
-        let options = AsOfOptions {
-            strategy: asof_strategy,
-            tolerance: None,
-            tolerance_str: tolerance.map(PlSmallStr::from),
-            left_by: leftBy.map(|s| vec![PlSmallStr::from(s)]),
-            right_by: rightBy.map(|s| vec![PlSmallStr::from(s)]),
-            allow_eq: true,
-            check_sortedness: true,
-        };
-
-        let on_expr = col(&on);
-        let ref_slice = std::slice::from_ref(&on_expr);
-        let inner = self
-            .inner
-            .clone()
-            .lazy()
-            .join(
-                other.inner.clone().lazy(),
-                ref_slice,
-                ref_slice,
-                JoinArgs {
-                    how: JoinType::AsOf(Box::new(options)),
-                    ..Default::default()
-                },
-            )
-            .with_optimizations(OptFlags::EAGER)
-            .collect()
-            .map_err(|e| PolarsException::new(format!("joinAsof failed: {}", e)))?;
+        let inner = join_asof_lazy_frames(
+            self.inner.clone().lazy(),
+            other.inner.clone().lazy(),
+            on,
+            strategy,
+            leftBy,
+            rightBy,
+            tolerance,
+            by,
+            leftOn,
+            rightOn,
+            suffix,
+            allowExactMatches,
+            checkSortedness,
+        )?
+        .with_optimizations(OptFlags::EAGER)
+        .collect()
+        .map_err(|e| PolarsException::new(format!("joinAsof failed: {}", e)))?;
         Ok(Self { inner })
     }
 
@@ -1979,6 +2033,17 @@ impl PhpDataFrame {
             Err(e) => Err(e.into()),
         }
     }
+
+    fn horizontal(&self, function: FunctionExpr, name: &str) -> ExtResult<PhpSeries> {
+        if self.inner.width() == 0 {
+            return Err(PolarsException::new(format!(
+                "Cannot compute horizontal {} of a DataFrame without columns",
+                name
+            )));
+        }
+        let expr = Expr::n_ary(function, vec![all().as_expr()]).alias(name);
+        self._do_select(vec![expr])?.to_series()
+    }
 }
 
 // impl Into<PhpDataFrame> for DataFrame {
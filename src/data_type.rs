@@ -15,6 +15,12 @@ impl PolarsDataType {
 
 }
 
+impl From<&PolarsDataType> for DataType {
+    fn from(dtype: &PolarsDataType) -> Self {
+        dtype.0.clone()
+    }
+}
+
 impl From<DataType> for PolarsDataType {
     fn from(dtype: DataType) -> Self {
         PolarsDataType(dtype)
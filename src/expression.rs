@@ -1,13 +1,35 @@
 #![allow(non_snake_case)]
 
+use crate::common::{
+    zval_to_bools, zval_to_column_expr, zval_to_column_exprs, zval_to_dtype, zval_to_length,
+    zval_to_seed,
+};
 use crate::exception::{ExtResult, PolarsException};
+use crate::expression_name::PolarsExprNameNameSpace;
+use crate::series::{PhpSeries, zval_vec_to_series};
 use ext_php_rs::flags::DataType;
-use ext_php_rs::types::{ZendObject, Zval};
+use ext_php_rs::types::{ArrayKey, ZendCallable, ZendHashTable, ZendObject, Zval};
 use ext_php_rs::{php_class, php_enum, php_impl};
-use polars::lazy::dsl::{Expr, all, col, cols, lit};
+use polars::error::polars_bail;
+use polars::lazy::dsl::{
+    Expr, FunctionExpr, WindowMapping, all, all_horizontal, any_horizontal, arg_where, coalesce,
+    col, cols, concat_list, concat_str, cov, date_range, datetime_range, element, int_range,
+    int_ranges, len, lit, pearson_corr, repeat, spearman_rank_corr, time_range,
+};
 use polars::prelude::ClosedInterval;
+use polars::prelude::CorrelationMethod;
+use polars::prelude::DataType as PolarsDataType;
 use polars::prelude::QuantileMethod;
-use polars::prelude::{Literal, NULL};
+use polars::prelude::{
+    ChunkApply, Column, FillNullStrategy, IdxSize, InterpolationMethod, IntoColumn, IntoSeries,
+    NamedFrom, PlSmallStr, PolarsResult, Series, TimeUnit, TimeZone,
+};
+use polars::prelude::{
+    ClosedWindow, Duration, EWMOptions, Literal, NULL, RankMethod, RankOptions, RollingFnParams,
+    RollingOptionsDynamicWindow, RollingOptionsFixedWindow, RollingVarParams, RoundMode,
+    SortMultipleOptions, SortOptions,
+};
+use polars::series::ops::NullBehavior;
 use std::ops::{Add, Div, Neg};
 
 #[php_class]
@@ -31,6 +53,308 @@ impl PolarsExpr {
         Self(cols(names).as_expr())
     }
 
+    /// Alias for an element being evaluated in an eval expression (e.g. `cumulativeEval`)
+    pub fn element() -> Self {
+        Self(element())
+    }
+
+    // CONSTRUCTORS //
+
+    /// Create a literal expression, optionally cast to a data type
+    /// @param mixed $value int, float, string, bool, null or Expr
+    /// @param string|\Polars\DataType|null $dtype Data type of the literal
+    /// @throws Polars\Exception
+    pub fn lit(value: &Zval, dtype: Option<&Zval>) -> ExtResult<Self> {
+        let expr = zval_to_expr(value)?;
+        Ok(match optional_zval_to_dtype(dtype)? {
+            Some(dtype) => expr.strict_cast(dtype),
+            None => expr,
+        }
+        .into())
+    }
+
+    /// Generate a range of integers. When `end` is not given, the range ends at the number of rows
+    /// @param int|\Polars\Expr $start Start of the range (inclusive)
+    /// @param int|\Polars\Expr|null $end End of the range (exclusive)
+    /// @param string|\Polars\DataType|null $dtype Integer data type of the result, int64 by default
+    /// @throws Polars\Exception
+    #[php(name = "intRange", defaults(step = 1))]
+    pub fn int_range(
+        start: Option<&Zval>,
+        end: Option<&Zval>,
+        step: i64,
+        dtype: Option<&Zval>,
+    ) -> ExtResult<Self> {
+        let start = optional_zval_to_expr(start)?.unwrap_or_else(|| lit(0));
+        let end = optional_zval_to_expr(end)?.unwrap_or_else(len);
+        let dtype = optional_zval_to_dtype(dtype)?.unwrap_or(PolarsDataType::Int64);
+        Ok(int_range(start, end, step, dtype).into())
+    }
+
+    /// Generate a list of integers for every row, from `start` to `end` (exclusive)
+    /// @param int|string|\Polars\Expr $start Start value or column
+    /// @param int|string|\Polars\Expr $end End value or column
+    /// @param int|string|\Polars\Expr $step Step value or column
+    /// @param string|\Polars\DataType|null $dtype Integer data type of the list elements, int64 by default
+    /// @throws Polars\Exception
+    #[php(name = "intRanges")]
+    pub fn int_ranges(
+        start: &Zval,
+        end: &Zval,
+        step: Option<&Zval>,
+        dtype: Option<&Zval>,
+    ) -> ExtResult<Self> {
+        let start = zval_to_column_or_value_expr(start)?;
+        let end = zval_to_column_or_value_expr(end)?;
+        let step = match step {
+            Some(step) if !step.is_null() => zval_to_column_or_value_expr(step)?,
+            _ => lit(1),
+        };
+        let dtype = optional_zval_to_dtype(dtype)?.unwrap_or(PolarsDataType::Int64);
+        Ok(int_ranges(start, end, step, dtype).into())
+    }
+
+    /// Generate a range of dates
+    /// @param string|\Polars\Expr $start First date, e.g. '2024-01-01'
+    /// @param string|\Polars\Expr $end Last date, e.g. '2024-12-31'
+    /// @param string $interval Interval between dates, e.g. '1d', '1w' or '1mo'
+    /// @throws Polars\Exception
+    #[php(
+        name = "dateRange",
+        defaults(interval = "1d".to_string(), closed = PolarsClosedInterval::Both)
+    )]
+    pub fn date_range(
+        start: &Zval,
+        end: &Zval,
+        interval: String,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let start = zval_to_temporal_expr(start, PolarsDataType::Date)?;
+        let end = zval_to_temporal_expr(end, PolarsDataType::Date)?;
+        let interval = parse_duration(&interval)?;
+        Ok(date_range(start, end, interval, closed.into()).into())
+    }
+
+    /// Generate a range of datetimes
+    /// @param string|\Polars\Expr $start First datetime, e.g. '2024-01-01 00:00:00'
+    /// @param string|\Polars\Expr $end Last datetime, e.g. '2024-01-02 00:00:00'
+    /// @param string $interval Interval between datetimes, e.g. '1h' or '15m'
+    /// @param string|null $timeUnit One of: 'ns', 'us', 'ms'
+    /// @param string|null $timeZone Time zone of the result, e.g. 'Europe/Warsaw'
+    /// @throws Polars\Exception
+    #[php(
+        name = "datetimeRange",
+        defaults(interval = "1d".to_string(), closed = PolarsClosedInterval::Both)
+    )]
+    pub fn datetime_range(
+        start: &Zval,
+        end: &Zval,
+        interval: String,
+        closed: PolarsClosedInterval,
+        timeUnit: Option<String>,
+        timeZone: Option<String>,
+    ) -> ExtResult<Self> {
+        let time_unit = timeUnit.as_deref().map(parse_time_unit).transpose()?;
+        let dtype = PolarsDataType::Datetime(time_unit.unwrap_or(TimeUnit::Microseconds), None);
+        let start = zval_to_temporal_expr(start, dtype.clone())?;
+        let end = zval_to_temporal_expr(end, dtype)?;
+        let interval = parse_duration(&interval)?;
+        let time_zone = timeZone
+            .map(|tz| {
+                TimeZone::opt_try_new(Some(PlSmallStr::from(tz)))
+                    .map_err(|e| PolarsException::new(format!("Invalid time zone: {}", e)))
+            })
+            .transpose()?
+            .flatten();
+        Ok(datetime_range(start, end, interval, closed.into(), time_unit, time_zone).into())
+    }
+
+    /// Generate a range of times within a day
+    /// @param string|\Polars\Expr|null $start First time, e.g. '08:00', midnight by default
+    /// @param string|\Polars\Expr|null $end Last time, e.g. '17:30:00', end of the day by default
+    /// @param string $interval Interval between times, e.g. '1h' or '30m'
+    /// @throws Polars\Exception
+    #[php(
+        name = "timeRange",
+        defaults(interval = "1h".to_string(), closed = PolarsClosedInterval::Both)
+    )]
+    pub fn time_range(
+        start: Option<&Zval>,
+        end: Option<&Zval>,
+        interval: String,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let start = match start {
+            Some(start) if !start.is_null() => zval_to_temporal_expr(start, PolarsDataType::Time)?,
+            _ => lit(0i64).cast(PolarsDataType::Time),
+        };
+        let end = match end {
+            Some(end) if !end.is_null() => zval_to_temporal_expr(end, PolarsDataType::Time)?,
+            _ => lit(NANOSECONDS_PER_DAY - 1).cast(PolarsDataType::Time),
+        };
+        let interval = parse_duration(&interval)?;
+        Ok(time_range(start, end, interval, closed.into()).into())
+    }
+
+    /// Repeat a value `n` times. When `n` is not given, the value is repeated for every row
+    /// @param mixed $value Value or Expr to repeat
+    /// @param int|\Polars\Expr|null $n Number of repetitions
+    /// @throws Polars\Exception
+    pub fn repeat(value: &Zval, n: Option<&Zval>) -> ExtResult<Self> {
+        let value = zval_to_expr(value)?;
+        let n = optional_zval_to_expr(n)?.unwrap_or_else(len);
+        Ok(repeat(value, n).into())
+    }
+
+    /// Create a column of zeros. When `n` is not given, one zero is created for every row
+    /// @param int|\Polars\Expr|null $n Number of elements
+    /// @param string|\Polars\DataType|null $dtype Data type of the result, float64 by default
+    /// @throws Polars\Exception
+    pub fn zeros(n: Option<&Zval>, dtype: Option<&Zval>) -> ExtResult<Self> {
+        repeat_number(0, n, dtype, "zeros")
+    }
+
+    /// Create a column of ones. When `n` is not given, one is created for every row
+    /// @param int|\Polars\Expr|null $n Number of elements
+    /// @param string|\Polars\DataType|null $dtype Data type of the result, float64 by default
+    /// @throws Polars\Exception
+    pub fn ones(n: Option<&Zval>, dtype: Option<&Zval>) -> ExtResult<Self> {
+        repeat_number(1, n, dtype, "ones")
+    }
+
+    // HORIZONTAL //
+
+    /// Sum all values horizontally across columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to sum
+    /// @throws Polars\Exception
+    #[php(name = "sumHorizontal", defaults(ignoreNulls = true))]
+    pub fn sum_horizontal(exprs: &Zval, ignoreNulls: bool) -> ExtResult<Self> {
+        horizontal(exprs, |exprs| sum_horizontal(exprs, ignoreNulls))
+    }
+
+    /// Compute the mean of all values horizontally across columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to average
+    /// @throws Polars\Exception
+    #[php(name = "meanHorizontal", defaults(ignoreNulls = true))]
+    pub fn mean_horizontal(exprs: &Zval, ignoreNulls: bool) -> ExtResult<Self> {
+        horizontal(exprs, |exprs| mean_horizontal(exprs, ignoreNulls))
+    }
+
+    /// Get the minimum value horizontally across columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to compare
+    /// @throws Polars\Exception
+    #[php(name = "minHorizontal")]
+    pub fn min_horizontal(exprs: &Zval) -> ExtResult<Self> {
+        horizontal(exprs, min_horizontal)
+    }
+
+    /// Get the maximum value horizontally across columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to compare
+    /// @throws Polars\Exception
+    #[php(name = "maxHorizontal")]
+    pub fn max_horizontal(exprs: &Zval) -> ExtResult<Self> {
+        horizontal(exprs, max_horizontal)
+    }
+
+    /// Compute the bitwise OR horizontally across boolean columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Boolean columns
+    /// @throws Polars\Exception
+    #[php(name = "anyHorizontal")]
+    pub fn any_horizontal(exprs: &Zval) -> ExtResult<Self> {
+        horizontal(exprs, any_horizontal)
+    }
+
+    /// Compute the bitwise AND horizontally across boolean columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Boolean columns
+    /// @throws Polars\Exception
+    #[php(name = "allHorizontal")]
+    pub fn all_horizontal(exprs: &Zval) -> ExtResult<Self> {
+        horizontal(exprs, all_horizontal)
+    }
+
+    /// Take the first non-null value of the given columns, row by row
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns in order of preference
+    /// @throws Polars\Exception
+    pub fn coalesce(exprs: &Zval) -> ExtResult<Self> {
+        horizontal(exprs, |exprs| Ok(coalesce(&exprs)))
+    }
+
+    /// Horizontally concatenate columns into a single string column
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to concatenate
+    /// @throws Polars\Exception
+    #[php(name = "concatStr", defaults(separator = "".to_string(), ignoreNulls = false))]
+    pub fn concat_str(exprs: &Zval, separator: String, ignoreNulls: bool) -> ExtResult<Self> {
+        horizontal(exprs, |exprs| {
+            Ok(concat_str(exprs, &separator, ignoreNulls))
+        })
+    }
+
+    /// Horizontally concatenate columns into a single list column
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to concatenate
+    /// @throws Polars\Exception
+    #[php(name = "concatList")]
+    pub fn concat_list(exprs: &Zval) -> ExtResult<Self> {
+        horizontal(exprs, concat_list)
+    }
+
+    /// Accumulate over multiple columns horizontally, starting from `acc`.
+    /// The function is called with `(Expr $acc, Expr $column)` while the expression is built
+    /// and must return the new accumulator Expr
+    /// @param mixed $acc Initial value or Expr of the accumulator
+    /// @param callable $function Combines the accumulator with the next column
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to fold over
+    /// @throws Polars\Exception
+    pub fn fold(acc: &Zval, function: ZendCallable, exprs: &Zval) -> ExtResult<Self> {
+        let acc = zval_to_expr(acc)?;
+        let exprs = zval_to_column_exprs(exprs)?;
+        fold_with_callable(acc, &function, exprs).map(Self)
+    }
+
+    /// Accumulate over multiple columns horizontally, using the first column as initial accumulator.
+    /// The function is called with `(Expr $acc, Expr $column)` while the expression is built
+    /// and must return the new accumulator Expr
+    /// @param callable $function Combines the accumulator with the next column
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $exprs Columns to reduce
+    /// @throws Polars\Exception
+    pub fn reduce(function: ZendCallable, exprs: &Zval) -> ExtResult<Self> {
+        let mut exprs = zval_to_column_exprs(exprs)?.into_iter();
+        let acc = exprs.next().ok_or_else(|| {
+            PolarsException::new("reduce needs at least one expression".to_string())
+        })?;
+        fold_with_callable(acc, &function, exprs.collect()).map(Self)
+    }
+
+    // CORRELATION //
+
+    /// Compute the correlation between two columns
+    /// @param string|\Polars\Expr $a First column
+    /// @param string|\Polars\Expr $b Second column
+    /// @param string $method One of: 'pearson', 'spearman'
+    /// @param bool $propagateNans Make the spearman correlation NaN when any value is NaN
+    /// @throws Polars\Exception
+    #[php(defaults(method = "pearson".to_string(), propagateNans = false))]
+    pub fn corr(a: &Zval, b: &Zval, method: String, propagateNans: bool) -> ExtResult<Self> {
+        let method = parse_correlation_method(&method, propagateNans)?;
+        Ok(Self(correlation(
+            zval_to_column_expr(a)?,
+            zval_to_column_expr(b)?,
+            method,
+        )))
+    }
+
+    /// Compute the covariance between two columns
+    /// @param string|\Polars\Expr $a First column
+    /// @param string|\Polars\Expr $b Second column
+    /// @throws Polars\Exception
+    #[php(defaults(ddof = 1))]
+    pub fn cov(a: &Zval, b: &Zval, ddof: u8) -> ExtResult<Self> {
+        Ok(Self(cov(
+            zval_to_column_expr(a)?,
+            zval_to_column_expr(b)?,
+            ddof,
+        )))
+    }
+
     // AGGREGATIONS //
     pub fn all() -> Self {
         Self(all().as_expr())
@@ -112,6 +436,24 @@ impl PolarsExpr {
             .into())
     }
 
+    /// Alias of `quantile($quantile, QuantileMethod::Nearest)`. Polars has no sketch-based
+    /// quantile, so the result is the exact nearest-rank value, not an approximation
+    /// @throws Polars\Exception
+    #[php(name = "approxQuantile")]
+    pub fn approx_quantile(&self, quantile: f64) -> ExtResult<Self> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsException::new(format!(
+                "Invalid quantile: {}. Must be between 0 and 1",
+                quantile
+            )));
+        }
+        Ok(self
+            .0
+            .clone()
+            .quantile(lit(quantile), QuantileMethod::Nearest)
+            .into())
+    }
+
     #[php(defaults(ddof = 1))]
     pub fn std(&self, ddof: u8) -> Self {
         self.0.clone().std(ddof).into()
@@ -141,19 +483,40 @@ impl PolarsExpr {
         self.0.clone().arg_min().into()
     }
 
+    /// Bitwise AND of all values (aggregation), or element-wise with `other` when it is given
+    /// @param int|\Polars\Expr|null $other Expression or integer to combine with element-wise
+    /// @throws Polars\Exception
     #[php(name = "bitwiseAnd")]
-    pub fn bitwise_and(&self) -> Self {
-        self.0.clone().bitwise_and().into()
+    pub fn bitwise_and(&self, other: Option<&Zval>) -> ExtResult<Self> {
+        Ok(match optional_zval_to_expr(other)? {
+            Some(other) => self.0.clone().and(other),
+            None => self.0.clone().bitwise_and(),
+        }
+        .into())
     }
 
+    /// Bitwise OR of all values (aggregation), or element-wise with `other` when it is given
+    /// @param int|\Polars\Expr|null $other Expression or integer to combine with element-wise
+    /// @throws Polars\Exception
     #[php(name = "bitwiseOr")]
-    pub fn bitwise_or(&self) -> Self {
-        self.0.clone().bitwise_or().into()
+    pub fn bitwise_or(&self, other: Option<&Zval>) -> ExtResult<Self> {
+        Ok(match optional_zval_to_expr(other)? {
+            Some(other) => self.0.clone().or(other),
+            None => self.0.clone().bitwise_or(),
+        }
+        .into())
     }
 
+    /// Bitwise XOR of all values (aggregation), or element-wise with `other` when it is given
+    /// @param int|\Polars\Expr|null $other Expression or integer to combine with element-wise
+    /// @throws Polars\Exception
     #[php(name = "bitwiseXor")]
-    pub fn bitwise_xor(&self) -> Self {
-        self.0.clone().bitwise_xor().into()
+    pub fn bitwise_xor(&self, other: Option<&Zval>) -> ExtResult<Self> {
+        Ok(match optional_zval_to_expr(other)? {
+            Some(other) => self.0.clone().xor(other),
+            None => self.0.clone().bitwise_xor(),
+        }
+        .into())
     }
 
     pub fn implode(&self) -> Self {
@@ -298,189 +661,1537 @@ impl PolarsExpr {
         Ok(self.0.clone().or(other_expr).into())
     }
 
-    // BOOLEAN //
+    // BITWISE //
 
-    #[php(name = "hasNulls")]
-    pub fn has_nulls(&self) -> Self {
-        self.0.clone().null_count().gt(0).into()
+    /// Count the number of set bits of every element
+    #[php(name = "bitwiseCountOnes")]
+    pub fn bitwise_count_ones(&self) -> Self {
+        self.0.clone().bitwise_count_ones().into()
     }
 
-    /// Set an alias for the expression
-    pub fn alias(&self, name: String) -> Self {
-        self.0.clone().alias(&name).into()
+    /// Count the number of unset bits of every element
+    #[php(name = "bitwiseCountZeros")]
+    pub fn bitwise_count_zeros(&self) -> Self {
+        self.0.clone().bitwise_count_zeros().into()
     }
 
-    // /// Exclude column or columns
-    // pub fn exclude(&self, columns: Vec<String>) -> ExtResult<Self> {
-    //     self.0
-    //         .clone()
-    //         .into_selector()
-    //         .or_else(|| PolarsException::new("Failed to create selector".to_string()))?
-    //         .exclude_cols(&columns)
-    //         .into()
-    // }
+    /// Count the number of leading set bits of every element
+    #[php(name = "bitwiseLeadingOnes")]
+    pub fn bitwise_leading_ones(&self) -> Self {
+        self.0.clone().bitwise_leading_ones().into()
+    }
 
-    /// Shift values by n positions
-    pub fn shift(&self, n: &Zval) -> ExtResult<Self> {
-        let n_expr = zval_to_expr(n)?;
-        Ok(self.0.clone().shift(n_expr).into())
+    /// Count the number of leading unset bits of every element
+    #[php(name = "bitwiseLeadingZeros")]
+    pub fn bitwise_leading_zeros(&self) -> Self {
+        self.0.clone().bitwise_leading_zeros().into()
     }
 
-    /// Take every nth value
-    #[php(name = "gatherEvery", defaults(offset = 0))]
-    pub fn gather_every(&self, n: i64, offset: i64) -> Self {
-        self.0
-            .clone()
-            .gather_every(n as usize, offset as usize)
-            .into()
+    /// Count the number of trailing set bits of every element
+    #[php(name = "bitwiseTrailingOnes")]
+    pub fn bitwise_trailing_ones(&self) -> Self {
+        self.0.clone().bitwise_trailing_ones().into()
     }
 
-    /// Cast to a data type
-    pub fn cast(&self, dtype: String) -> ExtResult<Self> {
-        let target = crate::common::parse_dtype(&dtype)?;
-        Ok(self.0.clone().cast(target).into())
+    /// Count the number of trailing unset bits of every element
+    #[php(name = "bitwiseTrailingZeros")]
+    pub fn bitwise_trailing_zeros(&self) -> Self {
+        self.0.clone().bitwise_trailing_zeros().into()
     }
 
-    #[php(name = "isBetween")]
-    pub fn is_between(
-        &self,
-        lowerBound: &Zval,
-        upperBound: &Zval,
-        closed: PolarsClosedInterval,
-    ) -> ExtResult<Self> {
-        let lower = zval_to_expr(lowerBound)?;
-        let upper = zval_to_expr(upperBound)?;
+    /// Shift the bits of every integer to the left, keeping the data type.
+    /// Bits shifted past the width of the type are lost
+    /// @throws Polars\Exception
+    #[php(name = "shiftLeft")]
+    pub fn shift_left(&self, n: i64) -> ExtResult<Self> {
+        let n = bit_shift_amount(n)?;
         Ok(self
             .0
             .clone()
-            .is_between(lower, upper, closed.into())
+            .map_with_fmt_str(
+                move |column| shift_bits(column, n, true),
+                |_, field| Ok(field.clone()),
+                "shift_left",
+            )
             .into())
     }
 
-    /// Exclude specific columns from the expression
-    pub fn exclude(&self, columns: &Zval) -> ExtResult<Self> {
-        let names = match columns.get_type() {
-            DataType::String => {
-                vec![columns.str().unwrap().to_string()]
-            }
-            DataType::Array => {
-                let array = columns.array().unwrap();
-                let mut names = Vec::new();
+    /// Shift the bits of every integer to the right, keeping the data type.
+    /// The sign of signed integers is kept
+    /// @throws Polars\Exception
+    #[php(name = "shiftRight")]
+    pub fn shift_right(&self, n: i64) -> ExtResult<Self> {
+        let n = bit_shift_amount(n)?;
+        Ok(self
+            .0
+            .clone()
+            .map_with_fmt_str(
+                move |column| shift_bits(column, n, false),
+                |_, field| Ok(field.clone()),
+                "shift_right",
+            )
+            .into())
+    }
 
-                for (_, val) in array.iter() {
-                    if val.get_type() == DataType::String {
-                        names.push(val.str().unwrap().to_string());
-                    } else {
-                        return Err(PolarsException::new(
-                            "exclude() with array requires all elements to be strings".to_string(),
-                        ));
-                    }
-                }
-                names
-            }
-            DataType::Object(_) => {
-                let object: &ZendObject = columns.object().unwrap();
-                if !object.is_instance::<PolarsExpr>() {
-                    return Err(PolarsException::new(
-                        "Passed object is not of class Polars\\Expr".to_string(),
-                    ));
-                }
-                let inner_expr = columns.extract::<&PolarsExpr>().unwrap().0.clone();
-                if let Expr::Column(name) = inner_expr {
-                    vec![name.to_string()]
-                } else {
-                    return Err(PolarsException::new(
-                        "exclude() object must be a column expression".to_string(),
-                    ));
-                }
-            }
-            _ => {
-                return Err(PolarsException::new(
-                    "exclude() accepts a string, array of strings, or Polars\\Expr".to_string(),
-                ));
-            }
-        };
+    /// Hash every element to a UInt64. The hash is deterministic for given seeds within
+    /// one Polars version, but may change between versions
+    /// @param int $seed Seed of the hash; other seeds default to it
+    /// @throws Polars\Exception
+    #[php(defaults(seed = 0))]
+    pub fn hash(
+        &self,
+        seed: i64,
+        seed1: Option<i64>,
+        seed2: Option<i64>,
+        seed3: Option<i64>,
+    ) -> ExtResult<Self> {
+        let k0 = zval_to_seed(seed)?;
+        let k1 = seed1.map_or(Ok(k0), zval_to_seed)?;
+        let k2 = seed2.map_or(Ok(k0), zval_to_seed)?;
+        let k3 = seed3.map_or(Ok(k0), zval_to_seed)?;
+        Ok(self.0.clone().hash(k0, k1, k2, k3).into())
+    }
 
-        // In polars 0.52, Expr doesn't have .exclude().
-        // Instead, we use the Selector's .exclude_cols() if the original expr can be treated as a selector.
-        if let Some(sel) = self.0.clone().into_selector() {
-            Ok(PolarsExpr(sel.exclude_cols(names).as_expr()))
-        } else {
-            Err(PolarsException::new("exclude() can only be called on expressions that can be treated as selectors (e.g. all(), col())".to_string()))
-        }
+    /// Reinterpret the bits of 64-bit integers as signed (Int64) or unsigned (UInt64)
+    #[php(defaults(signed = true))]
+    pub fn reinterpret(&self, signed: bool) -> Self {
+        self.0.clone().reinterpret(signed).into()
     }
-}
 
-/// Methods that are hidden from PHP stubs
-impl PolarsExpr {
-    pub fn get_expr(&self) -> &Expr {
-        &self.0
+    // MATH //
+
+    /// Compute absolute values
+    pub fn abs(&self) -> Self {
+        self.0.clone().abs().into()
     }
-}
 
-impl From<Expr> for PolarsExpr {
-    fn from(expr: Expr) -> Self {
-        PolarsExpr(expr)
+    /// Compute the square root of the elements
+    pub fn sqrt(&self) -> Self {
+        self.0.clone().sqrt().into()
     }
-}
 
-impl From<PolarsExpr> for Expr {
-    fn from(polars_expr: PolarsExpr) -> Expr {
-        polars_expr.0
+    /// Compute the cube root of the elements
+    pub fn cbrt(&self) -> Self {
+        self.0.clone().cbrt().into()
     }
-}
 
-impl From<&PolarsExpr> for Expr {
-    fn from(polars_expr: &PolarsExpr) -> Expr {
-        polars_expr.0.clone()
+    /// Compute the exponential, element-wise
+    pub fn exp(&self) -> Self {
+        self.0.clone().exp().into()
     }
-}
 
-// impl FromZval<'_> for PolarsExpr {
-//     const TYPE: DataType = DataType::Object(Some("Polars\\Expr"));
-//
-//     fn from_zval(zval: &Zval) -> Option<Self> {
-//         //let object: &ZendObject = value.object().unwrap();
-//         //                 if !object.is_instance::<PolarsExpr>() {
-//         //                     return Err(PolarsException::new("Passed object is not of class Polars\\Expr".to_string()));
-//         //                 }
-//         //                 value.extract::<&PolarsExpr>().unwrap().into()
-//     }
-// }
+    /// Compute the logarithm to a given base. Natural logarithm is used when base is null
+    pub fn log(&self, base: Option<f64>) -> Self {
+        let base = base.unwrap_or(std::f64::consts::E);
+        self.0.clone().log(lit(base)).into()
+    }
 
-#[php_enum]
-#[php(name = "Polars\\ClosedInterval")]
-pub enum PolarsClosedInterval {
-    Both,
-    Left,
-    Right,
-    None,
-}
+    /// Compute the entropy as `-sum(pk * log(pk))`, where `pk` are discrete probabilities.
+    /// Natural logarithm is used when base is null
+    /// @param bool $normalize Normalize `pk` so that it sums to 1
+    #[php(defaults(normalize = true))]
+    pub fn entropy(&self, base: Option<f64>, normalize: bool) -> Self {
+        let base = base.unwrap_or(std::f64::consts::E);
+        self.0.clone().entropy(base, normalize).into()
+    }
 
-impl From<PolarsClosedInterval> for ClosedInterval {
-    fn from(polars_closed_interval: PolarsClosedInterval) -> Self {
-        match polars_closed_interval {
-            PolarsClosedInterval::Both => ClosedInterval::Both,
-            PolarsClosedInterval::Left => ClosedInterval::Left,
-            PolarsClosedInterval::Right => ClosedInterval::Right,
-            PolarsClosedInterval::None => ClosedInterval::None,
-        }
+    /// Compute the natural logarithm of the elements plus one
+    pub fn log1p(&self) -> Self {
+        self.0.clone().log1p().into()
     }
-}
 
-#[php_enum]
-#[php(name = "Polars\\QuantileMethod")]
-pub enum PolarsQuantileMethod {
-    Nearest,
-    Lower,
-    Higher,
-    Midpoint,
-    Linear,
-    Equiprobable,
-}
+    /// Compute the base 10 logarithm of the elements
+    pub fn log10(&self) -> Self {
+        self.0.clone().log(lit(10.0)).into()
+    }
 
-impl From<PolarsQuantileMethod> for QuantileMethod {
-    fn from(method: PolarsQuantileMethod) -> Self {
+    /// Compute the element-wise value for the sine
+    pub fn sin(&self) -> Self {
+        self.0.clone().sin().into()
+    }
+
+    /// Compute the element-wise value for the cosine
+    pub fn cos(&self) -> Self {
+        self.0.clone().cos().into()
+    }
+
+    /// Compute the element-wise value for the tangent
+    pub fn tan(&self) -> Self {
+        self.0.clone().tan().into()
+    }
+
+    /// Compute the element-wise value for the cotangent
+    pub fn cot(&self) -> Self {
+        self.0.clone().cot().into()
+    }
+
+    /// Compute the element-wise value for the inverse sine
+    pub fn arcsin(&self) -> Self {
+        self.0.clone().arcsin().into()
+    }
+
+    /// Compute the element-wise value for the inverse cosine
+    pub fn arccos(&self) -> Self {
+        self.0.clone().arccos().into()
+    }
+
+    /// Compute the element-wise value for the inverse tangent
+    pub fn arctan(&self) -> Self {
+        self.0.clone().arctan().into()
+    }
+
+    /// Compute the element-wise value for the hyperbolic sine
+    pub fn sinh(&self) -> Self {
+        self.0.clone().sinh().into()
+    }
+
+    /// Compute the element-wise value for the hyperbolic cosine
+    pub fn cosh(&self) -> Self {
+        self.0.clone().cosh().into()
+    }
+
+    /// Compute the element-wise value for the hyperbolic tangent
+    pub fn tanh(&self) -> Self {
+        self.0.clone().tanh().into()
+    }
+
+    /// Compute the element-wise value for the inverse hyperbolic sine
+    pub fn arcsinh(&self) -> Self {
+        self.0.clone().arcsinh().into()
+    }
+
+    /// Compute the element-wise value for the inverse hyperbolic cosine
+    pub fn arccosh(&self) -> Self {
+        self.0.clone().arccosh().into()
+    }
+
+    /// Compute the element-wise value for the inverse hyperbolic tangent
+    pub fn arctanh(&self) -> Self {
+        self.0.clone().arctanh().into()
+    }
+
+    /// Convert from radians to degrees
+    pub fn degrees(&self) -> Self {
+        self.0.clone().degrees().into()
+    }
+
+    /// Convert from degrees to radians
+    pub fn radians(&self) -> Self {
+        self.0.clone().radians().into()
+    }
+
+    /// Rounds down to the nearest integer value
+    pub fn floor(&self) -> Self {
+        self.0.clone().floor().into()
+    }
+
+    /// Rounds up to the nearest integer value
+    pub fn ceil(&self) -> Self {
+        self.0.clone().ceil().into()
+    }
+
+    /// Round underlying floating point data by `decimals` digits
+    /// @param string $mode One of: 'half_to_even', 'half_away_from_zero'
+    /// @throws Polars\Exception
+    #[php(defaults(decimals = 0, mode = "half_to_even".to_string()))]
+    pub fn round(&self, decimals: u32, mode: String) -> ExtResult<Self> {
+        let mode = parse_round_mode(&mode)?;
+        Ok(self.0.clone().round(decimals, mode).into())
+    }
+
+    /// Round to a number of significant figures
+    #[php(name = "roundSigFigs")]
+    pub fn round_sig_figs(&self, digits: i32) -> Self {
+        self.0.clone().round_sig_figs(digits).into()
+    }
+
+    /// Set values outside the given boundaries to the boundary value. Null bound means no bound on that side
+    /// @param int|float|null|\Polars\Expr $lowerBound
+    /// @param int|float|null|\Polars\Expr $upperBound
+    /// @throws Polars\Exception
+    pub fn clip(&self, lowerBound: Option<&Zval>, upperBound: Option<&Zval>) -> ExtResult<Self> {
+        let lower = optional_zval_to_expr(lowerBound)?;
+        let upper = optional_zval_to_expr(upperBound)?;
+        let expr = self.0.clone();
+        Ok(match (lower, upper) {
+            (Some(lower), Some(upper)) => expr.clip(lower, upper),
+            (Some(lower), None) => expr.clip_min(lower),
+            (None, Some(upper)) => expr.clip_max(upper),
+            (None, None) => expr,
+        }
+        .into())
+    }
+
+    /// Compute the element-wise sign function on numeric types (-1, 0 or 1)
+    pub fn sign(&self) -> Self {
+        self.0.clone().sign().into()
+    }
+
+    /// Compute the dot/inner product between two expressions
+    /// @param int|float|\Polars\Expr $other
+    pub fn dot(&self, other: &Zval) -> ExtResult<Self> {
+        let other_expr = zval_to_expr(other)?;
+        Ok(self.0.clone().dot(other_expr).into())
+    }
+
+    // CUMULATIVE //
+
+    /// Get an array with the cumulative sum computed at every element
+    #[php(name = "cumSum", defaults(reverse = false))]
+    pub fn cum_sum(&self, reverse: bool) -> Self {
+        self.0.clone().cum_sum(reverse).into()
+    }
+
+    /// Get an array with the cumulative product computed at every element
+    #[php(name = "cumProd", defaults(reverse = false))]
+    pub fn cum_prod(&self, reverse: bool) -> Self {
+        self.0.clone().cum_prod(reverse).into()
+    }
+
+    /// Get an array with the cumulative min computed at every element
+    #[php(name = "cumMin", defaults(reverse = false))]
+    pub fn cum_min(&self, reverse: bool) -> Self {
+        self.0.clone().cum_min(reverse).into()
+    }
+
+    /// Get an array with the cumulative max computed at every element
+    #[php(name = "cumMax", defaults(reverse = false))]
+    pub fn cum_max(&self, reverse: bool) -> Self {
+        self.0.clone().cum_max(reverse).into()
+    }
+
+    /// Get an array with the cumulative count of non-null values computed at every element
+    #[php(name = "cumCount", defaults(reverse = false))]
+    pub fn cum_count(&self, reverse: bool) -> Self {
+        self.0.clone().cum_count(reverse).into()
+    }
+
+    /// Calculate the first discrete difference between shifted items
+    /// @param string $nullBehavior One of: 'ignore', 'drop'
+    /// @throws Polars\Exception
+    #[php(defaults(n = 1, nullBehavior = "ignore".to_string()))]
+    pub fn diff(&self, n: i64, nullBehavior: String) -> ExtResult<Self> {
+        let null_behavior = parse_null_behavior(&nullBehavior)?;
+        Ok(self.0.clone().diff(lit(n), null_behavior).into())
+    }
+
+    /// Computes percentage change between values
+    #[php(name = "pctChange", defaults(n = 1))]
+    pub fn pct_change(&self, n: i64) -> Self {
+        self.0.clone().pct_change(lit(n)).into()
+    }
+
+    /// Run an expression over a sliding window that increases by one slot every iteration.
+    /// Use `Expr::element()` to refer to the values in the window
+    /// @throws Polars\Exception
+    #[php(name = "cumulativeEval", defaults(minSamples = 1))]
+    pub fn cumulative_eval(&self, expr: &PolarsExpr, minSamples: i64) -> ExtResult<Self> {
+        let min_samples = zval_to_length(minSamples, "minSamples")?;
+        Ok(self
+            .0
+            .clone()
+            .cumulative_eval(expr.0.clone(), min_samples)
+            .into())
+    }
+
+    // ROLLING //
+
+    /// Apply a rolling sum over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingSum", defaults(center = false))]
+    pub fn rolling_sum(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        Ok(self.0.clone().rolling_sum(options).into())
+    }
+
+    /// Apply a rolling mean over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingMean", defaults(center = false))]
+    pub fn rolling_mean(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        Ok(self.0.clone().rolling_mean(options).into())
+    }
+
+    /// Apply a rolling min over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingMin", defaults(center = false))]
+    pub fn rolling_min(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        Ok(self.0.clone().rolling_min(options).into())
+    }
+
+    /// Apply a rolling max over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingMax", defaults(center = false))]
+    pub fn rolling_max(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        Ok(self.0.clone().rolling_max(options).into())
+    }
+
+    /// Apply a rolling median over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingMedian", defaults(center = false))]
+    pub fn rolling_median(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        Ok(self.0.clone().rolling_median(options).into())
+    }
+
+    /// Apply a rolling standard deviation over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingStd", defaults(center = false, ddof = 1))]
+    pub fn rolling_std(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, Some(params))?;
+        Ok(self.0.clone().rolling_std(options).into())
+    }
+
+    /// Apply a rolling variance over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[php(name = "rollingVar", defaults(center = false, ddof = 1))]
+    pub fn rolling_var(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, Some(params))?;
+        Ok(self.0.clone().rolling_var(options).into())
+    }
+
+    /// Apply a rolling quantile over a fixed window of `windowSize` values
+    /// @throws Polars\Exception
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "rollingQuantile",
+        defaults(interpolation = PolarsQuantileMethod::Nearest, center = false)
+    )]
+    pub fn rolling_quantile(
+        &self,
+        quantile: f64,
+        windowSize: i64,
+        interpolation: PolarsQuantileMethod,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        Ok(self
+            .0
+            .clone()
+            .rolling_quantile(interpolation.into(), quantile, options)
+            .into())
+    }
+
+    /// Apply a rolling sum over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(name = "rollingSumBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_sum_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        Ok(self.0.clone().rolling_sum_by(by, options).into())
+    }
+
+    /// Apply a rolling mean over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(name = "rollingMeanBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_mean_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        Ok(self.0.clone().rolling_mean_by(by, options).into())
+    }
+
+    /// Apply a rolling min over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(name = "rollingMinBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_min_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        Ok(self.0.clone().rolling_min_by(by, options).into())
+    }
+
+    /// Apply a rolling max over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(name = "rollingMaxBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_max_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        Ok(self.0.clone().rolling_max_by(by, options).into())
+    }
+
+    /// Apply a rolling median over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(name = "rollingMedianBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_median_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        Ok(self.0.clone().rolling_median_by(by, options).into())
+    }
+
+    /// Apply a rolling standard deviation over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(
+        name = "rollingStdBy",
+        defaults(minPeriods = 1, closed = PolarsClosedInterval::Right, ddof = 1)
+    )]
+    pub fn rolling_std_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, Some(params))?;
+        Ok(self.0.clone().rolling_std_by(by, options).into())
+    }
+
+    /// Apply a rolling variance over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[php(
+        name = "rollingVarBy",
+        defaults(minPeriods = 1, closed = PolarsClosedInterval::Right, ddof = 1)
+    )]
+    pub fn rolling_var_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, Some(params))?;
+        Ok(self.0.clone().rolling_var_by(by, options).into())
+    }
+
+    /// Apply a rolling quantile over a temporal window (e.g. '7d') based on the `by` column
+    /// @param string|\Polars\Expr $by Temporal or integer column used to determine the windows
+    /// @throws Polars\Exception
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "rollingQuantileBy",
+        defaults(
+            interpolation = PolarsQuantileMethod::Nearest,
+            minPeriods = 1,
+            closed = PolarsClosedInterval::Right
+        )
+    )]
+    pub fn rolling_quantile_by(
+        &self,
+        by: &Zval,
+        windowSize: String,
+        quantile: f64,
+        interpolation: PolarsQuantileMethod,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        Ok(self
+            .0
+            .clone()
+            .rolling_quantile_by(by, interpolation.into(), quantile, options)
+            .into())
+    }
+
+    // EXPONENTIALLY WEIGHTED //
+
+    /// Compute exponentially-weighted moving mean.
+    /// Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+    /// @throws Polars\Exception
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "ewmMean",
+        defaults(adjust = true, minPeriods = 1, ignoreNulls = false)
+    )]
+    pub fn ewm_mean(
+        &self,
+        com: Option<f64>,
+        span: Option<f64>,
+        halfLife: Option<f64>,
+        alpha: Option<f64>,
+        adjust: bool,
+        minPeriods: i64,
+        ignoreNulls: bool,
+    ) -> ExtResult<Self> {
+        let options = ewm_options(
+            com,
+            span,
+            halfLife,
+            alpha,
+            adjust,
+            false,
+            minPeriods,
+            ignoreNulls,
+        )?;
+        Ok(self.0.clone().ewm_mean(options).into())
+    }
+
+    /// Compute exponentially-weighted moving standard deviation.
+    /// Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+    /// @throws Polars\Exception
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "ewmStd",
+        defaults(adjust = true, bias = false, minPeriods = 1, ignoreNulls = false)
+    )]
+    pub fn ewm_std(
+        &self,
+        com: Option<f64>,
+        span: Option<f64>,
+        halfLife: Option<f64>,
+        alpha: Option<f64>,
+        adjust: bool,
+        bias: bool,
+        minPeriods: i64,
+        ignoreNulls: bool,
+    ) -> ExtResult<Self> {
+        let options = ewm_options(
+            com,
+            span,
+            halfLife,
+            alpha,
+            adjust,
+            bias,
+            minPeriods,
+            ignoreNulls,
+        )?;
+        Ok(self.0.clone().ewm_std(options).into())
+    }
+
+    /// Compute exponentially-weighted moving variance.
+    /// Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+    /// @throws Polars\Exception
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "ewmVar",
+        defaults(adjust = true, bias = false, minPeriods = 1, ignoreNulls = false)
+    )]
+    pub fn ewm_var(
+        &self,
+        com: Option<f64>,
+        span: Option<f64>,
+        halfLife: Option<f64>,
+        alpha: Option<f64>,
+        adjust: bool,
+        bias: bool,
+        minPeriods: i64,
+        ignoreNulls: bool,
+    ) -> ExtResult<Self> {
+        let options = ewm_options(
+            com,
+            span,
+            halfLife,
+            alpha,
+            adjust,
+            bias,
+            minPeriods,
+            ignoreNulls,
+        )?;
+        Ok(self.0.clone().ewm_var(options).into())
+    }
+
+    /// Compute time-based exponentially weighted moving average
+    /// @param string|\Polars\Expr $by Temporal or integer column with the times of the values
+    /// @param string $halfLife Duration string such as '1d' over which an observation decays to half its value
+    /// @throws Polars\Exception
+    #[php(name = "ewmMeanBy")]
+    pub fn ewm_mean_by(&self, by: &Zval, halfLife: String) -> ExtResult<Self> {
+        let by = zval_to_column_expr(by)?;
+        let half_life = parse_duration(&halfLife)?;
+        Ok(self.0.clone().ewm_mean_by(by, half_life).into())
+    }
+
+    // ORDERING //
+
+    /// Assign ranks to data, dealing with ties appropriately
+    /// @param string $method One of: 'average', 'min', 'max', 'dense', 'ordinal', 'random'
+    /// @throws Polars\Exception
+    #[php(defaults(method = "average".to_string(), descending = false))]
+    pub fn rank(&self, method: String, descending: bool, seed: Option<i64>) -> ExtResult<Self> {
+        let method = match method.as_str() {
+            "average" => RankMethod::Average,
+            "min" => RankMethod::Min,
+            "max" => RankMethod::Max,
+            "dense" => RankMethod::Dense,
+            "ordinal" => RankMethod::Ordinal,
+            "random" => RankMethod::Random,
+            _ => {
+                return Err(PolarsException::new(format!(
+                    "Invalid rank method: {}. Use 'average', 'min', 'max', 'dense', 'ordinal', or 'random'",
+                    method
+                )));
+            }
+        };
+        let options = RankOptions { method, descending };
+        let seed = seed.map(zval_to_seed).transpose()?;
+        Ok(self.0.clone().rank(options, seed).into())
+    }
+
+    /// Sort this column. In a group-by context, the groups are sorted
+    #[php(defaults(descending = false, nullsLast = true))]
+    pub fn sort(&self, descending: bool, nullsLast: bool) -> Self {
+        let options = SortOptions::default()
+            .with_order_descending(descending)
+            .with_nulls_last(nullsLast);
+        self.0.clone().sort(options).into()
+    }
+
+    /// Sort this column by the ordering of other columns
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to sort by
+    /// @param bool|bool[]|null $descending Sort in descending order, one flag per column or a single flag for all
+    /// @throws Polars\Exception
+    #[php(
+        name = "sortBy",
+        defaults(nullsLast = true, maintainOrder = false, multithreaded = true)
+    )]
+    pub fn sort_by(
+        &self,
+        by: &Zval,
+        descending: Option<&Zval>,
+        nullsLast: bool,
+        maintainOrder: bool,
+        multithreaded: bool,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_exprs(by)?;
+        let descending = zval_to_bools(descending, by.len(), "descending")?;
+        let options = SortMultipleOptions::new()
+            .with_order_descending_multi(descending)
+            .with_nulls_last(nullsLast)
+            .with_maintain_order(maintainOrder)
+            .with_multithreaded(multithreaded);
+        Ok(self.0.clone().sort_by(by, options).into())
+    }
+
+    /// Get the index values that would sort this column
+    #[php(name = "argSort", defaults(descending = false, nullsLast = true))]
+    pub fn arg_sort(&self, descending: bool, nullsLast: bool) -> Self {
+        self.0.clone().arg_sort(descending, nullsLast).into()
+    }
+
+    /// Return the `k` largest elements
+    #[php(name = "topK", defaults(k = 5))]
+    pub fn top_k(&self, k: i64) -> Self {
+        self.0.clone().top_k(lit(k)).into()
+    }
+
+    /// Return the `k` smallest elements
+    #[php(name = "bottomK", defaults(k = 5))]
+    pub fn bottom_k(&self, k: i64) -> Self {
+        self.0.clone().bottom_k(lit(k)).into()
+    }
+
+    /// Return the elements corresponding to the `k` largest elements of the `by` column(s)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) used to determine the largest elements
+    /// @param bool|bool[]|null $reverse Consider the `k` smallest elements of the `by` column(s) instead, per column
+    /// @throws Polars\Exception
+    #[php(name = "topKBy", defaults(k = 5))]
+    pub fn top_k_by(&self, by: &Zval, k: i64, reverse: Option<&Zval>) -> ExtResult<Self> {
+        let by = zval_to_column_exprs(by)?;
+        let reverse = zval_to_bools(reverse, by.len(), "reverse")?;
+        Ok(self.0.clone().top_k_by(lit(k), by, reverse).into())
+    }
+
+    /// Return the elements corresponding to the `k` smallest elements of the `by` column(s)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) used to determine the smallest elements
+    /// @param bool|bool[]|null $reverse Consider the `k` largest elements of the `by` column(s) instead, per column
+    /// @throws Polars\Exception
+    #[php(name = "bottomKBy", defaults(k = 5))]
+    pub fn bottom_k_by(&self, by: &Zval, k: i64, reverse: Option<&Zval>) -> ExtResult<Self> {
+        let by = zval_to_column_exprs(by)?;
+        let reverse = zval_to_bools(reverse, by.len(), "reverse")?;
+        Ok(self.0.clone().bottom_k_by(lit(k), by, reverse).into())
+    }
+
+    /// Reverse the selection
+    pub fn reverse(&self) -> Self {
+        self.0.clone().reverse().into()
+    }
+
+    /// Shuffle the contents of this expression
+    /// @throws Polars\Exception
+    pub fn shuffle(&self, seed: Option<i64>) -> ExtResult<Self> {
+        let seed = seed.map(zval_to_seed).transpose()?;
+        Ok(self.0.clone().shuffle(seed).into())
+    }
+
+    /// Sample from this expression. Exactly one of `n` or `fraction` must be given
+    /// @throws Polars\Exception
+    #[php(defaults(withReplacement = false, shuffle = false))]
+    pub fn sample(
+        &self,
+        n: Option<i64>,
+        fraction: Option<f64>,
+        withReplacement: bool,
+        shuffle: bool,
+        seed: Option<i64>,
+    ) -> ExtResult<Self> {
+        let seed = seed.map(zval_to_seed).transpose()?;
+        let expr = self.0.clone();
+        Ok(match (n, fraction) {
+            (Some(n), None) => expr.sample_n(lit(n), withReplacement, shuffle, seed),
+            (None, Some(fraction)) => {
+                expr.sample_frac(lit(fraction), withReplacement, shuffle, seed)
+            }
+            _ => {
+                return Err(PolarsException::new(
+                    "Exactly one of 'n' or 'fraction' must be given".to_string(),
+                ));
+            }
+        }
+        .into())
+    }
+
+    // SET OPERATIONS //
+
+    /// Check if elements of this expression are present in the other collection
+    /// @param array|\Polars\Series|\Polars\Expr $other Values to look for. An Expr must evaluate to a list
+    /// @throws Polars\Exception
+    #[php(name = "isIn", defaults(nullsEqual = false))]
+    pub fn is_in(&self, other: &Zval, nullsEqual: bool) -> ExtResult<Self> {
+        let other = zval_to_collection_expr(other)?;
+        Ok(self.0.clone().is_in(other, nullsEqual).into())
+    }
+
+    /// Replace values according to a mapping. Values missing from the mapping are kept as they are,
+    /// unless a default is given
+    /// @param array $mapping Associative array of old value => new value
+    /// @param mixed $default Value (or Expr) used for values missing from the mapping
+    /// @throws Polars\Exception
+    pub fn replace(&self, mapping: &ZendHashTable, default: Option<&Zval>) -> ExtResult<Self> {
+        let (old, new) = mapping_to_series(mapping)?;
+        let expr = self.0.clone();
+        Ok(match optional_zval_to_expr(default)? {
+            Some(default) => {
+                expr.replace_strict(lit(old), lit(new), Some(default), None::<PolarsDataType>)
+            }
+            None => expr.replace(lit(old), lit(new)),
+        }
+        .into())
+    }
+
+    /// Replace all values according to a mapping. Values missing from the mapping raise an error
+    /// on evaluation, unless a default is given
+    /// @param array $mapping Associative array of old value => new value
+    /// @param mixed $default Value (or Expr) used for values missing from the mapping
+    /// @param string|null $returnDtype Data type of the result, inferred from the new values if not given
+    /// @throws Polars\Exception
+    #[php(name = "replaceStrict")]
+    pub fn replace_strict(
+        &self,
+        mapping: &ZendHashTable,
+        default: Option<&Zval>,
+        returnDtype: Option<String>,
+    ) -> ExtResult<Self> {
+        let (old, new) = mapping_to_series(mapping)?;
+        let default = optional_zval_to_expr(default)?;
+        let return_dtype = returnDtype
+            .map(|dtype| crate::common::parse_dtype(&dtype))
+            .transpose()?;
+        Ok(self
+            .0
+            .clone()
+            .replace_strict(lit(old), lit(new), default, return_dtype)
+            .into())
+    }
+
+    /// Get a mask of duplicated values
+    #[php(name = "isDuplicated")]
+    pub fn is_duplicated(&self) -> Self {
+        self.0.clone().is_duplicated().into()
+    }
+
+    /// Get a mask of unique values
+    #[php(name = "isUnique")]
+    pub fn is_unique(&self) -> Self {
+        self.0.clone().is_unique().into()
+    }
+
+    /// Get a mask of the first occurrence of every distinct value
+    #[php(name = "isFirstDistinct")]
+    pub fn is_first_distinct(&self) -> Self {
+        self.0.clone().is_first_distinct().into()
+    }
+
+    /// Get a mask of the last occurrence of every distinct value
+    #[php(name = "isLastDistinct")]
+    pub fn is_last_distinct(&self) -> Self {
+        self.0.clone().is_last_distinct().into()
+    }
+
+    /// Get unique values of this expression
+    #[php(defaults(maintainOrder = false))]
+    pub fn unique(&self, maintainOrder: bool) -> Self {
+        if maintainOrder {
+            self.0.clone().unique_stable().into()
+        } else {
+            self.0.clone().unique().into()
+        }
+    }
+
+    /// Get the index of the first occurrence of every unique value
+    #[php(name = "argUnique")]
+    pub fn arg_unique(&self) -> Self {
+        self.0.clone().arg_unique().into()
+    }
+
+    /// Count the occurrences of unique values. Returns a struct with the value and a
+    /// 'count' field ('proportion' when normalized)
+    #[php(name = "valueCounts", defaults(sort = false, normalize = false))]
+    pub fn value_counts(&self, sort: bool, normalize: bool) -> Self {
+        let name = if normalize { "proportion" } else { "count" };
+        self.0
+            .clone()
+            .value_counts(sort, false, name, normalize)
+            .into()
+    }
+
+    // MISSING DATA //
+
+    /// Get a mask of null values
+    #[php(name = "isNull")]
+    pub fn is_null(&self) -> Self {
+        self.0.clone().is_null().into()
+    }
+
+    /// Get a mask of non-null values
+    #[php(name = "isNotNull")]
+    pub fn is_not_null(&self) -> Self {
+        self.0.clone().is_not_null().into()
+    }
+
+    /// Get a mask of NaN values (float columns only)
+    #[php(name = "isNan")]
+    pub fn is_nan(&self) -> Self {
+        self.0.clone().is_nan().into()
+    }
+
+    /// Get a mask of non-NaN values (float columns only)
+    #[php(name = "isNotNan")]
+    pub fn is_not_nan(&self) -> Self {
+        self.0.clone().is_not_nan().into()
+    }
+
+    /// Get a mask of finite values
+    #[php(name = "isFinite")]
+    pub fn is_finite(&self) -> Self {
+        self.0.clone().is_finite().into()
+    }
+
+    /// Get a mask of infinite values
+    #[php(name = "isInfinite")]
+    pub fn is_infinite(&self) -> Self {
+        self.0.clone().is_infinite().into()
+    }
+
+    /// Fill null values with a value or using a strategy. Exactly one of `value` or `strategy` must be given
+    /// @param mixed $value Value or Expr to fill nulls with
+    /// @param string|null $strategy One of: 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'
+    /// @param int|null $limit Maximum number of consecutive nulls to fill (forward and backward strategies only)
+    /// @throws Polars\Exception
+    #[php(name = "fillNull")]
+    pub fn fill_null(
+        &self,
+        value: Option<&Zval>,
+        strategy: Option<String>,
+        limit: Option<i64>,
+    ) -> ExtResult<Self> {
+        let value = optional_zval_to_expr(value)?;
+        match (value, strategy) {
+            (Some(value), None) => {
+                if limit.is_some() {
+                    return Err(PolarsException::new(
+                        "'limit' can only be used with the 'forward' or 'backward' strategy"
+                            .to_string(),
+                    ));
+                }
+                Ok(self.0.clone().fill_null(value).into())
+            }
+            (None, Some(strategy)) => {
+                let strategy = parse_fill_null_strategy(&strategy, limit)?;
+                Ok(self.0.clone().fill_null_with_strategy(strategy).into())
+            }
+            _ => Err(PolarsException::new(
+                "Exactly one of 'value' or 'strategy' must be given".to_string(),
+            )),
+        }
+    }
+
+    /// Fill floating point NaN values with a value
+    /// @param mixed $value Value or Expr to fill NaN values with
+    /// @throws Polars\Exception
+    #[php(name = "fillNan")]
+    pub fn fill_nan(&self, value: &Zval) -> ExtResult<Self> {
+        let value = zval_to_expr(value)?;
+        Ok(self.0.clone().fill_nan(value).into())
+    }
+
+    /// Fill null values with the last non-null value
+    /// @throws Polars\Exception
+    #[php(name = "forwardFill")]
+    pub fn forward_fill(&self, limit: Option<i64>) -> ExtResult<Self> {
+        let limit = fill_limit(limit)?;
+        Ok(self
+            .0
+            .clone()
+            .fill_null_with_strategy(FillNullStrategy::Forward(limit))
+            .into())
+    }
+
+    /// Fill null values with the next non-null value
+    /// @throws Polars\Exception
+    #[php(name = "backwardFill")]
+    pub fn backward_fill(&self, limit: Option<i64>) -> ExtResult<Self> {
+        let limit = fill_limit(limit)?;
+        Ok(self
+            .0
+            .clone()
+            .fill_null_with_strategy(FillNullStrategy::Backward(limit))
+            .into())
+    }
+
+    /// Drop null values
+    #[php(name = "dropNulls")]
+    pub fn drop_nulls(&self) -> Self {
+        self.0.clone().drop_nulls().into()
+    }
+
+    /// Drop floating point NaN values
+    #[php(name = "dropNans")]
+    pub fn drop_nans(&self) -> Self {
+        self.0.clone().drop_nans().into()
+    }
+
+    /// Fill null values by interpolating between the surrounding non-null values.
+    /// Nulls at the beginning and end remain null
+    /// @param string $method One of: 'linear', 'nearest'
+    /// @throws Polars\Exception
+    #[php(defaults(method = "linear".to_string()))]
+    pub fn interpolate(&self, method: String) -> ExtResult<Self> {
+        let method = match method.as_str() {
+            "linear" => InterpolationMethod::Linear,
+            "nearest" => InterpolationMethod::Nearest,
+            _ => {
+                return Err(PolarsException::new(format!(
+                    "Invalid interpolation method: {}. Use 'linear' or 'nearest'",
+                    method
+                )));
+            }
+        };
+        Ok(self.0.clone().interpolate(method).into())
+    }
+
+    // DISCRETIZATION //
+
+    /// Bin continuous values into discrete categories
+    /// @param array $breaks Bin boundaries, in ascending order
+    /// @param array|null $labels Names of the categories; must have one more element than `breaks`
+    /// @param bool $leftClosed Make the intervals closed on the left instead of on the right
+    /// @param bool $includeBreaks Return a struct with the right endpoint of the bin and the category
+    /// @throws Polars\Exception
+    #[php(defaults(leftClosed = false, includeBreaks = false))]
+    pub fn cut(
+        &self,
+        breaks: Vec<f64>,
+        labels: Option<Vec<String>>,
+        leftClosed: bool,
+        includeBreaks: bool,
+    ) -> ExtResult<Self> {
+        validate_bin_labels(labels.as_deref(), breaks.len() + 1)?;
+        Ok(self
+            .0
+            .clone()
+            .cut(breaks, labels, leftClosed, includeBreaks)
+            .into())
+    }
+
+    /// Bin continuous values into discrete categories based on their quantiles
+    /// @param array|int $quantiles Quantile probabilities between 0 and 1, or the number of bins of equal probability
+    /// @param array|null $labels Names of the categories; must have one more element than the number of quantiles
+    /// @param bool $leftClosed Make the intervals closed on the left instead of on the right
+    /// @param bool $allowDuplicates Drop duplicate breaks instead of raising an error
+    /// @param bool $includeBreaks Return a struct with the right endpoint of the bin and the category
+    /// @throws Polars\Exception
+    #[php(defaults(leftClosed = false, allowDuplicates = false, includeBreaks = false))]
+    pub fn qcut(
+        &self,
+        quantiles: &Zval,
+        labels: Option<Vec<String>>,
+        leftClosed: bool,
+        allowDuplicates: bool,
+        includeBreaks: bool,
+    ) -> ExtResult<Self> {
+        if let Some(n) = quantiles.long() {
+            if n < 1 {
+                return Err(PolarsException::new(format!(
+                    "Invalid number of bins: {}. Must be at least 1",
+                    n
+                )));
+            }
+            validate_bin_labels(labels.as_deref(), n as usize)?;
+            return Ok(self
+                .0
+                .clone()
+                .qcut_uniform(
+                    n as usize,
+                    labels,
+                    leftClosed,
+                    allowDuplicates,
+                    includeBreaks,
+                )
+                .into());
+        }
+        let Some(array) = quantiles.array() else {
+            return Err(PolarsException::new(
+                "Quantiles must be an array of probabilities or a number of bins".to_string(),
+            ));
+        };
+        let mut probs = Vec::with_capacity(array.len());
+        for (_, value) in array.iter() {
+            let prob = value.double().or_else(|| value.long().map(|v| v as f64));
+            match prob {
+                Some(prob) if (0.0..=1.0).contains(&prob) => probs.push(prob),
+                _ => {
+                    return Err(PolarsException::new(
+                        "Quantiles must be numbers between 0 and 1".to_string(),
+                    ));
+                }
+            }
+        }
+        validate_bin_labels(labels.as_deref(), probs.len() + 1)?;
+        Ok(self
+            .0
+            .clone()
+            .qcut(probs, labels, leftClosed, allowDuplicates, includeBreaks)
+            .into())
+    }
+
+    // SELECTION //
+
+    /// Filter the values by a boolean predicate. Mostly useful in group by aggregations,
+    /// e.g. the sum of amounts where status is paid
+    /// @param string|\Polars\Expr $predicate Boolean expression or name of a boolean column
+    /// @throws Polars\Exception
+    pub fn filter(&self, predicate: &Zval) -> ExtResult<Self> {
+        let predicate = zval_to_column_expr(predicate)?;
+        Ok(self.0.clone().filter(predicate).into())
+    }
+
+    /// Take values by index. Negative indices count from the end
+    /// @param int|int[]|\Polars\Series|\Polars\Expr $indices Index or indices to take
+    /// @throws Polars\Exception
+    pub fn gather(&self, indices: &Zval) -> ExtResult<Self> {
+        let indices = zval_to_indices_expr(indices)?;
+        Ok(self.0.clone().gather(indices).into())
+    }
+
+    /// Take a single value by index, returning a scalar. Negative indices count from the end
+    /// @param int|\Polars\Expr $index Index of the value
+    /// @throws Polars\Exception
+    pub fn get(&self, index: &Zval) -> ExtResult<Self> {
+        let index = match index.long() {
+            Some(index) => lit(index),
+            None => match index.extract::<&PolarsExpr>() {
+                Some(expr) => expr.0.clone(),
+                None => {
+                    return Err(PolarsException::new(
+                        "Index must be an int or Polars\\Expr".to_string(),
+                    ));
+                }
+            },
+        };
+        Ok(self.0.clone().get(index).into())
+    }
+
+    /// Get the first n values
+    #[php(defaults(n = 10))]
+    pub fn head(&self, n: i64) -> ExtResult<Self> {
+        Ok(self.0.clone().head(Some(non_negative_length(n)?)).into())
+    }
+
+    /// Get the last n values
+    #[php(defaults(n = 10))]
+    pub fn tail(&self, n: i64) -> ExtResult<Self> {
+        Ok(self.0.clone().tail(Some(non_negative_length(n)?)).into())
+    }
+
+    /// Get the first n values. Alias for `head`
+    #[php(defaults(n = 10))]
+    pub fn limit(&self, n: i64) -> ExtResult<Self> {
+        self.head(n)
+    }
+
+    /// Get a slice of the values. A negative offset counts from the end;
+    /// without length the slice runs to the end
+    pub fn slice(&self, offset: i64, length: Option<i64>) -> ExtResult<Self> {
+        let length = match length {
+            Some(length) => lit(non_negative_length(length)? as u64),
+            None => len(),
+        };
+        Ok(self.0.clone().slice(lit(offset), length).into())
+    }
+
+    /// Get the indices of the true values
+    #[php(name = "argTrue")]
+    pub fn arg_true(&self) -> Self {
+        arg_where(self.0.clone()).into()
+    }
+
+    /// Get the indices where the condition is true
+    /// @param string|\Polars\Expr $condition Boolean expression or name of a boolean column
+    /// @throws Polars\Exception
+    #[php(name = "argWhere")]
+    pub fn arg_where(condition: &Zval) -> ExtResult<Self> {
+        Ok(arg_where(zval_to_column_expr(condition)?).into())
+    }
+
+    /// Explode a list column into one row per element
+    pub fn explode(&self) -> Self {
+        self.0.clone().explode().into()
+    }
+
+    /// Flatten a list column into one row per element. Alias for `explode`
+    pub fn flatten(&self) -> Self {
+        self.0.clone().flatten().into()
+    }
+
+    /// Append the values of another expression
+    /// @param mixed $other Expression or value to append
+    /// @param bool $upcast Cast both sides to a common supertype if the types differ
+    /// @throws Polars\Exception
+    #[php(name = "appendExpr", defaults(upcast = true))]
+    pub fn append_expr(&self, other: &Zval, upcast: bool) -> ExtResult<Self> {
+        let other = zval_to_expr(other)?;
+        Ok(self.0.clone().append(other, upcast).into())
+    }
+
+    // WINDOW //
+
+    /// Compute expressions over the given groups (window function)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $partitionBy Column(s) to group by
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $orderBy Order the window functions by these column(s)
+    /// @param string $mappingStrategy One of: 'group_to_rows', 'join', 'explode'
+    /// @throws Polars\Exception
+    #[php(defaults(descending = false, nullsLast = false, mappingStrategy = "group_to_rows".to_string()))]
+    pub fn over(
+        &self,
+        partitionBy: Option<&Zval>,
+        orderBy: Option<&Zval>,
+        descending: bool,
+        nullsLast: bool,
+        mappingStrategy: String,
+    ) -> ExtResult<Self> {
+        let partition_by = match partitionBy {
+            Some(value) if !value.is_null() => Some(zval_to_column_exprs(value)?),
+            _ => None,
+        };
+        let order_by = match orderBy {
+            Some(value) if !value.is_null() => Some((
+                zval_to_column_exprs(value)?,
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nullsLast),
+            )),
+            _ => None,
+        };
+        let mapping = match mappingStrategy.as_str() {
+            "group_to_rows" => WindowMapping::GroupsToRows,
+            "join" => WindowMapping::Join,
+            "explode" => WindowMapping::Explode,
+            _ => {
+                return Err(PolarsException::new(format!(
+                    "Invalid mapping strategy: {}. Use 'group_to_rows', 'join', or 'explode'",
+                    mappingStrategy
+                )));
+            }
+        };
+        let expr = self
+            .0
+            .clone()
+            .over_with_options(partition_by, order_by, mapping)
+            .map_err(|e| PolarsException::new(format!("over() failed: {}", e)))?;
+        Ok(expr.into())
+    }
+
+    // BOOLEAN //
+
+    #[php(name = "hasNulls")]
+    pub fn has_nulls(&self) -> Self {
+        self.0.clone().null_count().gt(0).into()
+    }
+
+    /// Set an alias for the expression
+    pub fn alias(&self, name: String) -> Self {
+        self.0.clone().alias(&name).into()
+    }
+
+    /// Access methods modifying the output name of the expression
+    pub fn name(&self) -> PolarsExprNameNameSpace {
+        self.0.clone().into()
+    }
+
+    // /// Exclude column or columns
+    // pub fn exclude(&self, columns: Vec<String>) -> ExtResult<Self> {
+    //     self.0
+    //         .clone()
+    //         .into_selector()
+    //         .or_else(|| PolarsException::new("Failed to create selector".to_string()))?
+    //         .exclude_cols(&columns)
+    //         .into()
+    // }
+
+    /// Shift values by n positions
+    pub fn shift(&self, n: &Zval) -> ExtResult<Self> {
+        let n_expr = zval_to_expr(n)?;
+        Ok(self.0.clone().shift(n_expr).into())
+    }
+
+    /// Take every nth value
+    #[php(name = "gatherEvery", defaults(offset = 0))]
+    pub fn gather_every(&self, n: i64, offset: i64) -> Self {
+        self.0
+            .clone()
+            .gather_every(n as usize, offset as usize)
+            .into()
+    }
+
+    /// Cast to a data type
+    pub fn cast(&self, dtype: String) -> ExtResult<Self> {
+        let target = crate::common::parse_dtype(&dtype)?;
+        Ok(self.0.clone().cast(target).into())
+    }
+
+    #[php(name = "isBetween")]
+    pub fn is_between(
+        &self,
+        lowerBound: &Zval,
+        upperBound: &Zval,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let lower = zval_to_expr(lowerBound)?;
+        let upper = zval_to_expr(upperBound)?;
+        Ok(self
+            .0
+            .clone()
+            .is_between(lower, upper, closed.into())
+            .into())
+    }
+
+    /// Exclude specific columns from the expression
+    pub fn exclude(&self, columns: &Zval) -> ExtResult<Self> {
+        let names = match columns.get_type() {
+            DataType::String => {
+                vec![columns.str().unwrap().to_string()]
+            }
+            DataType::Array => {
+                let array = columns.array().unwrap();
+                let mut names = Vec::new();
+
+                for (_, val) in array.iter() {
+                    if val.get_type() == DataType::String {
+                        names.push(val.str().unwrap().to_string());
+                    } else {
+                        return Err(PolarsException::new(
+                            "exclude() with array requires all elements to be strings".to_string(),
+                        ));
+                    }
+                }
+                names
+            }
+            DataType::Object(_) => {
+                let object: &ZendObject = columns.object().unwrap();
+                if !object.is_instance::<PolarsExpr>() {
+                    return Err(PolarsException::new(
+                        "Passed object is not of class Polars\\Expr".to_string(),
+                    ));
+                }
+                let inner_expr = columns.extract::<&PolarsExpr>().unwrap().0.clone();
+                if let Expr::Column(name) = inner_expr {
+                    vec![name.to_string()]
+                } else {
+                    return Err(PolarsException::new(
+                        "exclude() object must be a column expression".to_string(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(PolarsException::new(
+                    "exclude() accepts a string, array of strings, or Polars\\Expr".to_string(),
+                ));
+            }
+        };
+
+        // In polars 0.52, Expr doesn't have .exclude().
+        // Instead, we use the Selector's .exclude_cols() if the original expr can be treated as a selector.
+        if let Some(sel) = self.0.clone().into_selector() {
+            Ok(PolarsExpr(sel.exclude_cols(names).as_expr()))
+        } else {
+            Err(PolarsException::new("exclude() can only be called on expressions that can be treated as selectors (e.g. all(), col())".to_string()))
+        }
+    }
+}
+
+/// Methods that are hidden from PHP stubs
+impl PolarsExpr {
+    pub fn get_expr(&self) -> &Expr {
+        &self.0
+    }
+}
+
+impl From<Expr> for PolarsExpr {
+    fn from(expr: Expr) -> Self {
+        PolarsExpr(expr)
+    }
+}
+
+impl From<PolarsExpr> for Expr {
+    fn from(polars_expr: PolarsExpr) -> Expr {
+        polars_expr.0
+    }
+}
+
+impl From<&PolarsExpr> for Expr {
+    fn from(polars_expr: &PolarsExpr) -> Expr {
+        polars_expr.0.clone()
+    }
+}
+
+// impl FromZval<'_> for PolarsExpr {
+//     const TYPE: DataType = DataType::Object(Some("Polars\\Expr"));
+//
+//     fn from_zval(zval: &Zval) -> Option<Self> {
+//         //let object: &ZendObject = value.object().unwrap();
+//         //                 if !object.is_instance::<PolarsExpr>() {
+//         //                     return Err(PolarsException::new("Passed object is not of class Polars\\Expr".to_string()));
+//         //                 }
+//         //                 value.extract::<&PolarsExpr>().unwrap().into()
+//     }
+// }
+
+#[php_enum]
+#[php(name = "Polars\\ClosedInterval")]
+pub enum PolarsClosedInterval {
+    Both,
+    Left,
+    Right,
+    None,
+}
+
+impl From<PolarsClosedInterval> for ClosedWindow {
+    fn from(polars_closed_interval: PolarsClosedInterval) -> Self {
+        match polars_closed_interval {
+            PolarsClosedInterval::Both => ClosedWindow::Both,
+            PolarsClosedInterval::Left => ClosedWindow::Left,
+            PolarsClosedInterval::Right => ClosedWindow::Right,
+            PolarsClosedInterval::None => ClosedWindow::None,
+        }
+    }
+}
+
+impl From<PolarsClosedInterval> for ClosedInterval {
+    fn from(polars_closed_interval: PolarsClosedInterval) -> Self {
+        match polars_closed_interval {
+            PolarsClosedInterval::Both => ClosedInterval::Both,
+            PolarsClosedInterval::Left => ClosedInterval::Left,
+            PolarsClosedInterval::Right => ClosedInterval::Right,
+            PolarsClosedInterval::None => ClosedInterval::None,
+        }
+    }
+}
+
+#[php_enum]
+#[php(name = "Polars\\QuantileMethod")]
+pub enum PolarsQuantileMethod {
+    Nearest,
+    Lower,
+    Higher,
+    Midpoint,
+    Linear,
+    Equiprobable,
+}
+
+impl From<PolarsQuantileMethod> for QuantileMethod {
+    fn from(method: PolarsQuantileMethod) -> Self {
         match method {
             PolarsQuantileMethod::Nearest => QuantileMethod::Nearest,
             PolarsQuantileMethod::Lower => QuantileMethod::Lower,
@@ -515,6 +2226,469 @@ impl From<PolarsQuantileMethod> for QuantileMethod {
 //     }
 // }
 
+/// Number of nanoseconds in a day, the exclusive upper bound of Time values
+const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// Convert an optional PHP value to a data type, treating null as absent
+fn optional_zval_to_dtype(value: Option<&Zval>) -> ExtResult<Option<PolarsDataType>> {
+    match value {
+        Some(value) if !value.is_null() => Ok(Some(zval_to_dtype(value)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Convert a string to a column and any other value to a literal
+fn zval_to_column_or_value_expr(value: &Zval) -> ExtResult<Expr> {
+    match value.str() {
+        Some(name) => Ok(col(name)),
+        None => zval_to_expr(value),
+    }
+}
+
+/// Convert a temporal string (e.g. '2024-01-31', '2024-01-31 12:00:00' or '12:00') or Expr
+/// to an expression of the given temporal data type
+fn zval_to_temporal_expr(value: &Zval, dtype: PolarsDataType) -> ExtResult<Expr> {
+    if let Some(text) = value.str() {
+        if dtype == PolarsDataType::Time {
+            let nanoseconds = parse_time_of_day(text).ok_or_else(|| {
+                PolarsException::new(format!("Invalid time: {}. Use 'HH:MM[:SS[.f]]'", text))
+            })?;
+            return Ok(lit(nanoseconds).cast(dtype));
+        }
+        return Ok(lit(text).strict_cast(dtype));
+    }
+    if let Some(object) = value.object()
+        && object.is_instance::<PolarsExpr>()
+    {
+        return Ok(value.extract::<&PolarsExpr>().unwrap().into());
+    }
+    Err(PolarsException::new(
+        "Expected a date/time string or Polars\\Expr".to_string(),
+    ))
+}
+
+/// Parse 'HH:MM[:SS[.fraction]]' to nanoseconds since midnight
+fn parse_time_of_day(text: &str) -> Option<i64> {
+    let mut parts = text.split(':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let (seconds, fraction) = match parts.next() {
+        Some(seconds) => match seconds.split_once('.') {
+            Some((seconds, fraction)) => (seconds.parse().ok()?, fraction),
+            None => (seconds.parse().ok()?, ""),
+        },
+        None => (0i64, ""),
+    };
+    if parts.next().is_some()
+        || !(0..24).contains(&hours)
+        || !(0..60).contains(&minutes)
+        || !(0..60).contains(&seconds)
+        || fraction.len() > 9
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let fraction_ns = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", fraction).parse::<i64>().ok()?
+    };
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1_000_000_000 + fraction_ns)
+}
+
+/// Parse a time unit name ('ns', 'us' or 'ms')
+fn parse_time_unit(unit: &str) -> ExtResult<TimeUnit> {
+    match unit {
+        "ns" => Ok(TimeUnit::Nanoseconds),
+        "us" => Ok(TimeUnit::Microseconds),
+        "ms" => Ok(TimeUnit::Milliseconds),
+        _ => Err(PolarsException::new(format!(
+            "Invalid time unit: {}. Use 'ns', 'us', or 'ms'",
+            unit
+        ))),
+    }
+}
+
+/// Repeat a number `n` times (defaults to the column length) as a column named `name`
+fn repeat_number(
+    value: i64,
+    n: Option<&Zval>,
+    dtype: Option<&Zval>,
+    name: &str,
+) -> ExtResult<PolarsExpr> {
+    let n = optional_zval_to_expr(n)?.unwrap_or_else(len);
+    let dtype = optional_zval_to_dtype(dtype)?.unwrap_or(PolarsDataType::Float64);
+    Ok(PolarsExpr(repeat(lit(value).cast(dtype), n).alias(name)))
+}
+
+/// Build a horizontal expression from one or more column expressions
+fn horizontal<F>(exprs: &Zval, build: F) -> ExtResult<PolarsExpr>
+where
+    F: FnOnce(Vec<Expr>) -> PolarsResult<Expr>,
+{
+    let exprs = zval_to_column_exprs(exprs)?;
+    if exprs.is_empty() {
+        return Err(PolarsException::new(
+            "At least one expression must be given".to_string(),
+        ));
+    }
+    build(exprs)
+        .map(PolarsExpr)
+        .map_err(|e| PolarsException::new(format!("Cannot build horizontal expression: {}", e)))
+}
+
+// `sum_horizontal`, `mean_horizontal`, `min_horizontal` and `max_horizontal` mirror the Polars DSL
+// functions of the same name. Polars 0.52 re-exports those through overlapping glob imports,
+// which rustc rejects as ambiguous in any crate that imports them.
+
+/// Sum expressions row by row
+fn sum_horizontal(exprs: Vec<Expr>, ignore_nulls: bool) -> PolarsResult<Expr> {
+    Ok(Expr::n_ary(
+        FunctionExpr::SumHorizontal { ignore_nulls },
+        exprs,
+    ))
+}
+
+/// Average expressions row by row
+fn mean_horizontal(exprs: Vec<Expr>, ignore_nulls: bool) -> PolarsResult<Expr> {
+    Ok(Expr::n_ary(
+        FunctionExpr::MeanHorizontal { ignore_nulls },
+        exprs,
+    ))
+}
+
+/// Minimum of expressions row by row
+fn min_horizontal(exprs: Vec<Expr>) -> PolarsResult<Expr> {
+    Ok(Expr::n_ary(FunctionExpr::MinHorizontal, exprs))
+}
+
+/// Maximum of expressions row by row
+fn max_horizontal(exprs: Vec<Expr>) -> PolarsResult<Expr> {
+    Ok(Expr::n_ary(FunctionExpr::MaxHorizontal, exprs))
+}
+
+/// Parse a correlation method name
+pub fn parse_correlation_method(
+    method: &str,
+    propagate_nans: bool,
+) -> ExtResult<CorrelationMethod> {
+    match method {
+        "pearson" => Ok(CorrelationMethod::Pearson),
+        "spearman" => Ok(CorrelationMethod::SpearmanRank(propagate_nans)),
+        _ => Err(PolarsException::new(format!(
+            "Invalid correlation method: {}. Use 'pearson' or 'spearman'",
+            method
+        ))),
+    }
+}
+
+/// Build a correlation or covariance expression between two columns
+pub fn correlation(a: Expr, b: Expr, method: CorrelationMethod) -> Expr {
+    match method {
+        CorrelationMethod::Pearson => pearson_corr(a, b),
+        CorrelationMethod::SpearmanRank(propagate_nans) => spearman_rank_corr(a, b, propagate_nans),
+        CorrelationMethod::Covariance(ddof) => cov(a, b, ddof),
+    }
+}
+
+/// Check that the number of bin labels matches the number of bins
+fn validate_bin_labels(labels: Option<&[String]>, bins: usize) -> ExtResult<()> {
+    match labels {
+        Some(labels) if labels.len() != bins => Err(PolarsException::new(format!(
+            "Expected {} labels, got {}",
+            bins,
+            labels.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Fold expressions by calling a PHP callable with `(Expr $acc, Expr $column)` for every column
+fn fold_with_callable(acc: Expr, function: &ZendCallable, exprs: Vec<Expr>) -> ExtResult<Expr> {
+    let mut acc = PolarsExpr(acc);
+    for expr in exprs {
+        let result = function
+            .try_call(vec![&acc, &PolarsExpr(expr)])
+            .map_err(|e| PolarsException::new(format!("Fold function failed: {}", e)))?;
+        acc = result.extract::<&PolarsExpr>().cloned().ok_or_else(|| {
+            PolarsException::new("Fold function must return a Polars\\Expr".to_string())
+        })?;
+    }
+    Ok(acc.0)
+}
+
+/// Convert an optional PHP value to an expression, treating null as absent
+pub fn optional_zval_to_expr(value: Option<&Zval>) -> ExtResult<Option<Expr>> {
+    match value {
+        Some(value) if !value.is_null() => Ok(Some(zval_to_expr(value)?)),
+        _ => Ok(None),
+    }
+}
+
+pub fn parse_round_mode(mode: &str) -> ExtResult<RoundMode> {
+    match mode {
+        "half_to_even" => Ok(RoundMode::HalfToEven),
+        "half_away_from_zero" => Ok(RoundMode::HalfAwayFromZero),
+        _ => Err(PolarsException::new(format!(
+            "Invalid round mode: {}. Use 'half_to_even' or 'half_away_from_zero'",
+            mode
+        ))),
+    }
+}
+
+/// Convert the number of consecutive nulls to fill, rejecting negative values
+fn fill_limit(limit: Option<i64>) -> ExtResult<Option<IdxSize>> {
+    limit
+        .map(|l| {
+            IdxSize::try_from(l).map_err(|_| {
+                PolarsException::new(format!("Invalid limit: {}. Must not be negative", l))
+            })
+        })
+        .transpose()
+}
+
+/// Parse a fill null strategy name; `limit` is only allowed for 'forward' and 'backward'
+pub fn parse_fill_null_strategy(strategy: &str, limit: Option<i64>) -> ExtResult<FillNullStrategy> {
+    let limit_value = fill_limit(limit)?;
+    let strategy = match strategy {
+        "forward" => return Ok(FillNullStrategy::Forward(limit_value)),
+        "backward" => return Ok(FillNullStrategy::Backward(limit_value)),
+        "min" => FillNullStrategy::Min,
+        "max" => FillNullStrategy::Max,
+        "mean" => FillNullStrategy::Mean,
+        "zero" => FillNullStrategy::Zero,
+        "one" => FillNullStrategy::One,
+        _ => {
+            return Err(PolarsException::new(format!(
+                "Invalid fill null strategy: {}. Use 'forward', 'backward', 'min', 'max', 'mean', 'zero', or 'one'",
+                strategy
+            )));
+        }
+    };
+    if limit.is_some() {
+        return Err(PolarsException::new(
+            "'limit' can only be used with the 'forward' or 'backward' strategy".to_string(),
+        ));
+    }
+    Ok(strategy)
+}
+
+/// Parse a duration string such as '7d', '2h30m' or '3i'
+pub fn parse_duration(duration: &str) -> ExtResult<Duration> {
+    Duration::try_parse(duration)
+        .map_err(|e| PolarsException::new(format!("Invalid duration '{}': {}", duration, e)))
+}
+
+/// Build options for exponentially weighted functions from exactly one of com, span, half life or alpha
+#[allow(clippy::too_many_arguments)]
+pub fn ewm_options(
+    com: Option<f64>,
+    span: Option<f64>,
+    half_life: Option<f64>,
+    alpha: Option<f64>,
+    adjust: bool,
+    bias: bool,
+    min_periods: i64,
+    ignore_nulls: bool,
+) -> ExtResult<EWMOptions> {
+    let alpha = match (com, span, half_life, alpha) {
+        (Some(com), None, None, None) => {
+            if com < 0.0 {
+                return Err(PolarsException::new("Require 'com' >= 0".to_string()));
+            }
+            1.0 / (1.0 + com)
+        }
+        (None, Some(span), None, None) => {
+            if span < 1.0 {
+                return Err(PolarsException::new("Require 'span' >= 1".to_string()));
+            }
+            2.0 / (span + 1.0)
+        }
+        (None, None, Some(half_life), None) => {
+            if half_life <= 0.0 {
+                return Err(PolarsException::new("Require 'halfLife' > 0".to_string()));
+            }
+            1.0 - (-std::f64::consts::LN_2 / half_life).exp()
+        }
+        (None, None, None, Some(alpha)) => {
+            if alpha <= 0.0 || alpha > 1.0 {
+                return Err(PolarsException::new("Require 0 < 'alpha' <= 1".to_string()));
+            }
+            alpha
+        }
+        _ => {
+            return Err(PolarsException::new(
+                "Exactly one of 'com', 'span', 'halfLife' or 'alpha' must be given".to_string(),
+            ));
+        }
+    };
+    Ok(EWMOptions {
+        alpha,
+        adjust,
+        bias,
+        min_periods: zval_to_length(min_periods, "minPeriods")?,
+        ignore_nulls,
+    })
+}
+
+/// Build options for rolling functions over a fixed number of values
+pub fn fixed_window_options(
+    window_size: i64,
+    min_periods: Option<i64>,
+    center: bool,
+    weights: Option<Vec<f64>>,
+    fn_params: Option<RollingFnParams>,
+) -> ExtResult<RollingOptionsFixedWindow> {
+    Ok(RollingOptionsFixedWindow {
+        window_size: zval_to_length(window_size, "windowSize")?,
+        min_periods: zval_to_length(min_periods.unwrap_or(window_size), "minPeriods")?,
+        weights,
+        center,
+        fn_params,
+    })
+}
+
+/// Build options for rolling functions over a duration string such as '7d' or '2h30m'
+pub fn dynamic_window_options(
+    window_size: &str,
+    min_periods: i64,
+    closed: PolarsClosedInterval,
+    fn_params: Option<RollingFnParams>,
+) -> ExtResult<RollingOptionsDynamicWindow> {
+    Ok(RollingOptionsDynamicWindow {
+        window_size: parse_duration(window_size)?,
+        min_periods: zval_to_length(min_periods, "minPeriods")?,
+        closed_window: closed.into(),
+        fn_params,
+    })
+}
+
+pub fn parse_null_behavior(null_behavior: &str) -> ExtResult<NullBehavior> {
+    match null_behavior {
+        "ignore" => Ok(NullBehavior::Ignore),
+        "drop" => Ok(NullBehavior::Drop),
+        _ => Err(PolarsException::new(format!(
+            "Invalid null behavior: {}. Use 'ignore' or 'drop'",
+            null_behavior
+        ))),
+    }
+}
+
+/// Convert a PHP array, Series or list Expr into an expression usable as the `other` side of `isIn`
+fn zval_to_collection_expr(value: &Zval) -> ExtResult<Expr> {
+    if let Some(array) = value.array() {
+        let values: Vec<Zval> = array.values().map(|v| v.shallow_clone()).collect();
+        return Ok(lit(zval_vec_to_series("", values)?).implode());
+    }
+    if let Some(object) = value.object() {
+        if object.is_instance::<PhpSeries>() {
+            let series = value.extract::<&PhpSeries>().unwrap();
+            return Ok(lit(series.inner.clone()).implode());
+        }
+        if object.is_instance::<PolarsExpr>() {
+            return Ok(value.extract::<&PolarsExpr>().unwrap().into());
+        }
+    }
+    Err(PolarsException::new(
+        "Expected an array, Polars\\Series or Polars\\Expr".to_string(),
+    ))
+}
+
+/// Convert an index, an array of indices, a Series or an Expr to an indices expression
+fn zval_to_indices_expr(value: &Zval) -> ExtResult<Expr> {
+    if let Some(index) = value.long() {
+        return Ok(lit(index));
+    }
+    if let Some(array) = value.array() {
+        let mut indices = Vec::with_capacity(array.len());
+        for (_, item) in array.iter() {
+            indices.push(
+                item.long()
+                    .ok_or_else(|| PolarsException::new("Indices must be integers".to_string()))?,
+            );
+        }
+        return Ok(lit(Series::new("".into(), indices)));
+    }
+    if let Some(series) = value.extract::<&PhpSeries>() {
+        return Ok(lit(series.inner.clone()));
+    }
+    if let Some(expr) = value.extract::<&PolarsExpr>() {
+        return Ok(expr.0.clone());
+    }
+    Err(PolarsException::new(
+        "Indices must be an int, an array of ints, Polars\\Series or Polars\\Expr".to_string(),
+    ))
+}
+
+/// Check that a number of bits to shift by is between 0 and 63
+fn bit_shift_amount(n: i64) -> ExtResult<u32> {
+    match u32::try_from(n) {
+        Ok(n) if n < 64 => Ok(n),
+        _ => Err(PolarsException::new(format!(
+            "Invalid shift: {}. Must be between 0 and 63",
+            n
+        ))),
+    }
+}
+
+/// Shift the bits of an integer column by `n` positions, keeping its data type
+fn shift_bits(column: Column, n: u32, left: bool) -> PolarsResult<Column> {
+    macro_rules! shift {
+        ($ca:expr, $t:ty) => {{
+            let ca = $ca;
+            let shifted = if left {
+                ca.apply_values(|v| v.checked_shl(n).unwrap_or(0))
+            } else {
+                // Shifting by the full width or more leaves only the sign
+                ca.apply_values(|v| v.checked_shr(n).unwrap_or((v >> (<$t>::BITS - 1)) >> 1))
+            };
+            shifted.into_series()
+        }};
+    }
+    let series = column.as_materialized_series();
+    let shifted = match series.dtype() {
+        PolarsDataType::Int8 => shift!(series.i8()?, i8),
+        PolarsDataType::Int16 => shift!(series.i16()?, i16),
+        PolarsDataType::Int32 => shift!(series.i32()?, i32),
+        PolarsDataType::Int64 => shift!(series.i64()?, i64),
+        PolarsDataType::UInt8 => shift!(series.u8()?, u8),
+        PolarsDataType::UInt16 => shift!(series.u16()?, u16),
+        PolarsDataType::UInt32 => shift!(series.u32()?, u32),
+        PolarsDataType::UInt64 => shift!(series.u64()?, u64),
+        dtype => polars_bail!(InvalidOperation: "bit shifts are not supported for {}", dtype),
+    };
+    Ok(shifted.into_column())
+}
+
+/// Check that a number of values is not negative
+fn non_negative_length(n: i64) -> ExtResult<usize> {
+    usize::try_from(n)
+        .map_err(|_| PolarsException::new(format!("Invalid length: {}. Must not be negative", n)))
+}
+
+/// Split a PHP associative array of old value => new value into two Series
+fn mapping_to_series(mapping: &ZendHashTable) -> ExtResult<(Series, Series)> {
+    let all_int_keys = mapping
+        .iter()
+        .all(|(key, _)| matches!(key, ArrayKey::Long(_)));
+    let old = if all_int_keys {
+        let keys: Vec<i64> = mapping
+            .iter()
+            .filter_map(|(key, _)| match key {
+                ArrayKey::Long(key) => Some(key),
+                _ => None,
+            })
+            .collect();
+        Series::new("".into(), keys)
+    } else {
+        let keys: Vec<String> = mapping.iter().map(|(key, _)| key.to_string()).collect();
+        Series::new("".into(), keys)
+    };
+    let values: Vec<Zval> = mapping.values().map(|v| v.shallow_clone()).collect();
+    let new = zval_vec_to_series("", values)?;
+    Ok((old, new))
+}
+
 pub fn zval_to_expr(value: &Zval) -> ExtResult<Expr> {
     Ok(
         match value.get_type() {
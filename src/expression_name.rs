@@ -0,0 +1,86 @@
+use crate::common::PhpCallable;
+use crate::exception::{ExtResult, PolarsException};
+use crate::expression::PolarsExpr;
+use ext_php_rs::prelude::*;
+use ext_php_rs::types::Zval;
+use polars::error::polars_err;
+use polars::lazy::dsl::Expr;
+use polars::prelude::{PlSmallStr, PlanCallback, PolarsResult};
+
+/// Methods modifying the output name of an expression, returned by `Expr::name()`
+#[php_class]
+#[php(name = "Polars\\ExprNameNameSpace")]
+#[derive(Clone, Debug)]
+pub struct PolarsExprNameNameSpace(Expr);
+
+#[php_impl]
+impl PolarsExprNameNameSpace {
+    /// Keep the original root column name, ignoring any alias
+    pub fn keep(&self) -> PolarsExpr {
+        self.0.clone().name().keep().into()
+    }
+
+    /// Add a prefix to the root column name
+    pub fn prefix(&self, prefix: String) -> PolarsExpr {
+        self.0.clone().name().prefix(&prefix).into()
+    }
+
+    /// Add a suffix to the root column name
+    pub fn suffix(&self, suffix: String) -> PolarsExpr {
+        self.0.clone().name().suffix(&suffix).into()
+    }
+
+    /// Convert the root column name to lowercase
+    pub fn to_lowercase(&self) -> PolarsExpr {
+        self.0.clone().name().to_lowercase().into()
+    }
+
+    /// Convert the root column name to uppercase
+    pub fn to_uppercase(&self) -> PolarsExpr {
+        self.0.clone().name().to_uppercase().into()
+    }
+
+    /// Rename the output by calling a PHP callable with the root column name.
+    /// The callable is invoked when the query is resolved and must return a string.
+    /// @param callable $function Callable with signature `fn(string $name): string`
+    /// @throws Polars\Exception
+    pub fn map(&self, function: &Zval) -> ExtResult<PolarsExpr> {
+        if !function.is_callable() {
+            return Err(PolarsException::new(
+                "Name mapping function must be callable".to_string(),
+            ));
+        }
+        let mapper = NameMapper(PhpCallable::new(function, "name mapping function"));
+        let callback = PlanCallback::new(move |name: PlSmallStr| mapper.call(&name));
+        Ok(self.0.clone().name().map(callback).into())
+    }
+
+    /// Add a prefix to every field name of a struct column
+    pub fn prefix_fields(&self, prefix: String) -> PolarsExpr {
+        self.0.clone().name().prefix_fields(&prefix).into()
+    }
+
+    /// Add a suffix to every field name of a struct column
+    pub fn suffix_fields(&self, suffix: String) -> PolarsExpr {
+        self.0.clone().name().suffix_fields(&suffix).into()
+    }
+}
+
+impl From<Expr> for PolarsExprNameNameSpace {
+    fn from(expr: Expr) -> Self {
+        PolarsExprNameNameSpace(expr)
+    }
+}
+
+/// PHP callable mapping a column name to a new one
+struct NameMapper(PhpCallable);
+
+impl NameMapper {
+    fn call(&self, name: &str) -> PolarsResult<PlSmallStr> {
+        let result = self.0.call(vec![&name.to_string()])?;
+        result
+            .str()
+            .map(PlSmallStr::from)
+            .ok_or_else(|| polars_err!(ComputeError: "name mapping function must return a string"))
+    }
+}
@@ -0,0 +1,371 @@
+use crate::common::{any_value_to_zval, extract_exprs, zval_to_length};
+use crate::data_frame::PhpDataFrame;
+use crate::exception::{ExtResult, PolarsException};
+use crate::expression::{PolarsExpr, PolarsQuantileMethod};
+use crate::lazy_group_by::all_expr;
+use ext_php_rs::prelude::*;
+use ext_php_rs::types::{ZendCallable, ZendHashTable, Zval};
+use ext_php_rs::zend::ce;
+use polars::lazy::dsl::{Expr, col, len, lit};
+use polars::prelude::{DataFrame, IntoLazy, LazyGroupBy, ListChunked, OptFlags, PlSmallStr};
+
+/// Name of the temporary row index column used to gather the rows of every group
+const GROUP_INDEX: &str = "__polars_php_group_index";
+
+/// Name of the temporary column holding the result of the `having()` predicate
+const HAVING_COLUMN: &str = "__polars_php_having";
+
+/// Eager group by, returned by `DataFrame::groupBy()`. Iterating over it yields
+/// `key tuple => DataFrame` pairs, one per group.
+#[php_class]
+#[php(name = "Polars\\GroupBy")]
+#[php(implements(ce = ce::aggregate, stub = "\\IteratorAggregate"))]
+#[derive(Clone)]
+pub struct PhpGroupBy {
+    df: DataFrame,
+    by: Vec<Expr>,
+    maintain_order: bool,
+}
+
+impl PhpGroupBy {
+    pub fn new(df: DataFrame, by: Vec<Expr>, maintain_order: bool) -> Self {
+        Self {
+            df,
+            by,
+            maintain_order,
+        }
+    }
+
+    fn lazy_group_by(&self, df: DataFrame) -> LazyGroupBy {
+        if self.maintain_order {
+            df.lazy().group_by_stable(self.by.clone())
+        } else {
+            df.lazy().group_by(self.by.clone())
+        }
+    }
+
+    /// Aggregate every group with given expressions and collect the result
+    fn aggregate(&self, exprs: Vec<Expr>) -> ExtResult<PhpDataFrame> {
+        let inner = self
+            .lazy_group_by(self.df.clone())
+            .agg(exprs)
+            .with_optimizations(OptFlags::EAGER)
+            .collect()
+            .map_err(|e| PolarsException::new(format!("Group by aggregation failed: {}", e)))?;
+        Ok(PhpDataFrame { inner })
+    }
+
+    /// Collect the key values and the row indices of every group
+    fn groups(&self) -> ExtResult<(DataFrame, ListChunked)> {
+        let df = self
+            .df
+            .clone()
+            .with_row_index(PlSmallStr::from_static(GROUP_INDEX), None)
+            .map_err(|e| PolarsException::new(format!("Failed to index rows: {}", e)))?;
+        let mut keys = self
+            .lazy_group_by(df)
+            .agg([col(GROUP_INDEX)])
+            .with_optimizations(OptFlags::EAGER)
+            .collect()
+            .map_err(|e| PolarsException::new(format!("Failed to compute groups: {}", e)))?;
+        let indices = keys
+            .drop_in_place(GROUP_INDEX)
+            .and_then(|c| c.list().cloned())
+            .map_err(|e| PolarsException::new(format!("Failed to compute groups: {}", e)))?;
+        Ok((keys, indices))
+    }
+}
+
+#[php_impl]
+#[php(change_method_case = "camelCase")]
+impl PhpGroupBy {
+    /// Iterate over the groups as `array $key => DataFrame $group` pairs
+    /// @return \Polars\GroupByIterator
+    /// @throws Polars\Exception
+    pub fn get_iterator(&self) -> ExtResult<PhpGroupByIterator> {
+        let (keys, indices) = self.groups()?;
+        Ok(PhpGroupByIterator {
+            df: self.df.clone(),
+            keys,
+            indices,
+            position: 0,
+        })
+    }
+
+    /// Aggregate using expressions
+    /// @param \Polars\Expr[] $expressions
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn agg(&self, expressions: &ZendHashTable) -> ExtResult<PhpDataFrame> {
+        self.aggregate(extract_exprs(expressions)?)
+    }
+
+    /// Number of rows per group
+    /// @param string $name Name of the length column
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    #[php(defaults(name = "len".to_string()))]
+    pub fn len(&self, name: String) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![len().alias(name)])
+    }
+
+    /// Number of non-null values per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn count(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().count()])
+    }
+
+    /// First row per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn first(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().first()])
+    }
+
+    /// Last row per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn last(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().last()])
+    }
+
+    /// First n rows per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    #[php(defaults(n = 5))]
+    pub fn head(&self, n: i64) -> ExtResult<PhpDataFrame> {
+        let inner = self
+            .lazy_group_by(self.df.clone())
+            .head(Some(zval_to_length(n, "n")?))
+            .with_optimizations(OptFlags::EAGER)
+            .collect()
+            .map_err(|e| PolarsException::new(format!("Group by head failed: {}", e)))?;
+        Ok(PhpDataFrame { inner })
+    }
+
+    /// Last n rows per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    #[php(defaults(n = 5))]
+    pub fn tail(&self, n: i64) -> ExtResult<PhpDataFrame> {
+        let inner = self
+            .lazy_group_by(self.df.clone())
+            .tail(Some(zval_to_length(n, "n")?))
+            .with_optimizations(OptFlags::EAGER)
+            .collect()
+            .map_err(|e| PolarsException::new(format!("Group by tail failed: {}", e)))?;
+        Ok(PhpDataFrame { inner })
+    }
+
+    /// Sum per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn sum(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().sum()])
+    }
+
+    /// Mean per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn mean(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().mean()])
+    }
+
+    /// Median per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn median(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().median()])
+    }
+
+    /// Min per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn min(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().min()])
+    }
+
+    /// Max per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn max(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().max()])
+    }
+
+    /// Number of unique values per group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn n_unique(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr().n_unique()])
+    }
+
+    /// Quantile per group
+    /// @param float $quantile Quantile between 0.0 and 1.0
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    #[php(defaults(interpolation = PolarsQuantileMethod::Nearest))]
+    pub fn quantile(
+        &self,
+        quantile: f64,
+        interpolation: PolarsQuantileMethod,
+    ) -> ExtResult<PhpDataFrame> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsException::new(
+                "quantile must be between 0 and 1".to_string(),
+            ));
+        }
+        self.aggregate(vec![
+            all_expr().quantile(lit(quantile), interpolation.into()),
+        ])
+    }
+
+    /// Aggregate the values of every group into lists
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn all(&self) -> ExtResult<PhpDataFrame> {
+        self.aggregate(vec![all_expr()])
+    }
+
+    /// Keep only the groups matching the predicate. The predicate is evaluated per group and
+    /// must aggregate to a single boolean, e.g. `Expr::col('a')->len()->gt(1)`.
+    /// @param \Polars\Expr $predicate Boolean aggregation
+    /// @return \Polars\GroupBy
+    /// @throws Polars\Exception
+    pub fn having(&self, predicate: &PolarsExpr) -> ExtResult<Self> {
+        let df = self
+            .df
+            .clone()
+            .with_row_index(PlSmallStr::from_static(GROUP_INDEX), None)
+            .map_err(|e| PolarsException::new(format!("Failed to index rows: {}", e)))?;
+        let matching = self
+            .lazy_group_by(df)
+            .agg([
+                col(GROUP_INDEX),
+                predicate.get_expr().clone().alias(HAVING_COLUMN),
+            ])
+            .filter(col(HAVING_COLUMN))
+            .select([col(GROUP_INDEX).explode().sort(Default::default())])
+            .with_optimizations(OptFlags::EAGER)
+            .collect()
+            .map_err(|e| {
+                PolarsException::new(format!(
+                    "Group by having failed, the predicate must aggregate to one boolean per group: {}",
+                    e
+                ))
+            })?;
+        let rows = matching
+            .column(GROUP_INDEX)
+            .and_then(|c| c.idx().cloned())
+            .map_err(|e| PolarsException::new(format!("Invalid group indices: {}", e)))?;
+        let df = self
+            .df
+            .take(&rows)
+            .map_err(|e| PolarsException::new(format!("Failed to gather groups: {}", e)))?;
+        Ok(Self::new(df, self.by.clone(), self.maintain_order))
+    }
+
+    /// Apply a PHP callable to every group and concatenate the results vertically.
+    /// The callable receives the group as a DataFrame and must return a DataFrame.
+    /// @param callable $function Callable with signature `fn(DataFrame $group): DataFrame`
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn map_groups(&self, function: ZendCallable) -> ExtResult<PhpDataFrame> {
+        let (_, indices) = self.groups()?;
+        let mut output: Option<DataFrame> = None;
+        for idx in 0..indices.len() {
+            let group = PhpDataFrame {
+                inner: take_group(&self.df, &indices, idx)?,
+            };
+            let result = function
+                .try_call(vec![&group])
+                .map_err(|e| PolarsException::new(format!("Map groups function failed: {}", e)))?;
+            let frame = result
+                .extract::<&PhpDataFrame>()
+                .map(|df| df.inner.clone())
+                .ok_or_else(|| {
+                    PolarsException::new(
+                        "Map groups function must return a Polars\\DataFrame".to_string(),
+                    )
+                })?;
+            match output.as_mut() {
+                Some(acc) => {
+                    acc.vstack_mut(&frame).map_err(|e| {
+                        PolarsException::new(format!("Failed to concatenate groups: {}", e))
+                    })?;
+                }
+                None => output = Some(frame),
+            }
+        }
+        Ok(PhpDataFrame {
+            inner: output.unwrap_or_else(|| self.df.clear()),
+        })
+    }
+}
+
+/// Gather the rows of the group at `idx`
+fn take_group(df: &DataFrame, indices: &ListChunked, idx: usize) -> ExtResult<DataFrame> {
+    let rows = indices
+        .get_as_series(idx)
+        .ok_or_else(|| PolarsException::new(format!("Group {} does not exist", idx)))?;
+    let rows = rows
+        .idx()
+        .map_err(|e| PolarsException::new(format!("Invalid group indices: {}", e)))?;
+    df.take(rows)
+        .map_err(|e| PolarsException::new(format!("Failed to gather group: {}", e)))
+}
+
+/// Iterator over the groups of a `Polars\GroupBy`
+#[php_class]
+#[php(name = "Polars\\GroupByIterator")]
+#[php(implements(ce = ce::iterator, stub = "\\Iterator"))]
+pub struct PhpGroupByIterator {
+    df: DataFrame,
+    keys: DataFrame,
+    indices: ListChunked,
+    position: usize,
+}
+
+#[php_impl]
+#[php(change_method_case = "camelCase")]
+impl PhpGroupByIterator {
+    /// Rows of the current group
+    /// @return \Polars\DataFrame
+    /// @throws Polars\Exception
+    pub fn current(&self) -> ExtResult<PhpDataFrame> {
+        Ok(PhpDataFrame {
+            inner: take_group(&self.df, &self.indices, self.position)?,
+        })
+    }
+
+    /// Key values of the current group, one per group by expression
+    /// @return array
+    /// @throws Polars\Exception
+    pub fn key(&self) -> ExtResult<Vec<Zval>> {
+        self.keys
+            .get_columns()
+            .iter()
+            .map(|column| {
+                let value = column
+                    .get(self.position)
+                    .map_err(|e| PolarsException::new(format!("Failed to get value: {}", e)))?;
+                any_value_to_zval(value)
+            })
+            .collect()
+    }
+
+    /// Move to the next group
+    pub fn next(&mut self) {
+        self.position += 1;
+    }
+
+    /// Move back to the first group
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    /// Whether the current position points at a group
+    pub fn valid(&self) -> bool {
+        self.position < self.indices.len()
+    }
+}
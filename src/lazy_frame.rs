@@ -1,15 +1,20 @@
 #![allow(non_snake_case)]
 
-use crate::common::extract_exprs;
+use crate::common::{
+    cast_exprs, extract_exprs, join_asof_lazy_frames, join_lazy_frames, pivot_aggregation,
+    sort_options, unpivot_args, zval_to_column_exprs, zval_to_length, zval_to_names,
+    zval_to_row_count,
+};
 use crate::data_frame::PhpDataFrame;
 use crate::data_type::PolarsDataType;
 use crate::exception::{ExtResult, PolarsException};
-use crate::expression::PolarsExpr;
-use crate::expression::PolarsQuantileMethod;
-use crate::lazy_group_by::PhpLazyGroupBy;
+use crate::expression::{PolarsClosedInterval, PolarsExpr};
+use crate::expression::{PolarsQuantileMethod, zval_to_expr};
+use crate::lazy_group_by::{PhpLazyGroupBy, group_by_dynamic, group_by_rolling};
 use ext_php_rs::prelude::*;
 use ext_php_rs::types::{ZendHashTable, Zval};
-use polars::prelude::{Expr, JoinArgs, JoinType, LazyCsvReader, LazyFileListReader, LazyFrame, LazyJsonLineReader, LiteralValue, PlPath, ScanArgsParquet, Selector, SortMultipleOptions, UniqueKeepStrategy, Scalar};
+use polars::lazy::dsl::all;
+use polars::prelude::{col, lit, Engine, Expr, InterpolationMethod, OptFlags, JoinBuilder, LazyCsvReader, LazyFileListReader, LazyFrame, LazyJsonLineReader, LiteralValue, PlPath, PlSmallStr, ScanArgsParquet, Selector, UniqueKeepStrategy, Scalar};
 
 #[php_class]
 #[php(name = "Polars\\LazyFrame")]
@@ -64,17 +69,100 @@ impl PhpLazyFrame {
 
     // Core //
 
-    /// Execute the lazy query and return a DataFrame
+    /// Execute the lazy query and return a DataFrame.
+    /// Optimization toggles left as null keep the setting of the LazyFrame.
+    /// @param string $engine Engine to run the query on: 'auto', 'in-memory' or 'streaming'
+    /// @param bool|null $typeCoercion Coerce types so that operations succeed
+    /// @param bool|null $predicatePushdown Apply filters as early as possible
+    /// @param bool|null $projectionPushdown Only read columns that are used later in the query
+    /// @param bool|null $simplifyExpression Run expression simplification rules
+    /// @param bool|null $slicePushdown Push slices and limits down to the scans
+    /// @param bool|null $commSubplanElim Cache duplicated subplans
+    /// @param bool|null $commSubexprElim Cache duplicated expressions
+    /// @param bool|null $clusterWithColumns Combine sequential independent `withColumns()` calls
+    /// @param bool $noOptimization Turn off all optimizations except type coercion and type checking before applying the toggles
     /// @return \Polars\DataFrame
-    pub fn collect(&self) -> ExtResult<PhpDataFrame> {
+    /// @throws Polars\Exception
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(engine = "auto".to_string(), noOptimization = false))]
+    pub fn collect(
+        &self,
+        engine: String,
+        typeCoercion: Option<bool>,
+        predicatePushdown: Option<bool>,
+        projectionPushdown: Option<bool>,
+        simplifyExpression: Option<bool>,
+        slicePushdown: Option<bool>,
+        commSubplanElim: Option<bool>,
+        commSubexprElim: Option<bool>,
+        clusterWithColumns: Option<bool>,
+        noOptimization: bool,
+    ) -> ExtResult<PhpDataFrame> {
+        let engine = parse_engine(&engine)?;
+        let flags = optimization_flags(
+            self.inner.get_current_optimizations(),
+            noOptimization,
+            typeCoercion,
+            predicatePushdown,
+            projectionPushdown,
+            simplifyExpression,
+            slicePushdown,
+            commSubplanElim,
+            commSubexprElim,
+            clusterWithColumns,
+        );
         let df = self
             .inner
             .clone()
-            .collect()
+            .with_optimizations(flags)
+            .collect_with_engine(engine)
             .map_err(|e| PolarsException::new(format!("Failed to collect LazyFrame: {}", e)))?;
         Ok(df.into())
     }
 
+    /// Set the optimizations used when the query is collected, explained or profiled.
+    /// Toggles left as null keep the current setting.
+    /// @param bool|null $typeCoercion Coerce types so that operations succeed
+    /// @param bool|null $predicatePushdown Apply filters as early as possible
+    /// @param bool|null $projectionPushdown Only read columns that are used later in the query
+    /// @param bool|null $simplifyExpression Run expression simplification rules
+    /// @param bool|null $slicePushdown Push slices and limits down to the scans
+    /// @param bool|null $commSubplanElim Cache duplicated subplans
+    /// @param bool|null $commSubexprElim Cache duplicated expressions
+    /// @param bool|null $clusterWithColumns Combine sequential independent `withColumns()` calls
+    /// @param bool $noOptimization Turn off all optimizations except type coercion and type checking before applying the toggles
+    /// @return \Polars\LazyFrame
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(noOptimization = false))]
+    pub fn with_optimizations(
+        &self,
+        typeCoercion: Option<bool>,
+        predicatePushdown: Option<bool>,
+        projectionPushdown: Option<bool>,
+        simplifyExpression: Option<bool>,
+        slicePushdown: Option<bool>,
+        commSubplanElim: Option<bool>,
+        commSubexprElim: Option<bool>,
+        clusterWithColumns: Option<bool>,
+        noOptimization: bool,
+    ) -> Self {
+        let flags = optimization_flags(
+            self.inner.get_current_optimizations(),
+            noOptimization,
+            typeCoercion,
+            predicatePushdown,
+            projectionPushdown,
+            simplifyExpression,
+            slicePushdown,
+            commSubplanElim,
+            commSubexprElim,
+            clusterWithColumns,
+        );
+        Self {
+            inner: self.inner.clone().with_optimizations(flags),
+        }
+    }
+
     /// Select columns by expression
     /// @param \Polars\Expr[] $expressions
     /// @return \Polars\LazyFrame
@@ -106,23 +194,171 @@ impl PhpLazyFrame {
 
     /// Group by expressions
     /// @param \Polars\Expr[] $expressions
+    /// @param bool $maintainOrder Keep groups in the order of their first appearance
     /// @return \Polars\LazyGroupBy
-    #[php(name = "groupBy")]
-    pub fn group_by(&self, expressions: &ZendHashTable) -> ExtResult<PhpLazyGroupBy> {
+    #[php(name = "groupBy", defaults(maintainOrder = false))]
+    pub fn group_by(
+        &self,
+        expressions: &ZendHashTable,
+        maintainOrder: bool,
+    ) -> ExtResult<PhpLazyGroupBy> {
         let exprs = extract_exprs(expressions)?;
-        Ok(PhpLazyGroupBy::new(self.inner.clone(), exprs))
+        let gb = if maintainOrder {
+            self.inner.clone().group_by_stable(exprs)
+        } else {
+            self.inner.clone().group_by(exprs)
+        };
+        Ok(PhpLazyGroupBy::new(gb))
+    }
+
+    /// Group by time windows of the index column, optionally per group of `groupBy` keys.
+    /// The index column must be sorted (within each group)
+    /// @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+    /// @param string $every Interval between the starts of consecutive windows, e.g. '15m' or '2i'
+    /// @param string|null $period Length of each window (default: $every)
+    /// @param string|null $offset Offset of the window starts (default: zero)
+    /// @param bool $includeBoundaries Add '_lower_boundary' and '_upper_boundary' columns
+    /// @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+    /// @param string $label Window label in the index column: 'left', 'right', 'datapoint'
+    /// @param string $startBy Start of the first window: 'window', 'datapoint' or a day of the week such as 'monday'
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+    /// @return \Polars\LazyGroupBy
+    /// @throws Polars\Exception
+    #[php(name = "groupByDynamic")]
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(
+        includeBoundaries = false,
+        closed = PolarsClosedInterval::Left,
+        label = "left".to_string(),
+        startBy = "window".to_string()
+    ))]
+    pub fn group_by_dynamic(
+        &self,
+        indexColumn: &Zval,
+        every: String,
+        period: Option<String>,
+        offset: Option<String>,
+        includeBoundaries: bool,
+        closed: PolarsClosedInterval,
+        label: String,
+        startBy: String,
+        groupBy: Option<&Zval>,
+    ) -> ExtResult<PhpLazyGroupBy> {
+        group_by_dynamic(
+            self.inner.clone(),
+            indexColumn,
+            &every,
+            period,
+            offset,
+            includeBoundaries,
+            closed,
+            &label,
+            &startBy,
+            groupBy,
+        )
+    }
+
+    /// Group by lookback windows anchored on every row of the index column, optionally per group of `groupBy` keys.
+    /// The window of a row with index value `t` is `(t + offset, t + offset + period]` by default.
+    /// The index column must be sorted (within each group)
+    /// @param string|\Polars\Expr $indexColumn Temporal or integer column defining the windows
+    /// @param string $period Length of each window, e.g. '30d' or '3i'
+    /// @param string|null $offset Offset of the window starts (default: -$period)
+    /// @param \Polars\ClosedInterval $closed Which window bounds are inclusive
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[]|null $groupBy Additional group keys
+    /// @return \Polars\LazyGroupBy
+    /// @throws Polars\Exception
+    #[php(defaults(closed = PolarsClosedInterval::Right))]
+    pub fn rolling(
+        &self,
+        indexColumn: &Zval,
+        period: String,
+        offset: Option<String>,
+        closed: PolarsClosedInterval,
+        groupBy: Option<&Zval>,
+    ) -> ExtResult<PhpLazyGroupBy> {
+        group_by_rolling(
+            self.inner.clone(),
+            indexColumn,
+            &period,
+            offset,
+            closed,
+            groupBy,
+        )
     }
 
-    /// Sort by a single column
+    /// Sort by one or more columns or expressions
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to sort by
+    /// @param bool|bool[]|null $descending Sort in descending order, one flag per key or a single flag for all
+    /// @param bool|bool[]|null $nullsLast Put null values last, one flag per key or a single flag for all (default: true)
+    /// @param bool $maintainOrder Maintain order of equal elements (stable sort)
+    /// @param bool $multithreaded Use multithreaded sorting
     /// @return \Polars\LazyFrame
-    #[php(defaults(descending = false, nullsLast = true))]
-    pub fn sort(&self, column: String, descending: bool, nullsLast: bool) -> Self {
-        let opts = SortMultipleOptions::new()
-            .with_order_descending(descending)
-            .with_nulls_last(nullsLast);
-        Self {
-            inner: self.inner.clone().sort([&column], opts),
-        }
+    #[php(defaults(maintainOrder = false, multithreaded = true))]
+    pub fn sort(
+        &self,
+        by: &Zval,
+        descending: Option<&Zval>,
+        nullsLast: Option<&Zval>,
+        maintainOrder: bool,
+        multithreaded: bool,
+    ) -> ExtResult<Self> {
+        let by = zval_to_column_exprs(by)?;
+        let opts = sort_options(descending, nullsLast, by.len(), maintainOrder, multithreaded)?;
+        Ok(Self {
+            inner: self.inner.clone().sort_by_exprs(by, opts),
+        })
+    }
+
+    /// Get the k rows with the largest values of the `by` column(s)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+    /// @param bool|bool[]|null $reverse Take the smallest values instead, one flag per key or a single flag for all
+    /// @return \Polars\LazyFrame
+    #[php(name = "topK")]
+    pub fn top_k(&self, k: i64, by: &Zval, reverse: Option<&Zval>) -> ExtResult<Self> {
+        let k = zval_to_row_count(k)?;
+        let by = zval_to_column_exprs(by)?;
+        let opts = sort_options(reverse, None, by.len(), false, true)?;
+        Ok(Self {
+            inner: self.inner.clone().top_k(k, by, opts),
+        })
+    }
+
+    /// Get the k rows with the smallest values of the `by` column(s)
+    /// @param string|string[]|\Polars\Expr|\Polars\Expr[] $by Column(s) to order by
+    /// @param bool|bool[]|null $reverse Take the largest values instead, one flag per key or a single flag for all
+    /// @return \Polars\LazyFrame
+    #[php(name = "bottomK")]
+    pub fn bottom_k(&self, k: i64, by: &Zval, reverse: Option<&Zval>) -> ExtResult<Self> {
+        let k = zval_to_row_count(k)?;
+        let by = zval_to_column_exprs(by)?;
+        let opts = sort_options(reverse, None, by.len(), false, true)?;
+        Ok(Self {
+            inner: self.inner.clone().bottom_k(k, by, opts),
+        })
+    }
+
+    /// Flag columns as sorted, so that later operations can use fast paths.
+    /// Flagging an unsorted column leads to incorrect results
+    /// @param string|string[] $column Column name(s)
+    /// @param bool $descending Whether the columns are sorted in descending order
+    /// @return \Polars\LazyFrame
+    #[php(name = "setSorted")]
+    #[php(defaults(descending = false))]
+    pub fn set_sorted(&self, column: &Zval, descending: bool) -> ExtResult<Self> {
+        use polars::series::IsSorted;
+        let sorted = if descending {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        };
+        let exprs: Vec<Expr> = zval_to_names(column, "column")?
+            .into_iter()
+            .map(|name| col(name).set_sorted_flag(sorted))
+            .collect();
+        Ok(Self {
+            inner: self.inner.clone().with_columns(exprs),
+        })
     }
 
     // Attributes //
@@ -218,6 +454,34 @@ impl PhpLazyFrame {
         self.head(n)
     }
 
+    /// Shift column values by n positions
+    /// @return \Polars\LazyFrame
+    pub fn shift(&self, n: i64) -> Self {
+        Self {
+            inner: self.inner.clone().shift(lit(n)),
+        }
+    }
+
+    /// Take every nth row
+    /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
+    #[php(defaults(offset = 0))]
+    pub fn gather_every(&self, n: i64, offset: i64) -> ExtResult<Self> {
+        if n < 1 {
+            return Err(PolarsException::new(format!(
+                "Invalid n: {}. Must be at least 1",
+                n
+            )));
+        }
+        let offset = zval_to_length(offset, "offset")?;
+        Ok(Self {
+            inner: self
+                .inner
+                .clone()
+                .select(&[Expr::from(all()).gather_every(n as usize, offset)]),
+        })
+    }
+
     // Aggregations //
 
     /// Return the number of non-null elements for each column
@@ -368,6 +632,143 @@ impl PhpLazyFrame {
         })
     }
 
+    /// Cast columns to different data types
+    /// @param array $dtypes Associative array of column name => data type string
+    /// @return \Polars\LazyFrame
+    #[php(defaults(strict = false))]
+    pub fn cast(&self, dtypes: &ZendHashTable, strict: bool) -> ExtResult<Self> {
+        let exprs = cast_exprs(dtypes, strict)?;
+        Ok(Self {
+            inner: self.inner.clone().with_columns(&exprs),
+        })
+    }
+
+    // Reshaping //
+
+    /// Explode list columns into rows
+    /// @param string[] $columns Column names to explode
+    /// @return \Polars\LazyFrame
+    pub fn explode(&self, columns: Vec<String>) -> Self {
+        let names: Vec<PlSmallStr> = columns.into_iter().map(PlSmallStr::from).collect();
+        let selector = Selector::ByName {
+            names: names.into(),
+            strict: false,
+        };
+        Self {
+            inner: self.inner.clone().explode(selector),
+        }
+    }
+
+    /// Unnest struct columns into separate columns
+    /// @param string[] $columns Names of struct columns to unnest
+    /// @return \Polars\LazyFrame
+    pub fn unnest(&self, columns: Vec<String>) -> Self {
+        let names: Vec<PlSmallStr> = columns.into_iter().map(PlSmallStr::from).collect();
+        let selector = Selector::ByName {
+            names: names.into(),
+            strict: true,
+        };
+        Self {
+            inner: self.inner.clone().unnest(selector, None),
+        }
+    }
+
+    /// Unpivot a LazyFrame from wide to long format
+    /// @param string[] $on Columns to use as values
+    /// @param string[] $index Columns to use as identifier
+    /// @param string|null $variableName Custom name for the variable column (default: 'variable')
+    /// @param string|null $valueName Custom name for the value column (default: 'value')
+    /// @return \Polars\LazyFrame
+    pub fn unpivot(
+        &self,
+        on: Vec<String>,
+        index: Vec<String>,
+        variableName: Option<String>,
+        valueName: Option<String>,
+    ) -> Self {
+        let args = unpivot_args(on, index, variableName, valueName);
+        Self {
+            inner: self.inner.clone().unpivot(args),
+        }
+    }
+
+    /// Unpivot (alias for unpivot, deprecated name)
+    /// @return \Polars\LazyFrame
+    pub fn melt(
+        &self,
+        on: Vec<String>,
+        index: Vec<String>,
+        variableName: Option<String>,
+        valueName: Option<String>,
+    ) -> Self {
+        self.unpivot(on, index, variableName, valueName)
+    }
+
+    /// Pivot a LazyFrame from long to wide format. The values of the `on` column that become
+    /// output columns must be given upfront, as the schema of a lazy query is known before it runs.
+    /// @param string $on Column whose values become the output columns
+    /// @param array $onColumns Values of the `on` column to turn into columns, in output order
+    /// @param string[]|null $index Column(s) to use as row index (default: all columns not in `on` and `values`)
+    /// @param string[]|null $values Column(s) to aggregate (default: all columns not in `on` and `index`)
+    /// @param string $aggregateFunction Aggregation function: 'first', 'last', 'sum', 'mean', 'median', 'min', 'max', 'count', 'len'
+    /// @param string $separator Separator between the value column name and the `on` value when aggregating multiple values
+    /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
+    #[php(defaults(aggregateFunction = "first".to_string(), separator = "_".to_string()))]
+    pub fn pivot(
+        &self,
+        on: String,
+        onColumns: &ZendHashTable,
+        index: Option<Vec<String>>,
+        values: Option<Vec<String>>,
+        aggregateFunction: String,
+        separator: String,
+    ) -> ExtResult<Self> {
+        let (index, values) = match (index, values) {
+            (Some(index), Some(values)) => (index, values),
+            (None, None) => {
+                return Err(PolarsException::new(
+                    "pivot requires at least one of index or values".to_string(),
+                ));
+            }
+            (index, values) => {
+                let schema = self
+                    .inner
+                    .clone()
+                    .collect_schema()
+                    .map_err(|e| PolarsException::new(format!("Failed to get schema: {}", e)))?;
+                let given = index.as_ref().or(values.as_ref()).cloned().unwrap_or_default();
+                let rest: Vec<String> = schema
+                    .iter_names()
+                    .map(|name| name.to_string())
+                    .filter(|name| *name != on && !given.contains(name))
+                    .collect();
+                match index {
+                    Some(index) => (index, rest),
+                    None => (rest, given),
+                }
+            }
+        };
+        let mut aggs = Vec::with_capacity(values.len() * onColumns.len());
+        for value in &values {
+            for (_, on_value) in onColumns.iter() {
+                let label = pivot_column_name(on_value)?;
+                let name = if values.len() == 1 {
+                    label
+                } else {
+                    format!("{}{}{}", value, separator, label)
+                };
+                let filtered =
+                    col(value.as_str()).filter(col(on.as_str()).eq(zval_to_expr(on_value)?));
+                aggs.push(pivot_aggregation(filtered, &aggregateFunction)?.alias(name));
+            }
+        }
+        let index: Vec<Expr> = index.iter().map(|name| col(name.as_str())).collect();
+        Ok(Self {
+            inner: self.inner.clone().group_by_stable(index).agg(aggs),
+        })
+    }
+
     // Null Handling //
 
     /// Drop rows with null values
@@ -410,38 +811,190 @@ impl PhpLazyFrame {
         })
     }
 
+    /// Drop rows with NaN values
+    /// @param string[]|null $subset Column names to check
+    /// @return \Polars\LazyFrame
+    pub fn drop_nans(&self, subset: Option<Vec<String>>) -> Self {
+        let selector = subset.map(|cols| {
+            let names: Vec<PlSmallStr> = cols.into_iter().map(PlSmallStr::from).collect();
+            Selector::ByName {
+                names: names.into(),
+                strict: false,
+            }
+        });
+        Self {
+            inner: self.inner.clone().drop_nans(selector),
+        }
+    }
+
+    /// Interpolate null values using linear interpolation
+    /// @return \Polars\LazyFrame
+    pub fn interpolate(&self) -> Self {
+        Self {
+            inner: self
+                .inner
+                .clone()
+                .with_columns(&[Expr::from(all()).interpolate(InterpolationMethod::Linear)]),
+        }
+    }
+
     // Join //
 
     /// Join with another LazyFrame
     /// @param \Polars\LazyFrame $other The right LazyFrame
-    /// @param \Polars\Expr[] $on Join columns (used for both left and right)
-    /// @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross'
-    /// @return \Polars\LazyFrame
-    #[php(defaults(how = "inner".to_string()))]
-    pub fn join(&self, other: &PhpLazyFrame, on: &ZendHashTable, how: String) -> ExtResult<Self> {
-        let exprs = extract_exprs(on)?;
-        let join_type = match how.as_str() {
-            "inner" => JoinType::Inner,
-            "left" => JoinType::Left,
-            "right" => JoinType::Right,
-            "full" => JoinType::Full,
-            "cross" => JoinType::Cross,
-            _ => {
-                return Err(PolarsException::new(format!(
-                    "Invalid join type: {}. Use 'inner', 'left', 'right', 'full', or 'cross'",
-                    how
-                )));
-            }
-        };
-        let lf = self.inner.clone().join(
+    /// @param \Polars\Expr[] $on Join columns (used for both left and right when leftOn/rightOn not given)
+    /// @param string $how Join type: 'inner', 'left', 'right', 'full', 'cross', 'semi', 'anti'
+    /// @param \Polars\Expr[]|null $leftOn Left join columns (overrides $on for the left side)
+    /// @param \Polars\Expr[]|null $rightOn Right join columns (overrides $on for the right side)
+    /// @param string|null $suffix Suffix for duplicate column names (default: '_right')
+    /// @param string|null $validate Join validation: 'm:m', 'm:1', '1:m', '1:1'
+    /// @param bool|null $coalesce Coalesce join columns
+    /// @param bool $nullsEqual Match null keys with each other
+    /// @param string $maintainOrder Row order to preserve: 'none', 'left', 'right', 'left_right', 'right_left'
+    /// @param bool $allowParallel Allow evaluating both sides in parallel
+    /// @param bool $forceParallel Force evaluating both sides in parallel
+    /// @return \Polars\LazyFrame
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(
+        how = "inner".to_string(),
+        nullsEqual = false,
+        maintainOrder = "none".to_string(),
+        allowParallel = true,
+        forceParallel = false
+    ))]
+    pub fn join(
+        &self,
+        other: &PhpLazyFrame,
+        on: &ZendHashTable,
+        how: String,
+        leftOn: Option<&ZendHashTable>,
+        rightOn: Option<&ZendHashTable>,
+        suffix: Option<String>,
+        validate: Option<String>,
+        coalesce: Option<bool>,
+        nullsEqual: bool,
+        maintainOrder: String,
+        allowParallel: bool,
+        forceParallel: bool,
+    ) -> ExtResult<Self> {
+        let lf = join_lazy_frames(
+            self.inner.clone(),
             other.inner.clone(),
-            &exprs,
-            &exprs,
-            JoinArgs::new(join_type),
-        );
+            on,
+            &how,
+            leftOn,
+            rightOn,
+            suffix,
+            validate,
+            coalesce,
+            nullsEqual,
+            &maintainOrder,
+            allowParallel,
+            forceParallel,
+        )?;
         Ok(Self { inner: lf })
     }
 
+    /// Perform an asof join with another LazyFrame
+    /// @param \Polars\LazyFrame $other The right LazyFrame
+    /// @param string|null $on Key column of both sides (must be sorted)
+    /// @param string|null $strategy Join strategy: 'backward', 'forward', 'nearest'
+    /// @param string|string[]|null $leftBy Group by columns of the left side
+    /// @param string|string[]|null $rightBy Group by columns of the right side
+    /// @param int|float|string|null $tolerance Maximum key distance, as a number or a duration string (e.g. "5m")
+    /// @param string|string[]|null $by Group by columns of both sides
+    /// @param string|null $leftOn Key column of the left side
+    /// @param string|null $rightOn Key column of the right side
+    /// @param string|null $suffix Suffix for duplicate column names (default: '_right')
+    /// @param bool $allowExactMatches Allow matching equal keys
+    /// @param bool $checkSortedness Check that the keys are sorted
+    /// @return \Polars\LazyFrame
+    #[php(name = "joinAsof")]
+    #[allow(clippy::too_many_arguments)]
+    #[php(defaults(allowExactMatches = true, checkSortedness = true))]
+    pub fn join_asof(
+        &self,
+        other: &PhpLazyFrame,
+        on: Option<String>,
+        strategy: Option<String>,
+        leftBy: Option<&Zval>,
+        rightBy: Option<&Zval>,
+        tolerance: Option<&Zval>,
+        by: Option<&Zval>,
+        leftOn: Option<String>,
+        rightOn: Option<String>,
+        suffix: Option<String>,
+        allowExactMatches: bool,
+        checkSortedness: bool,
+    ) -> ExtResult<Self> {
+        let lf = join_asof_lazy_frames(
+            self.inner.clone(),
+            other.inner.clone(),
+            on,
+            strategy,
+            leftBy,
+            rightBy,
+            tolerance,
+            by,
+            leftOn,
+            rightOn,
+            suffix,
+            allowExactMatches,
+            checkSortedness,
+        )?;
+        Ok(Self { inner: lf })
+    }
+
+    /// Join with another LazyFrame using arbitrary predicates, e.g. inequalities
+    /// @param \Polars\LazyFrame $other The right LazyFrame
+    /// @param \Polars\Expr[] $predicates Join predicates, all of which must hold
+    /// @param string|null $suffix Suffix for duplicate column names (default: '_right')
+    /// @return \Polars\LazyFrame
+    #[php(name = "joinWhere")]
+    pub fn join_where(
+        &self,
+        other: &PhpLazyFrame,
+        predicates: &ZendHashTable,
+        suffix: Option<String>,
+    ) -> ExtResult<Self> {
+        let pred_exprs = extract_exprs(predicates)?;
+        let mut builder = JoinBuilder::new(self.inner.clone()).with(other.inner.clone());
+        if let Some(s) = suffix {
+            builder = builder.suffix(s);
+        }
+        Ok(Self {
+            inner: builder.join_where(pred_exprs),
+        })
+    }
+
+    /// Create the Cartesian product with another LazyFrame
+    /// @param \Polars\LazyFrame $other The right LazyFrame
+    /// @param string|null $suffix Suffix for duplicate column names (default: '_right')
+    /// @return \Polars\LazyFrame
+    #[php(name = "crossJoin")]
+    pub fn cross_join(&self, other: &PhpLazyFrame, suffix: Option<String>) -> Self {
+        Self {
+            inner: self
+                .inner
+                .clone()
+                .cross_join(other.inner.clone(), suffix.map(PlSmallStr::from)),
+        }
+    }
+
+    /// Merge two sorted LazyFrames by a key column
+    /// @param \Polars\LazyFrame $other The other sorted LazyFrame
+    /// @param string $key The column to merge on (must be sorted in both LazyFrames)
+    /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
+    pub fn merge_sorted(&self, other: &PhpLazyFrame, key: String) -> ExtResult<Self> {
+        let inner = self
+            .inner
+            .clone()
+            .merge_sorted(other.inner.clone(), &key)
+            .map_err(|e| PolarsException::new(format!("mergeSorted failed: {}", e)))?;
+        Ok(Self { inner })
+    }
+
     // Miscellaneous //
 
     /// Add a row index column
@@ -467,18 +1020,40 @@ impl PhpLazyFrame {
     }
 
     /// Return the query plan as a string
+    /// @param bool $optimized Describe the plan after optimization
+    /// @param string $format Output format: 'plain', 'tree' or 'dot' (Graphviz)
     /// @return string
-    #[php(defaults(optimized = true))]
-    pub fn explain(&self, optimized: bool) -> ExtResult<String> {
-        if optimized {
-            self.inner
-                .describe_optimized_plan()
-                .map_err(|e| PolarsException::new(format!("Failed to describe plan: {}", e)))
-        } else {
-            self.inner
-                .describe_plan()
-                .map_err(|e| PolarsException::new(format!("Failed to describe plan: {}", e)))
-        }
+    /// @throws Polars\Exception
+    #[php(defaults(optimized = true, format = "plain".to_string()))]
+    pub fn explain(&self, optimized: bool, format: String) -> ExtResult<String> {
+        let plan = match (format.as_str(), optimized) {
+            ("plain", true) => self.inner.describe_optimized_plan(),
+            ("plain", false) => self.inner.describe_plan(),
+            ("tree", true) => self.inner.describe_optimized_plan_tree(),
+            ("tree", false) => self.inner.describe_plan_tree(),
+            ("dot", _) => self.inner.to_dot(optimized),
+            _ => {
+                return Err(PolarsException::new(format!(
+                    "Invalid format: {}. Use 'plain', 'tree', or 'dot'",
+                    format
+                )));
+            }
+        };
+        plan.map_err(|e| PolarsException::new(format!("Failed to describe plan: {}", e)))
+    }
+
+    /// Run the query and collect it together with the time spent in every node of the plan.
+    /// Returns `[DataFrame $result, DataFrame $timings]`; the timings DataFrame has the
+    /// columns `node`, `start` and `end`, in microseconds since the start of the query.
+    /// @return \Polars\DataFrame[]
+    /// @throws Polars\Exception
+    pub fn profile(&self) -> ExtResult<Vec<PhpDataFrame>> {
+        let (df, timings) = self
+            .inner
+            .clone()
+            .profile()
+            .map_err(|e| PolarsException::new(format!("Failed to profile LazyFrame: {}", e)))?;
+        Ok(vec![df.into(), timings.into()])
     }
 
     /// Cache the LazyFrame computation
@@ -596,3 +1171,70 @@ impl From<LazyFrame> for PhpLazyFrame {
         PhpLazyFrame { inner: lf }
     }
 }
+
+/// Name of the pivot output column created for a value of the `on` column
+fn pivot_column_name(value: &Zval) -> ExtResult<String> {
+    if let Some(s) = value.str() {
+        Ok(s.to_string())
+    } else if let Some(i) = value.long() {
+        Ok(i.to_string())
+    } else if let Some(f) = value.double() {
+        Ok(f.to_string())
+    } else if let Some(b) = value.bool() {
+        Ok(b.to_string())
+    } else {
+        Err(PolarsException::new(
+            "Pivot column values must be strings, numbers or booleans".to_string(),
+        ))
+    }
+}
+
+/// Parse the engine a query is collected on
+fn parse_engine(engine: &str) -> ExtResult<Engine> {
+    match engine {
+        "auto" => Ok(Engine::Auto),
+        "in-memory" => Ok(Engine::InMemory),
+        "streaming" => Ok(Engine::Streaming),
+        _ => Err(PolarsException::new(format!(
+            "Invalid engine: {}. Use 'auto', 'in-memory', or 'streaming'",
+            engine
+        ))),
+    }
+}
+
+/// Apply optimization toggles to the current flags. `None` keeps the current setting.
+#[allow(clippy::too_many_arguments)]
+fn optimization_flags(
+    current: OptFlags,
+    no_optimization: bool,
+    type_coercion: Option<bool>,
+    predicate_pushdown: Option<bool>,
+    projection_pushdown: Option<bool>,
+    simplify_expression: Option<bool>,
+    slice_pushdown: Option<bool>,
+    comm_subplan_elim: Option<bool>,
+    comm_subexpr_elim: Option<bool>,
+    cluster_with_columns: Option<bool>,
+) -> OptFlags {
+    let mut flags = if no_optimization {
+        OptFlags::schema_only()
+    } else {
+        current
+    };
+    let toggles = [
+        (OptFlags::TYPE_COERCION, type_coercion),
+        (OptFlags::PREDICATE_PUSHDOWN, predicate_pushdown),
+        (OptFlags::PROJECTION_PUSHDOWN, projection_pushdown),
+        (OptFlags::SIMPLIFY_EXPR, simplify_expression),
+        (OptFlags::SLICE_PUSHDOWN, slice_pushdown),
+        (OptFlags::COMM_SUBPLAN_ELIM, comm_subplan_elim),
+        (OptFlags::COMM_SUBEXPR_ELIM, comm_subexpr_elim),
+        (OptFlags::CLUSTER_WITH_COLUMNS, cluster_with_columns),
+    ];
+    for (flag, toggle) in toggles {
+        if let Some(enabled) = toggle {
+            flags.set(flag, enabled);
+        }
+    }
+    flags
+}
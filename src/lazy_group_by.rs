@@ -1,13 +1,26 @@
-use crate::common::extract_exprs;
-use crate::exception::ExtResult;
+use crate::common::{
+    PhpCallable, extract_exprs, zval_to_column_expr, zval_to_column_exprs, zval_to_length,
+    zval_to_schema,
+};
+use crate::data_frame::PhpDataFrame;
+use crate::exception::{ExtResult, PolarsException};
+use crate::expression::{PolarsClosedInterval, PolarsExpr, PolarsQuantileMethod, parse_duration};
 use crate::lazy_frame::PhpLazyFrame;
 use ext_php_rs::prelude::*;
-use ext_php_rs::types::ZendHashTable;
-use polars::prelude::LazyFrame;
-use polars::lazy::dsl::{all, Expr};
+use ext_php_rs::types::{ZendHashTable, Zval};
+use polars::error::polars_err;
+use polars::prelude::{
+    AnyValue, DataFrame, DynamicGroupOptions, IntoLazy, Label, LazyFrame, LazyGroupBy,
+    PlSmallStr, PlanCallback, PolarsResult, RollingGroupOptions, SchemaRef, StartBy, by_name,
+};
+use polars::lazy::dsl::{all, col, len, lit, Expr};
+use std::sync::Arc;
+
+/// Prefix of the temporary columns holding the results of `having()` predicates
+const HAVING_PREFIX: &str = "__polars_php_having_";
 
 /// Create a wildcard Expr that matches all columns
-fn all_expr() -> Expr {
+pub fn all_expr() -> Expr {
     Expr::from(all())
 }
 
@@ -15,13 +28,64 @@ fn all_expr() -> Expr {
 #[php(name = "Polars\\LazyGroupBy")]
 #[derive(Clone)]
 pub struct PhpLazyGroupBy {
-    lf: LazyFrame,
-    by: Vec<Expr>,
+    gb: LazyGroupBy,
+    predicates: Vec<Expr>,
 }
 
 impl PhpLazyGroupBy {
-    pub fn new(lf: LazyFrame, by: Vec<Expr>) -> Self {
-        Self { lf, by }
+    pub fn new(gb: LazyGroupBy) -> Self {
+        Self {
+            gb,
+            predicates: vec![],
+        }
+    }
+
+    /// `having()` predicates aliased to temporary column names
+    fn having_exprs(&self) -> (Vec<Expr>, Vec<PlSmallStr>) {
+        self.predicates
+            .iter()
+            .enumerate()
+            .map(|(i, predicate)| {
+                let name = PlSmallStr::from(format!("{HAVING_PREFIX}{i}"));
+                (predicate.clone().alias(name.clone()), name)
+            })
+            .unzip()
+    }
+
+    /// Aggregate every group and drop the groups not matching the `having()` predicates
+    fn aggregate(&self, mut aggs: Vec<Expr>) -> PhpLazyFrame {
+        let gb = self.gb.clone();
+        if self.predicates.is_empty() {
+            return PhpLazyFrame {
+                inner: gb.agg(aggs),
+            };
+        }
+        let (predicates, names) = self.having_exprs();
+        aggs.extend(predicates);
+        let mut inner = gb.agg(aggs);
+        for name in &names {
+            inner = inner.filter(col(name.clone()));
+        }
+        PhpLazyFrame {
+            inner: inner.drop(by_name(names, true)),
+        }
+    }
+
+    /// Aggregate every group into lists with `aggregation` and explode them back into rows
+    fn aggregate_rows(&self, aggregation: Expr) -> ExtResult<PhpLazyFrame> {
+        let keys: Vec<PlSmallStr> = self
+            .gb
+            .clone()
+            .agg(Vec::<Expr>::new())
+            .collect_schema()
+            .map_err(|e| PolarsException::new(format!("Failed to get schema: {}", e)))?
+            .iter_names()
+            .cloned()
+            .collect();
+        let lf = self.aggregate(vec![aggregation]).inner;
+        Ok(PhpLazyFrame {
+            inner: lf.explode(all() - by_name(keys, false)),
+        })
     }
 }
 
@@ -33,101 +97,315 @@ impl PhpLazyGroupBy {
     /// @return \Polars\LazyFrame
     pub fn agg(&self, expressions: &ZendHashTable) -> ExtResult<PhpLazyFrame> {
         let exprs = extract_exprs(expressions)?;
-        let gb = self.lf.clone().group_by(self.by.clone());
-        Ok(PhpLazyFrame {
-            inner: gb.agg(&exprs),
-        })
+        Ok(self.aggregate(exprs))
+    }
+
+    /// Keep only the groups matching the predicate. The predicate is evaluated per group,
+    /// so aggregations like `Expr::col('a')->sum()` refer to the group.
+    /// Calling it again adds another predicate.
+    /// @param \Polars\Expr $predicate Boolean expression
+    /// @return \Polars\LazyGroupBy
+    pub fn having(&self, predicate: &PolarsExpr) -> Self {
+        let mut gb = self.clone();
+        gb.predicates.push(predicate.get_expr().clone());
+        gb
+    }
+
+    /// Number of rows per group
+    /// @param string $name Name of the length column
+    /// @return \Polars\LazyFrame
+    #[php(defaults(name = "len".to_string()))]
+    pub fn len(&self, name: String) -> PhpLazyFrame {
+        self.aggregate(vec![len().alias(name)])
     }
 
     /// Count rows per group
     /// @return \Polars\LazyFrame
     pub fn count(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().count()]),
-        }
+        self.aggregate(vec![all_expr().count()])
     }
 
     /// First row per group
     /// @return \Polars\LazyFrame
     pub fn first(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().first()]),
-        }
+        self.aggregate(vec![all_expr().first()])
     }
 
     /// Last row per group
     /// @return \Polars\LazyFrame
     pub fn last(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().last()]),
-        }
+        self.aggregate(vec![all_expr().last()])
     }
 
     /// First n rows per group
     /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
     #[php(defaults(n = 5))]
-    pub fn head(&self, n: i64) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.head(Some(n as usize)),
+    pub fn head(&self, n: i64) -> ExtResult<PhpLazyFrame> {
+        let n = zval_to_length(n, "n")?;
+        if self.predicates.is_empty() {
+            let gb = self.gb.clone();
+            return Ok(PhpLazyFrame {
+                inner: gb.head(Some(n)),
+            });
         }
+        self.aggregate_rows(all_expr().head(Some(n)))
     }
 
     /// Last n rows per group
     /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
     #[php(defaults(n = 5))]
-    pub fn tail(&self, n: i64) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.tail(Some(n as usize)),
+    pub fn tail(&self, n: i64) -> ExtResult<PhpLazyFrame> {
+        let n = zval_to_length(n, "n")?;
+        if self.predicates.is_empty() {
+            let gb = self.gb.clone();
+            return Ok(PhpLazyFrame {
+                inner: gb.tail(Some(n)),
+            });
         }
+        self.aggregate_rows(all_expr().tail(Some(n)))
     }
 
     /// Sum per group
     /// @return \Polars\LazyFrame
     pub fn sum(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().sum()]),
-        }
+        self.aggregate(vec![all_expr().sum()])
     }
 
     /// Mean per group
     /// @return \Polars\LazyFrame
     pub fn mean(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().mean()]),
-        }
+        self.aggregate(vec![all_expr().mean()])
     }
 
     /// Median per group
     /// @return \Polars\LazyFrame
     pub fn median(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().median()]),
-        }
+        self.aggregate(vec![all_expr().median()])
     }
 
     /// Min per group
     /// @return \Polars\LazyFrame
     pub fn min(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().min()]),
-        }
+        self.aggregate(vec![all_expr().min()])
     }
 
     /// Max per group
     /// @return \Polars\LazyFrame
     pub fn max(&self) -> PhpLazyFrame {
-        let gb = self.lf.clone().group_by(self.by.clone());
-        PhpLazyFrame {
-            inner: gb.agg(&[all_expr().max()]),
+        self.aggregate(vec![all_expr().max()])
+    }
+
+    /// Number of unique values per group
+    /// @return \Polars\LazyFrame
+    pub fn n_unique(&self) -> PhpLazyFrame {
+        self.aggregate(vec![all_expr().n_unique()])
+    }
+
+    /// Standard deviation per group
+    /// @param int $ddof Delta degrees of freedom
+    /// @return \Polars\LazyFrame
+    #[php(defaults(ddof = 1))]
+    pub fn std(&self, ddof: u8) -> PhpLazyFrame {
+        self.aggregate(vec![all_expr().std(ddof)])
+    }
+
+    /// Variance per group
+    /// @param int $ddof Delta degrees of freedom
+    /// @return \Polars\LazyFrame
+    #[php(defaults(ddof = 1))]
+    pub fn variance(&self, ddof: u8) -> PhpLazyFrame {
+        self.aggregate(vec![all_expr().var(ddof)])
+    }
+
+    /// Quantile per group
+    /// @param float $quantile Quantile between 0.0 and 1.0
+    /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
+    #[php(defaults(interpolation = PolarsQuantileMethod::Nearest))]
+    pub fn quantile(
+        &self,
+        quantile: f64,
+        interpolation: PolarsQuantileMethod,
+    ) -> ExtResult<PhpLazyFrame> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsException::new(
+                "quantile must be between 0 and 1".to_string(),
+            ));
+        }
+        Ok(self.aggregate(vec![
+            all_expr().quantile(lit(quantile), interpolation.into()),
+        ]))
+    }
+
+    /// Aggregate the values of every group into lists
+    /// @return \Polars\LazyFrame
+    pub fn all(&self) -> PhpLazyFrame {
+        self.aggregate(vec![all_expr()])
+    }
+
+    /// Apply a PHP callable to every group and concatenate the results vertically.
+    /// The callable receives the group as a DataFrame and must return a DataFrame
+    /// matching `schema`. It is called when the query is collected with the in-memory engine.
+    /// @param callable $function Callable with signature `fn(DataFrame $group): DataFrame`
+    /// @param array|null $schema Output schema as column name => data type, defaults to the input schema
+    /// @return \Polars\LazyFrame
+    /// @throws Polars\Exception
+    #[php(defaults(schema = None))]
+    pub fn map_groups(
+        &self,
+        function: &Zval,
+        schema: Option<&ZendHashTable>,
+    ) -> ExtResult<PhpLazyFrame> {
+        if !function.is_callable() {
+            return Err(PolarsException::new(
+                "Map groups function must be callable".to_string(),
+            ));
+        }
+        let schema: SchemaRef = match schema {
+            Some(schema) => Arc::new(zval_to_schema(schema)?),
+            None => LazyFrame::from(self.gb.clone())
+                .collect_schema()
+                .map_err(|e| PolarsException::new(format!("Failed to get schema: {}", e)))?,
+        };
+        let (predicates, _) = self.having_exprs();
+        let mapper = GroupMapper {
+            function: PhpCallable::new(function, "map groups function"),
+            predicates,
+            schema: schema.clone(),
+        };
+        let callback = PlanCallback::new(move |df: DataFrame| mapper.call(df));
+        let gb = self.gb.clone();
+        Ok(PhpLazyFrame {
+            inner: gb.apply(callback, schema),
+        })
+    }
+}
+
+/// PHP callable applied to every group by `LazyGroupBy::mapGroups()`
+struct GroupMapper {
+    function: PhpCallable,
+    predicates: Vec<Expr>,
+    schema: SchemaRef,
+}
+
+impl GroupMapper {
+    fn call(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        if !self.matches(&df)? {
+            return Ok(DataFrame::empty_with_schema(&self.schema));
         }
+        let result = self.function.call(vec![&PhpDataFrame { inner: df }])?;
+        result
+            .extract::<&PhpDataFrame>()
+            .map(|df| df.inner.clone())
+            .ok_or_else(
+                || polars_err!(ComputeError: "map groups function must return a Polars\\DataFrame"),
+            )
+    }
+
+    /// Whether the group matches all `having()` predicates
+    fn matches(&self, df: &DataFrame) -> PolarsResult<bool> {
+        if self.predicates.is_empty() {
+            return Ok(true);
+        }
+        let result = df.clone().lazy().select(&self.predicates).collect()?;
+        Ok(result
+            .get_columns()
+            .iter()
+            .all(|column| matches!(column.get(0), Ok(AnyValue::Boolean(true)))))
+    }
+}
+
+/// Parse the label of dynamic group-by windows
+fn parse_label(label: &str) -> ExtResult<Label> {
+    match label {
+        "left" => Ok(Label::Left),
+        "right" => Ok(Label::Right),
+        "datapoint" => Ok(Label::DataPoint),
+        _ => Err(PolarsException::new(format!(
+            "Invalid label: {}. Use 'left', 'right', or 'datapoint'",
+            label
+        ))),
     }
 }
+
+/// Parse the strategy that determines the start of the first dynamic group-by window
+fn parse_start_by(start_by: &str) -> ExtResult<StartBy> {
+    match start_by {
+        "window" => Ok(StartBy::WindowBound),
+        "datapoint" => Ok(StartBy::DataPoint),
+        "monday" => Ok(StartBy::Monday),
+        "tuesday" => Ok(StartBy::Tuesday),
+        "wednesday" => Ok(StartBy::Wednesday),
+        "thursday" => Ok(StartBy::Thursday),
+        "friday" => Ok(StartBy::Friday),
+        "saturday" => Ok(StartBy::Saturday),
+        "sunday" => Ok(StartBy::Sunday),
+        _ => Err(PolarsException::new(format!(
+            "Invalid startBy: {}. Use 'window', 'datapoint', or a day of the week such as 'monday'",
+            start_by
+        ))),
+    }
+}
+
+/// Group a LazyFrame into time windows, shared by `DataFrame::groupByDynamic()` and `LazyFrame::groupByDynamic()`.
+/// `period` defaults to `every` and `offset` to zero.
+#[allow(clippy::too_many_arguments)]
+pub fn group_by_dynamic(
+    lf: LazyFrame,
+    index_column: &Zval,
+    every: &str,
+    period: Option<String>,
+    offset: Option<String>,
+    include_boundaries: bool,
+    closed: PolarsClosedInterval,
+    label: &str,
+    start_by: &str,
+    group_by: Option<&Zval>,
+) -> ExtResult<PhpLazyGroupBy> {
+    let index_column = zval_to_column_expr(index_column)?;
+    let group_by = match group_by {
+        Some(keys) if !keys.is_null() => zval_to_column_exprs(keys)?,
+        _ => vec![],
+    };
+    let every = parse_duration(every)?;
+    let options = DynamicGroupOptions {
+        every,
+        period: period.as_deref().map_or(Ok(every), parse_duration)?,
+        offset: parse_duration(offset.as_deref().unwrap_or("0ns"))?,
+        label: parse_label(label)?,
+        include_boundaries,
+        closed_window: closed.into(),
+        start_by: parse_start_by(start_by)?,
+        ..Default::default()
+    };
+    let gb = lf.group_by_dynamic(index_column, group_by, options);
+    Ok(PhpLazyGroupBy::new(gb))
+}
+
+/// Group a LazyFrame into lookback windows anchored on every row, shared by `DataFrame::rolling()`
+/// and `LazyFrame::rolling()`. Each window is `(t + offset, t + offset + period]`; `offset` defaults to `-period`.
+pub fn group_by_rolling(
+    lf: LazyFrame,
+    index_column: &Zval,
+    period: &str,
+    offset: Option<String>,
+    closed: PolarsClosedInterval,
+    group_by: Option<&Zval>,
+) -> ExtResult<PhpLazyGroupBy> {
+    let index_column = zval_to_column_expr(index_column)?;
+    let group_by = match group_by {
+        Some(keys) if !keys.is_null() => zval_to_column_exprs(keys)?,
+        _ => vec![],
+    };
+    let period = parse_duration(period)?;
+    let options = RollingGroupOptions {
+        period,
+        offset: offset.as_deref().map_or(Ok(-period), parse_duration)?,
+        closed_window: closed.into(),
+        ..Default::default()
+    };
+    let gb = lf.rolling(index_column, group_by, options);
+    Ok(PhpLazyGroupBy::new(gb))
+}
@@ -5,8 +5,11 @@ mod data_frame;
 mod data_type;
 mod exception;
 mod expression;
+mod expression_name;
+mod group_by;
 mod lazy_frame;
 mod lazy_group_by;
+mod selector;
 mod series;
 
 use ext_php_rs::prelude::*;
@@ -18,9 +21,14 @@ pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
         .class::<data_frame::PhpDataFrame>()
         .class::<series::PhpSeries>()
         .class::<expression::PolarsExpr>()
+        .class::<expression_name::PolarsExprNameNameSpace>()
         .class::<data_type::PolarsDataType>()
+        .class::<group_by::PhpGroupByIterator>()
+        .class::<group_by::PhpGroupBy>()
         .class::<lazy_frame::PhpLazyFrame>()
         .class::<lazy_group_by::PhpLazyGroupBy>()
+        .class::<selector::PolarsSelectors>()
+        .class::<selector::PolarsSelector>()
         .enumeration::<expression::PolarsClosedInterval>()
         .enumeration::<expression::PolarsQuantileMethod>()
 }
\ No newline at end of file
@@ -0,0 +1,195 @@
+use crate::common::zval_to_dtype;
+use crate::exception::ExtResult;
+use crate::expression::PolarsExpr;
+use ext_php_rs::prelude::*;
+use ext_php_rs::types::Zval;
+use polars::prelude::{DataType, DataTypeSelector, Selector};
+
+/// Factory of column selectors, the counterpart of Python's `polars.selectors` module.
+#[php_class]
+#[php(name = "Polars\\Selectors")]
+pub struct PolarsSelectors;
+
+#[php_impl]
+impl PolarsSelectors {
+    /// Select all columns
+    pub fn all() -> PolarsSelector {
+        Selector::Wildcard.into()
+    }
+
+    /// Select columns by name
+    pub fn by_name(names: Vec<String>) -> PolarsSelector {
+        Selector::ByName {
+            names: names.into_iter().map(Into::into).collect(),
+            strict: true,
+        }
+        .into()
+    }
+
+    /// Select columns by position. Negative indices count from the end.
+    pub fn by_index(indices: Vec<i64>) -> PolarsSelector {
+        Selector::ByIndex {
+            indices: indices.into(),
+            strict: true,
+        }
+        .into()
+    }
+
+    /// Select columns of given data types
+    /// @param string|\Polars\DataType|array $dtypes Data type name, DataType object or an array of them
+    /// @throws Polars\Exception
+    pub fn by_dtype(dtypes: &Zval) -> ExtResult<PolarsSelector> {
+        let dtypes: Vec<DataType> = match dtypes.array() {
+            Some(array) => array
+                .iter()
+                .map(|(_, value)| zval_to_dtype(value))
+                .collect::<ExtResult<_>>()?,
+            None => vec![zval_to_dtype(dtypes)?],
+        };
+        Ok(DataTypeSelector::AnyOf(dtypes.into()).as_selector().into())
+    }
+
+    /// Select all numeric (integer and float) columns
+    pub fn numeric() -> PolarsSelector {
+        DataTypeSelector::Numeric.as_selector().into()
+    }
+
+    /// Select all integer columns, signed and unsigned
+    pub fn integer() -> PolarsSelector {
+        DataTypeSelector::Integer.as_selector().into()
+    }
+
+    /// Select all float columns
+    pub fn float() -> PolarsSelector {
+        DataTypeSelector::Float.as_selector().into()
+    }
+
+    /// Select all string columns
+    pub fn string() -> PolarsSelector {
+        DataTypeSelector::AnyOf([DataType::String].into())
+            .as_selector()
+            .into()
+    }
+
+    /// Select all boolean columns
+    pub fn boolean() -> PolarsSelector {
+        DataTypeSelector::AnyOf([DataType::Boolean].into())
+            .as_selector()
+            .into()
+    }
+
+    /// Select all temporal (date, datetime, duration and time) columns
+    pub fn temporal() -> PolarsSelector {
+        DataTypeSelector::Temporal.as_selector().into()
+    }
+
+    /// Select columns whose name starts with any of given prefixes
+    pub fn starts_with(prefixes: Vec<String>) -> PolarsSelector {
+        Self::by_pattern(&prefixes, "^(", ")")
+    }
+
+    /// Select columns whose name ends with any of given suffixes
+    pub fn ends_with(suffixes: Vec<String>) -> PolarsSelector {
+        Self::by_pattern(&suffixes, "(", ")$")
+    }
+
+    /// Select columns whose name contains any of given substrings
+    pub fn contains(substrings: Vec<String>) -> PolarsSelector {
+        Self::by_pattern(&substrings, "(", ")")
+    }
+
+    /// Select columns whose name matches the regular expression
+    pub fn matches(pattern: String) -> PolarsSelector {
+        Selector::Matches(pattern.into()).into()
+    }
+
+    /// Select the first column
+    pub fn first() -> PolarsSelector {
+        Self::by_index(vec![0])
+    }
+
+    /// Select the last column
+    pub fn last() -> PolarsSelector {
+        Self::by_index(vec![-1])
+    }
+}
+
+impl PolarsSelectors {
+    /// Build a regex selector matching any of the escaped literals between `open` and `close`.
+    fn by_pattern(literals: &[String], open: &str, close: &str) -> PolarsSelector {
+        if literals.is_empty() {
+            return Selector::Empty.into();
+        }
+        let alternatives: Vec<String> = literals.iter().map(|s| escape_regex(s)).collect();
+        Selector::Matches(format!("{open}{}{close}", alternatives.join("|")).into()).into()
+    }
+}
+
+/// Escape characters with a special meaning in regular expressions
+fn escape_regex(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Column selector created by `Polars\Selectors`. Can be combined with set operations
+/// and used anywhere a column expression is accepted.
+#[php_class]
+#[php(name = "Polars\\Selector")]
+#[derive(Clone, Debug)]
+pub struct PolarsSelector(Selector);
+
+#[php_impl]
+impl PolarsSelector {
+    /// Select columns matched by either selector
+    pub fn union(&self, other: &PolarsSelector) -> Self {
+        Self(self.0.clone() | other.0.clone())
+    }
+
+    /// Select columns matched by both selectors
+    pub fn intersection(&self, other: &PolarsSelector) -> Self {
+        Self(self.0.clone() & other.0.clone())
+    }
+
+    /// Select columns matched by this selector but not by the other one
+    pub fn difference(&self, other: &PolarsSelector) -> Self {
+        Self(self.0.clone() - other.0.clone())
+    }
+
+    /// Select columns matched by exactly one of the selectors
+    pub fn exclusive_or(&self, other: &PolarsSelector) -> Self {
+        Self(self.0.clone() ^ other.0.clone())
+    }
+
+    /// Select all columns not matched by this selector
+    pub fn complement(&self) -> Self {
+        Self(!self.0.clone())
+    }
+
+    /// Convert selector to an expression
+    pub fn as_expr(&self) -> PolarsExpr {
+        self.0.clone().as_expr().into()
+    }
+
+    #[php(name = "__toString")]
+    pub fn __to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl PolarsSelector {
+    pub fn get_selector(&self) -> &Selector {
+        &self.0
+    }
+}
+
+impl From<Selector> for PolarsSelector {
+    fn from(selector: Selector) -> Self {
+        PolarsSelector(selector)
+    }
+}
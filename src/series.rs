@@ -1,8 +1,14 @@
 #![allow(non_snake_case)]
 
-use crate::common::any_value_to_zval;
+use crate::common::{any_value_to_zval, zval_to_length};
+use crate::data_frame::PhpDataFrame;
 use crate::data_type::PolarsDataType;
 use crate::exception::{ExtResult, PolarsException};
+use crate::expression::{
+    PolarsClosedInterval, PolarsExpr, PolarsQuantileMethod, correlation, dynamic_window_options,
+    ewm_options, fixed_window_options, optional_zval_to_expr, parse_correlation_method,
+    parse_duration, parse_null_behavior, parse_round_mode,
+};
 use ext_php_rs::flags::DataType as PhpDataType;
 use ext_php_rs::prelude::*;
 use ext_php_rs::types::{ZendHashTable, Zval};
@@ -10,13 +16,14 @@ use ext_php_rs::zend::ce;
 use std::collections::HashMap;
 
 use polars::prelude::{
-    ArgAgg, ChunkCompareEq, ChunkCompareIneq, DataType, FillNullStrategy, IntoSeries, NamedFrom,
-    QuantileMethod, Series, SortOptions,
+    ArgAgg, ChunkCompareEq, ChunkCompareIneq, CorrelationMethod, DataFrame, DataType, Expr,
+    FillNullStrategy, IntoColumn, IntoLazy, IntoSeries, NamedFrom, OptFlags, QuantileMethod,
+    RollingFnParams, RollingVarParams, Series, SortOptions, col, lit,
 };
 use polars::series::IsSorted;
 
 /// Convert PHP array values to a Series
-fn zval_vec_to_series(name: &str, values: Vec<Zval>) -> ExtResult<Series> {
+pub(crate) fn zval_vec_to_series(name: &str, values: Vec<Zval>) -> ExtResult<Series> {
     let first_value: &Zval = match values.first() {
         Some(value) => value,
         None => {
@@ -642,6 +649,701 @@ impl PhpSeries {
         })
     }
 
+    // ==================== Math Operations ====================
+
+    /// Compute absolute values
+    pub fn abs(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "absolute values", |e| e.abs())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the square root of the elements
+    pub fn sqrt(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "square root", |e| e.sqrt())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the cube root of the elements
+    pub fn cbrt(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cube root", |e| e.cbrt())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the exponential, element-wise
+    pub fn exp(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "exponential", |e| e.exp())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the logarithm to a given base. Natural logarithm is used when base is null
+    pub fn log(&self, base: Option<f64>) -> ExtResult<Self> {
+        let base = base.unwrap_or(std::f64::consts::E);
+        let result = apply_expr(&self.inner, "logarithm", |e| e.log(lit(base)))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the natural logarithm of the elements plus one
+    pub fn log1p(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "logarithm", |e| e.log1p())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the base 10 logarithm of the elements
+    pub fn log10(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "logarithm", |e| e.log(lit(10.0)))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the sine
+    pub fn sin(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "sine", |e| e.sin())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the cosine
+    pub fn cos(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cosine", |e| e.cos())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the tangent
+    pub fn tan(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "tangent", |e| e.tan())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the cotangent
+    pub fn cot(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cotangent", |e| e.cot())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the inverse sine
+    pub fn arcsin(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "inverse sine", |e| e.arcsin())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the inverse cosine
+    pub fn arccos(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "inverse cosine", |e| e.arccos())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the inverse tangent
+    pub fn arctan(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "inverse tangent", |e| e.arctan())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the hyperbolic sine
+    pub fn sinh(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "hyperbolic sine", |e| e.sinh())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the hyperbolic cosine
+    pub fn cosh(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "hyperbolic cosine", |e| e.cosh())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the hyperbolic tangent
+    pub fn tanh(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "hyperbolic tangent", |e| e.tanh())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the inverse hyperbolic sine
+    pub fn arcsinh(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "inverse hyperbolic sine", |e| e.arcsinh())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the inverse hyperbolic cosine
+    pub fn arccosh(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "inverse hyperbolic cosine", |e| e.arccosh())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise value for the inverse hyperbolic tangent
+    pub fn arctanh(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "inverse hyperbolic tangent", |e| e.arctanh())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Convert from radians to degrees
+    pub fn degrees(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "degrees", |e| e.degrees())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Convert from degrees to radians
+    pub fn radians(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "radians", |e| e.radians())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Rounds down to the nearest integer value
+    pub fn floor(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "floor", |e| e.floor())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Rounds up to the nearest integer value
+    pub fn ceil(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "ceil", |e| e.ceil())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Round underlying floating point data by `decimals` digits
+    /// @param string $mode One of: 'half_to_even', 'half_away_from_zero'
+    #[php(defaults(decimals = 0, mode = "half_to_even".to_string()))]
+    pub fn round(&self, decimals: u32, mode: String) -> ExtResult<Self> {
+        let mode = parse_round_mode(&mode)?;
+        let result = apply_expr(&self.inner, "round", |e| e.round(decimals, mode))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Round to a number of significant figures
+    #[php(name = "roundSigFigs")]
+    pub fn round_sig_figs(&self, digits: i32) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "round", |e| e.round_sig_figs(digits))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Set values outside the given boundaries to the boundary value. Null bound means no bound on that side
+    /// @param int|float|null $lowerBound
+    /// @param int|float|null $upperBound
+    pub fn clip(&self, lowerBound: Option<&Zval>, upperBound: Option<&Zval>) -> ExtResult<Self> {
+        let lower = optional_zval_to_expr(lowerBound)?;
+        let upper = optional_zval_to_expr(upperBound)?;
+        let result = apply_expr(&self.inner, "clip", |e| match (lower, upper) {
+            (Some(lower), Some(upper)) => e.clip(lower, upper),
+            (Some(lower), None) => e.clip_min(lower),
+            (None, Some(upper)) => e.clip_max(upper),
+            (None, None) => e,
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the element-wise sign function on numeric types (-1, 0 or 1)
+    pub fn sign(&self) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "sign", |e| e.sign())?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute the dot/inner product with another Series
+    pub fn dot(&self, other: &PhpSeries) -> ExtResult<Zval> {
+        if self.inner.len() != other.inner.len() {
+            return Err(PolarsException::new(format!(
+                "Cannot compute dot product: length mismatch ({} vs {})",
+                self.inner.len(),
+                other.inner.len()
+            )));
+        }
+        let df = DataFrame::new(vec![
+            self.inner.clone().with_name("a".into()).into_column(),
+            other.inner.clone().with_name("b".into()).into_column(),
+        ])
+        .map_err(|e| PolarsException::new(format!("Cannot compute dot product: {}", e)))?;
+        let result = df
+            .lazy()
+            .select([col("a").dot(col("b"))])
+            .with_optimizations(OptFlags::EAGER)
+            .collect()
+            .map_err(|e| PolarsException::new(format!("Cannot compute dot product: {}", e)))?;
+        let value = result.get_columns()[0]
+            .get(0)
+            .map_err(|e| PolarsException::new(format!("Cannot compute dot product: {}", e)))?;
+        any_value_to_zval(value)
+    }
+
+    /// Compute the correlation with another Series
+    /// @param string $method One of: 'pearson', 'spearman'
+    /// @throws Polars\Exception
+    #[php(defaults(method = "pearson".to_string(), propagateNans = false))]
+    pub fn corr(&self, other: &PhpSeries, method: String, propagateNans: bool) -> ExtResult<Zval> {
+        let method = parse_correlation_method(&method, propagateNans)?;
+        let result = apply_expr_by(&self.inner, &other.inner, "correlation", |a, b| {
+            correlation(a, b, method)
+        })?;
+        first_value(&result, "correlation")
+    }
+
+    /// Compute the covariance with another Series
+    /// @throws Polars\Exception
+    #[php(defaults(ddof = 1))]
+    pub fn cov(&self, other: &PhpSeries, ddof: u8) -> ExtResult<Zval> {
+        let result = apply_expr_by(&self.inner, &other.inner, "covariance", |a, b| {
+            correlation(a, b, CorrelationMethod::Covariance(ddof))
+        })?;
+        first_value(&result, "covariance")
+    }
+
+    // ==================== Cumulative Operations ====================
+
+    /// Get the cumulative sum computed at every element
+    #[php(name = "cumSum", defaults(reverse = false))]
+    pub fn cum_sum(&self, reverse: bool) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cumulative sum", |e| e.cum_sum(reverse))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Get the cumulative product computed at every element
+    #[php(name = "cumProd", defaults(reverse = false))]
+    pub fn cum_prod(&self, reverse: bool) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cumulative product", |e| e.cum_prod(reverse))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Get the cumulative min computed at every element
+    #[php(name = "cumMin", defaults(reverse = false))]
+    pub fn cum_min(&self, reverse: bool) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cumulative min", |e| e.cum_min(reverse))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Get the cumulative max computed at every element
+    #[php(name = "cumMax", defaults(reverse = false))]
+    pub fn cum_max(&self, reverse: bool) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cumulative max", |e| e.cum_max(reverse))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Get the cumulative count of non-null values computed at every element
+    #[php(name = "cumCount", defaults(reverse = false))]
+    pub fn cum_count(&self, reverse: bool) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "cumulative count", |e| e.cum_count(reverse))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Calculate the first discrete difference between shifted items
+    /// @param string $nullBehavior One of: 'ignore', 'drop'
+    #[php(defaults(n = 1, nullBehavior = "ignore".to_string()))]
+    pub fn diff(&self, n: i64, nullBehavior: String) -> ExtResult<Self> {
+        let null_behavior = parse_null_behavior(&nullBehavior)?;
+        let result = apply_expr(&self.inner, "difference", |e| e.diff(lit(n), null_behavior))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute percentage change between values
+    #[php(name = "pctChange", defaults(n = 1))]
+    pub fn pct_change(&self, n: i64) -> ExtResult<Self> {
+        let result = apply_expr(&self.inner, "percentage change", |e| e.pct_change(lit(n)))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Run an expression over a sliding window that increases by one slot every iteration.
+    /// Use `Expr::element()` to refer to the values in the window
+    /// @throws Polars\Exception
+    #[php(name = "cumulativeEval", defaults(minSamples = 1))]
+    pub fn cumulative_eval(&self, expr: &PolarsExpr, minSamples: i64) -> ExtResult<Self> {
+        let min_samples = zval_to_length(minSamples, "minSamples")?;
+        let result = apply_expr(&self.inner, "cumulative eval", |e| {
+            e.cumulative_eval(expr.get_expr().clone(), min_samples)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    // ==================== Rolling Window Operations ====================
+
+    /// Apply a rolling sum over a fixed window of `windowSize` values
+    #[php(name = "rollingSum", defaults(center = false))]
+    pub fn rolling_sum(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        let result = apply_expr(&self.inner, "rolling sum", |e| e.rolling_sum(options))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling mean over a fixed window of `windowSize` values
+    #[php(name = "rollingMean", defaults(center = false))]
+    pub fn rolling_mean(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        let result = apply_expr(&self.inner, "rolling mean", |e| e.rolling_mean(options))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling min over a fixed window of `windowSize` values
+    #[php(name = "rollingMin", defaults(center = false))]
+    pub fn rolling_min(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        let result = apply_expr(&self.inner, "rolling min", |e| e.rolling_min(options))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling max over a fixed window of `windowSize` values
+    #[php(name = "rollingMax", defaults(center = false))]
+    pub fn rolling_max(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        let result = apply_expr(&self.inner, "rolling max", |e| e.rolling_max(options))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling median over a fixed window of `windowSize` values
+    #[php(name = "rollingMedian", defaults(center = false))]
+    pub fn rolling_median(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        let result = apply_expr(&self.inner, "rolling median", |e| e.rolling_median(options))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling standard deviation over a fixed window of `windowSize` values
+    #[php(name = "rollingStd", defaults(center = false, ddof = 1))]
+    pub fn rolling_std(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, Some(params))?;
+        let result = apply_expr(&self.inner, "rolling standard deviation", |e| {
+            e.rolling_std(options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling variance over a fixed window of `windowSize` values
+    #[php(name = "rollingVar", defaults(center = false, ddof = 1))]
+    pub fn rolling_var(
+        &self,
+        windowSize: i64,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, Some(params))?;
+        let result = apply_expr(&self.inner, "rolling variance", |e| e.rolling_var(options))?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling quantile over a fixed window of `windowSize` values
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "rollingQuantile",
+        defaults(interpolation = PolarsQuantileMethod::Nearest, center = false)
+    )]
+    pub fn rolling_quantile(
+        &self,
+        quantile: f64,
+        windowSize: i64,
+        interpolation: PolarsQuantileMethod,
+        minPeriods: Option<i64>,
+        center: bool,
+        weights: Option<Vec<f64>>,
+    ) -> ExtResult<Self> {
+        let options = fixed_window_options(windowSize, minPeriods, center, weights, None)?;
+        let result = apply_expr(&self.inner, "rolling quantile", |e| {
+            e.rolling_quantile(interpolation.into(), quantile, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling sum over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(name = "rollingSumBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_sum_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling sum", |e, by| {
+            e.rolling_sum_by(by, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling mean over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(name = "rollingMeanBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_mean_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling mean", |e, by| {
+            e.rolling_mean_by(by, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling min over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(name = "rollingMinBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_min_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling min", |e, by| {
+            e.rolling_min_by(by, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling max over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(name = "rollingMaxBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_max_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling max", |e, by| {
+            e.rolling_max_by(by, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling median over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(name = "rollingMedianBy", defaults(minPeriods = 1, closed = PolarsClosedInterval::Right))]
+    pub fn rolling_median_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling median", |e, by| {
+            e.rolling_median_by(by, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling standard deviation over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(
+        name = "rollingStdBy",
+        defaults(minPeriods = 1, closed = PolarsClosedInterval::Right, ddof = 1)
+    )]
+    pub fn rolling_std_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, Some(params))?;
+        let result = apply_expr_by(
+            &self.inner,
+            &by.inner,
+            "rolling standard deviation",
+            |e, by| e.rolling_std_by(by, options),
+        )?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling variance over a temporal window (e.g. '7d') based on the values of `by`
+    #[php(
+        name = "rollingVarBy",
+        defaults(minPeriods = 1, closed = PolarsClosedInterval::Right, ddof = 1)
+    )]
+    pub fn rolling_var_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+        ddof: u8,
+    ) -> ExtResult<Self> {
+        let params = RollingFnParams::Var(RollingVarParams { ddof });
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, Some(params))?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling variance", |e, by| {
+            e.rolling_var_by(by, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Apply a rolling quantile over a temporal window (e.g. '7d') based on the values of `by`
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "rollingQuantileBy",
+        defaults(
+            interpolation = PolarsQuantileMethod::Nearest,
+            minPeriods = 1,
+            closed = PolarsClosedInterval::Right
+        )
+    )]
+    pub fn rolling_quantile_by(
+        &self,
+        by: &PhpSeries,
+        windowSize: String,
+        quantile: f64,
+        interpolation: PolarsQuantileMethod,
+        minPeriods: i64,
+        closed: PolarsClosedInterval,
+    ) -> ExtResult<Self> {
+        let options = dynamic_window_options(&windowSize, minPeriods, closed, None)?;
+        let result = apply_expr_by(&self.inner, &by.inner, "rolling quantile", |e, by| {
+            e.rolling_quantile_by(by, interpolation.into(), quantile, options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    // ==================== Exponentially Weighted Operations ====================
+
+    /// Compute exponentially-weighted moving mean.
+    /// Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "ewmMean",
+        defaults(adjust = true, minPeriods = 1, ignoreNulls = false)
+    )]
+    pub fn ewm_mean(
+        &self,
+        com: Option<f64>,
+        span: Option<f64>,
+        halfLife: Option<f64>,
+        alpha: Option<f64>,
+        adjust: bool,
+        minPeriods: i64,
+        ignoreNulls: bool,
+    ) -> ExtResult<Self> {
+        let options = ewm_options(
+            com,
+            span,
+            halfLife,
+            alpha,
+            adjust,
+            false,
+            minPeriods,
+            ignoreNulls,
+        )?;
+        let result = apply_expr(&self.inner, "exponentially weighted mean", |e| {
+            e.ewm_mean(options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute exponentially-weighted moving standard deviation.
+    /// Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "ewmStd",
+        defaults(adjust = true, bias = false, minPeriods = 1, ignoreNulls = false)
+    )]
+    pub fn ewm_std(
+        &self,
+        com: Option<f64>,
+        span: Option<f64>,
+        halfLife: Option<f64>,
+        alpha: Option<f64>,
+        adjust: bool,
+        bias: bool,
+        minPeriods: i64,
+        ignoreNulls: bool,
+    ) -> ExtResult<Self> {
+        let options = ewm_options(
+            com,
+            span,
+            halfLife,
+            alpha,
+            adjust,
+            bias,
+            minPeriods,
+            ignoreNulls,
+        )?;
+        let result = apply_expr(
+            &self.inner,
+            "exponentially weighted standard deviation",
+            |e| e.ewm_std(options),
+        )?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute exponentially-weighted moving variance.
+    /// Exactly one of `com`, `span`, `halfLife` or `alpha` must be given
+    #[allow(clippy::too_many_arguments)]
+    #[php(
+        name = "ewmVar",
+        defaults(adjust = true, bias = false, minPeriods = 1, ignoreNulls = false)
+    )]
+    pub fn ewm_var(
+        &self,
+        com: Option<f64>,
+        span: Option<f64>,
+        halfLife: Option<f64>,
+        alpha: Option<f64>,
+        adjust: bool,
+        bias: bool,
+        minPeriods: i64,
+        ignoreNulls: bool,
+    ) -> ExtResult<Self> {
+        let options = ewm_options(
+            com,
+            span,
+            halfLife,
+            alpha,
+            adjust,
+            bias,
+            minPeriods,
+            ignoreNulls,
+        )?;
+        let result = apply_expr(&self.inner, "exponentially weighted variance", |e| {
+            e.ewm_var(options)
+        })?;
+        Ok(Self { inner: result })
+    }
+
+    /// Compute time-based exponentially weighted moving average, using `by` as the times of the values
+    /// @param string $halfLife Duration string such as '1d' over which an observation decays to half its value
+    #[php(name = "ewmMeanBy")]
+    pub fn ewm_mean_by(&self, by: &PhpSeries, halfLife: String) -> ExtResult<Self> {
+        let half_life = parse_duration(&halfLife)?;
+        let result = apply_expr_by(
+            &self.inner,
+            &by.inner,
+            "exponentially weighted mean",
+            |e, by| e.ewm_mean_by(by, half_life),
+        )?;
+        Ok(Self { inner: result })
+    }
+
     // ==================== Data Manipulation ====================
 
     /// Sort the Series
@@ -721,6 +1423,51 @@ impl PhpSeries {
         Ok(Self { inner: filled })
     }
 
+    /// Bin values into buckets and count their occurrences
+    /// @param array|null $bins Bin edges; the first bucket is closed on both sides
+    /// @param int|null $binCount Number of equal-width bins spanning the data range; defaults to 10 without `bins`
+    /// @param bool $includeCategory Include a `category` column with the bin interval
+    /// @param bool $includeBreakpoint Include a `breakpoint` column with the right edge of the bin
+    /// @throws Polars\Exception
+    #[php(defaults(includeCategory = true, includeBreakpoint = true))]
+    pub fn hist(
+        &self,
+        bins: Option<Vec<f64>>,
+        binCount: Option<i64>,
+        includeCategory: bool,
+        includeBreakpoint: bool,
+    ) -> ExtResult<PhpDataFrame> {
+        if bins.is_some() && binCount.is_some() {
+            return Err(PolarsException::new(
+                "Cannot compute histogram: pass either bins or binCount, not both".to_string(),
+            ));
+        }
+        let bin_count = match binCount {
+            Some(n) if n < 1 => {
+                return Err(PolarsException::new(format!(
+                    "Invalid bin count: {}. Must be at least 1",
+                    n
+                )));
+            }
+            Some(n) => Some(n as usize),
+            None => None,
+        };
+        let bins = bins.map(|bins| Series::new("bins".into(), bins));
+        let hist = polars_ops::chunked_array::hist_series(
+            &self.inner,
+            bin_count,
+            bins,
+            includeCategory,
+            includeBreakpoint,
+        )
+        .map_err(|e| PolarsException::new(format!("Cannot compute histogram: {}", e)))?;
+        let df = match hist.struct_() {
+            Ok(fields) => fields.clone().unnest(),
+            Err(_) => hist.into_frame(),
+        };
+        Ok(df.into())
+    }
+
     // ==================== Utility Methods ====================
 
     /// Convert Series to PHP array
@@ -808,6 +1555,61 @@ fn zval_to_scalar_series(reference: &Series, value: &Zval) -> ExtResult<Series>
     }
 }
 
+/// Evaluate an expression on the Series and return the resulting Series
+fn apply_expr<F>(series: &Series, operation: &str, f: F) -> ExtResult<Series>
+where
+    F: FnOnce(Expr) -> Expr,
+{
+    let expr = f(col(series.name().clone()));
+    let df = series
+        .clone()
+        .into_frame()
+        .lazy()
+        .select([expr])
+        .with_optimizations(OptFlags::EAGER)
+        .collect()
+        .map_err(|e| PolarsException::new(format!("Cannot compute {}: {}", operation, e)))?;
+    Ok(df.get_columns()[0].as_materialized_series().clone())
+}
+
+/// Get the single value of a reduced Series as a PHP value
+fn first_value(series: &Series, operation: &str) -> ExtResult<Zval> {
+    let value = series
+        .get(0)
+        .map_err(|e| PolarsException::new(format!("Cannot compute {}: {}", operation, e)))?;
+    any_value_to_zval(value)
+}
+
+/// Evaluate an expression that combines the Series with a second `by` Series of the same length
+fn apply_expr_by<F>(series: &Series, by: &Series, operation: &str, f: F) -> ExtResult<Series>
+where
+    F: FnOnce(Expr, Expr) -> Expr,
+{
+    if series.len() != by.len() {
+        return Err(PolarsException::new(format!(
+            "Cannot compute {}: length mismatch ({} vs {})",
+            operation,
+            series.len(),
+            by.len()
+        )));
+    }
+    let df = DataFrame::new(vec![
+        series.clone().with_name("values".into()).into_column(),
+        by.clone().with_name("by".into()).into_column(),
+    ])
+    .map_err(|e| PolarsException::new(format!("Cannot compute {}: {}", operation, e)))?;
+    let result = df
+        .lazy()
+        .select([f(col("values"), col("by"))])
+        .with_optimizations(OptFlags::EAGER)
+        .collect()
+        .map_err(|e| PolarsException::new(format!("Cannot compute {}: {}", operation, e)))?;
+    Ok(result.get_columns()[0]
+        .as_materialized_series()
+        .clone()
+        .with_name(series.name().clone()))
+}
+
 // ==================== Trait Implementations ====================
 
 impl From<Series> for PhpSeries {